@@ -0,0 +1,82 @@
+//! Counts the allocations and times `fmap` over `Vec` and `Box`, with the in-place path (where
+//! the element layouts match) next to the fallback (where they don't).
+//!
+//! Run with `cargo bench --bench fmap_in_place`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use higher_kinded::prelude::*;
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const LEN: usize = 1 << 16;
+const ROUNDS: u32 = 200;
+
+// Runs `f` on a fresh input `ROUNDS` times, reporting the allocations made by `f` alone.
+fn measure<T, U>(name: &str, mut input: impl FnMut() -> T, mut f: impl FnMut(T) -> U) {
+    let (mut allocations, mut bytes, mut time) = (0, 0, Duration::ZERO);
+    for _ in 0..ROUNDS {
+        let input = black_box(input());
+        let (before, before_bytes) = (
+            ALLOCATIONS.load(Ordering::Relaxed),
+            BYTES.load(Ordering::Relaxed),
+        );
+        let start = Instant::now();
+        let output = black_box(f(input));
+        time += start.elapsed();
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - before;
+        bytes += BYTES.load(Ordering::Relaxed) - before_bytes;
+        drop(output);
+    }
+    println!(
+        "{name:<32} {:>8.1} allocations {:>12} bytes {:>10.2?} per call",
+        f64::from(allocations as u32) / f64::from(ROUNDS),
+        bytes / ROUNDS as usize,
+        time / ROUNDS,
+    );
+}
+
+fn main() {
+    let numbers = || (0..LEN as u32).collect::<Vec<u32>>();
+    measure("Vec<u32> -> Vec<i32> (in place)", numbers, |v| {
+        v.fmap(|n| n.wrapping_sub(1) as i32)
+    });
+    measure("Vec<u32> -> Vec<u64> (fallback)", numbers, |v| {
+        v.fmap(|n| u64::from(n) + 1)
+    });
+    // The same map through `Iterator` and `collect`, for comparison.
+    measure("Vec<u32> -> Vec<i32> (collect)", numbers, |v| {
+        v.into_iter()
+            .map(|n| n.wrapping_sub(1) as i32)
+            .collect::<Vec<_>>()
+    });
+
+    let boxed = || Box::new([0u64; 64]);
+    measure("Box<[u64; 64]> -> Box<[i64; 64]>", boxed, |b| {
+        b.fmap(|a| a.map(|n| n as i64 - 1))
+    });
+    measure("Box<[u64; 64]> -> Box<[u8; 64]>", boxed, |b| {
+        b.fmap(|a| a.map(|n| n as u8))
+    });
+}
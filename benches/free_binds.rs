@@ -0,0 +1,63 @@
+//! Times a loop that keeps binding onto the end of a program, built as a `Free` and as a
+//! `FreeC`, at doubling lengths. The `Free` times roughly quadruple with each doubling, the
+//! `FreeC` ones roughly double. `FreeC` is then timed on a program long enough that building it
+//! recursively would overflow the stack.
+//!
+//! Run with `cargo bench --bench free_binds`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use higher_kinded::prelude::*;
+
+const ROUNDS: u32 = 20;
+
+// `Option` makes for the simplest instruction set, a step that either carries on or stops.
+type Program = Free<Option<()>, u32>;
+
+fn free(steps: u32) -> Program {
+    let mut prog = Free::Pure(0);
+    for _ in 0..steps {
+        prog = prog.bind(|n| Free::lift_f(Some(n + 1)));
+    }
+    prog
+}
+
+fn free_c(steps: u32) -> Program {
+    let mut prog = FreeC::<Option<()>, u32>::pure(0);
+    for _ in 0..steps {
+        prog = prog.bind(|n| FreeC::lift_f(Some(n + 1)));
+    }
+    prog.into()
+}
+
+fn run(mut prog: Program) -> Option<u32> {
+    loop {
+        match prog.resume() {
+            Either::Right(n) => return Some(n),
+            Either::Left(next) => prog = next?,
+        }
+    }
+}
+
+fn measure(name: &str, steps: u32, build: impl Fn(u32) -> Program) {
+    let mut time = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        let result = run(build(black_box(steps)));
+        time += start.elapsed();
+        assert_eq!(result, Some(steps));
+    }
+    println!(
+        "{name:<10} {steps:>6} binds {:>12.2?} per run",
+        time / ROUNDS
+    );
+}
+
+fn main() {
+    for steps in [250, 500, 1_000, 2_000] {
+        measure("Free", steps, free);
+        measure("FreeC", steps, free_c);
+    }
+    measure("FreeC", 100_000, free_c);
+}
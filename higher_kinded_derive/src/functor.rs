@@ -0,0 +1,277 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Data, DeriveInput, Fields, GenericArgument, Ident, PathArguments, ReturnType,
+    Type, TypeParamBound,
+};
+
+use crate::k1;
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let param = k1::last_type_param(&input)?.clone();
+    let mapper = Mapper { param: &param };
+
+    let arms = match &input.data {
+        Data::Struct(data) => vec![mapper.arm(quote!(#name), &data.fields)?],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                mapper.arm(quote!(#name::#ident), &variant.fields)
+            })
+            .collect::<syn::Result<_>>()?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`Functor` can't be derived for unions",
+            ))
+        }
+    };
+
+    // Lazy fields like boxed closures have to hold on to the mapping function, so everything is
+    // `'static`, like the hierarchy's own instances.
+    let mut generics = input.generics.clone();
+    let params: Vec<_> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for p in &params {
+        where_clause.predicates.push(parse_quote!(#p: 'static));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let fresh = k1::fresh_ident(&input);
+
+    Ok(quote! {
+        impl #impl_generics ::higher_kinded::functor::Functor for #name #ty_generics
+        #where_clause
+        {
+            fn fmap<#fresh: 'static>(
+                self,
+                __f: impl ::core::ops::FnMut(#param) -> #fresh + 'static,
+            ) -> <Self as ::higher_kinded::kind::K1>::With<#fresh> {
+                let __f = ::higher_kinded::__private::Rc::new(::core::cell::RefCell::new(__f));
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::higher_kinded::functor::FunctorWith for #name #ty_generics
+        #where_clause
+        {
+            fn fmap_with<__X: 'static, __Y: 'static>(
+                fx: <Self as ::higher_kinded::kind::K1>::With<__X>,
+                f: impl ::core::ops::FnMut(__X) -> __Y + 'static,
+            ) -> <Self as ::higher_kinded::kind::K1>::With<__Y> {
+                ::higher_kinded::functor::Functor::fmap(fx, f)
+            }
+        }
+    })
+}
+
+struct Mapper<'a> {
+    param: &'a Ident,
+}
+
+impl Mapper<'_> {
+    /// A match arm taking apart `path`'s fields and rebuilding it with each one mapped.
+    fn arm(&self, path: TokenStream, fields: &Fields) -> syn::Result<TokenStream> {
+        let bindings: Vec<_> = (0..fields.len())
+            .map(|i| format_ident!("__{}", i))
+            .collect();
+        let mapped = fields
+            .iter()
+            .zip(&bindings)
+            .map(|(field, binding)| self.map(&field.ty, quote!(#binding)))
+            .collect::<syn::Result<Vec<_>>>()?;
+        Ok(match fields {
+            Fields::Named(named) => {
+                let names: Vec<_> = named.named.iter().map(|f| &f.ident).collect();
+                quote! {
+                    #path { #(#names: #bindings),* } => #path { #(#names: #mapped),* },
+                }
+            }
+            Fields::Unnamed(_) => quote! {
+                #path(#(#bindings),*) => #path(#(#mapped),*),
+            },
+            Fields::Unit => quote! {
+                #path => #path,
+            },
+        })
+    }
+
+    /// An expression mapping `val`, of type `ty`, with the shared function `__f`.
+    fn map(&self, ty: &Type, val: TokenStream) -> syn::Result<TokenStream> {
+        if !self.mentions(ty) {
+            return Ok(val);
+        }
+        match ty {
+            Type::Path(path) if path.qself.is_none() && path.path.is_ident(self.param) => {
+                // The `let` ends the borrow right away, so sibling fields can borrow it again.
+                Ok(quote!({
+                    let __b = (__f.borrow_mut())(#val);
+                    __b
+                }))
+            }
+            Type::Paren(paren) => self.map(&paren.elem, val),
+            Type::Group(group) => self.map(&group.elem, val),
+            Type::Tuple(tuple) => {
+                let bindings: Vec<_> = (0..tuple.elems.len())
+                    .map(|i| format_ident!("__t{}", i))
+                    .collect();
+                let mapped = tuple
+                    .elems
+                    .iter()
+                    .zip(&bindings)
+                    .map(|(ty, binding)| self.map(ty, quote!(#binding)))
+                    .collect::<syn::Result<Vec<_>>>()?;
+                Ok(quote!({
+                    let (#(#bindings,)*) = #val;
+                    (#(#mapped,)*)
+                }))
+            }
+            Type::Array(array) => {
+                let inner = self.map(&array.elem, quote!(__v))?;
+                Ok(quote!((#val).map(|__v| #inner)))
+            }
+            Type::Path(path) if path.qself.is_none() => {
+                let last = path.path.segments.last().unwrap();
+                let args: Vec<&Type> = match &last.arguments {
+                    PathArguments::AngleBracketed(args) => args
+                        .args
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                if last.ident == "Result" && args.len() == 2 && !self.mentions(args[1]) {
+                    let inner = self.map(args[0], quote!(__v))?;
+                    return Ok(quote!((#val).map(|__v| #inner)));
+                }
+                let Some((elem, rest)) = args.split_last() else {
+                    return Err(self.unsupported(ty));
+                };
+                if rest.iter().any(|ty| self.mentions(ty)) {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        format!(
+                            "`{}` may only appear in the last type argument here",
+                            self.param
+                        ),
+                    ));
+                }
+                match last.ident.to_string().as_str() {
+                    "PhantomData" => Ok(quote!(::core::marker::PhantomData)),
+                    "Box" => match elem {
+                        Type::TraitObject(obj) => self.map_boxed_fn(obj, &val),
+                        elem => {
+                            let inner = self.map(elem, quote!(*#val))?;
+                            Ok(quote!(::higher_kinded::__private::Box::new(#inner)))
+                        }
+                    },
+                    "Option" => {
+                        let inner = self.map(elem, quote!(__v))?;
+                        Ok(quote!((#val).map(|__v| #inner)))
+                    }
+                    "Vec" => {
+                        let inner = self.map(elem, quote!(__v))?;
+                        Ok(quote! {
+                            (#val)
+                                .into_iter()
+                                .map(|__v| #inner)
+                                .collect::<::higher_kinded::__private::Vec<_>>()
+                        })
+                    }
+                    // Anything else has to be a `Functor` itself. The function is erased into a
+                    // `Box<dyn FnMut>`, so that recursive types instantiate `fmap` with the same
+                    // function type at every level.
+                    _ => {
+                        let inner = self.map(elem, quote!(__v))?;
+                        Ok(quote! {
+                            ::higher_kinded::functor::Functor::fmap(#val, {
+                                let __f = ::higher_kinded::__private::Rc::clone(&__f);
+                                ::higher_kinded::__private::Box::new(move |__v| #inner)
+                                    as ::higher_kinded::__private::Box<dyn ::core::ops::FnMut(_) -> _>
+                            })
+                        })
+                    }
+                }
+            }
+            _ => Err(self.unsupported(ty)),
+        }
+    }
+
+    /// Maps a `Box<dyn FnOnce(..) -> R>` (or `FnMut`, or `Fn`) by mapping what it returns.
+    fn map_boxed_fn(
+        &self,
+        obj: &syn::TypeTraitObject,
+        val: &TokenStream,
+    ) -> syn::Result<TokenStream> {
+        let sig = obj.bounds.iter().find_map(|bound| match bound {
+            TypeParamBound::Trait(bound) => {
+                let last = bound.path.segments.last()?;
+                let is_fn = ["Fn", "FnMut", "FnOnce"].contains(&last.ident.to_string().as_str());
+                match &last.arguments {
+                    PathArguments::Parenthesized(args) if is_fn => Some(args),
+                    _ => None,
+                }
+            }
+            _ => None,
+        });
+        let Some(sig) = sig else {
+            return Err(self.unsupported(&Type::TraitObject(obj.clone())));
+        };
+        if let Some(input) = sig.inputs.iter().find(|ty| self.mentions(ty)) {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "`{}` appears as a closure argument, so this type isn't a covariant functor",
+                    self.param
+                ),
+            ));
+        }
+        let ReturnType::Type(_, output) = &sig.output else {
+            unreachable!("the return type mentions the parameter");
+        };
+        let args: Vec<_> = (0..sig.inputs.len())
+            .map(|i| format_ident!("__a{}", i))
+            .collect();
+        let inner = self.map(output, quote!(__v))?;
+        Ok(quote! {
+            ::higher_kinded::__private::Box::new({
+                let __f = ::higher_kinded::__private::Rc::clone(&__f);
+                #[allow(unused_mut)]
+                let mut __g = #val;
+                move |#(#args),*| {
+                    let __v = __g(#(#args),*);
+                    #inner
+                }
+            })
+        })
+    }
+
+    fn mentions(&self, ty: &Type) -> bool {
+        struct Finder<'a>(&'a Ident, bool);
+        impl<'ast> syn::visit::Visit<'ast> for Finder<'_> {
+            fn visit_ident(&mut self, ident: &'ast Ident) {
+                self.1 |= ident == self.0;
+            }
+        }
+        let mut finder = Finder(self.param, false);
+        syn::visit::visit_type(&mut finder, ty);
+        finder.1
+    }
+
+    fn unsupported(&self, ty: &Type) -> syn::Error {
+        syn::Error::new_spanned(
+            ty,
+            format!(
+                "don't know how to map `{}` through this type; implement `Functor` by hand",
+                self.param
+            ),
+        )
+    }
+}
@@ -0,0 +1,75 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, GenericParam, Ident, Type, WherePredicate};
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let last = last_type_param(&input)?;
+
+    // A fresh name for the parameter of `With`, distinct from the type's own parameters.
+    let fresh = fresh_ident(&input);
+    let with_args = input.generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(lt) => {
+            let lt = &lt.lifetime;
+            quote!(#lt)
+        }
+        GenericParam::Type(ty) if ty.ident == *last => quote!(#fresh),
+        GenericParam::Type(ty) => {
+            let ident = &ty.ident;
+            quote!(#ident)
+        }
+        GenericParam::Const(c) => {
+            let ident = &c.ident;
+            quote!(#ident)
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::higher_kinded::kind::K1 for #name #ty_generics #where_clause {
+            type Inner = #last;
+
+            type With<#fresh> = #name<#(#with_args),*>;
+        }
+    })
+}
+
+pub(crate) fn last_type_param(input: &DeriveInput) -> syn::Result<&Ident> {
+    let param = input.generics.type_params().last().ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "`K1` can only be derived for types with a type parameter",
+        )
+    })?;
+
+    let bounded_in_where = input
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|clause| &clause.predicates)
+        .any(|pred| match pred {
+            WherePredicate::Type(pred) => is_param(&pred.bounded_ty, &param.ident),
+            _ => false,
+        });
+    if !param.bounds.is_empty() || bounded_in_where {
+        return Err(syn::Error::new_spanned(
+            param,
+            "the parameter `K1` abstracts over can't have bounds, since `With<I>` must work for \
+             any `I`",
+        ));
+    }
+    Ok(&param.ident)
+}
+
+fn is_param(ty: &Type, param: &Ident) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(param))
+}
+
+pub(crate) fn fresh_ident(input: &DeriveInput) -> Ident {
+    let taken = |ident: &Ident| input.generics.type_params().any(|p| p.ident == *ident);
+    let mut fresh = format_ident!("__With");
+    while taken(&fresh) {
+        fresh = format_ident!("_{}", fresh);
+    }
+    fresh
+}
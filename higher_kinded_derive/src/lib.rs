@@ -0,0 +1,41 @@
+//! Derive macros for [`higher_kinded`](https://docs.rs/higher_kinded). Enable its `derive`
+//! feature rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod functor;
+mod k1;
+
+/// Derives `K1` over the last type parameter.
+///
+/// `Inner` is that parameter, and `With<I>` replaces it with `I`, keeping every other parameter
+/// as is. It doesn't matter where, or whether, the parameter appears in the fields, so a
+/// `PhantomData<T>` works as well as a `T`.
+///
+/// The parameter can't have bounds, since `With<I>` has to be well-formed for any `I`.
+#[proc_macro_derive(K1)]
+pub fn derive_k1(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    k1::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `Functor`, and `FunctorWith`, by mapping the last type parameter wherever it appears
+/// in the fields.
+///
+/// Fields that don't mention the parameter are moved over as is. Otherwise, the parameter can
+/// appear inside tuples, arrays, `Box`, `Option`, `Vec`, `PhantomData`, the `Ok` side of a
+/// `Result`, the return type of a boxed `Fn`, `FnMut` or `FnOnce`, and the last type argument of
+/// any other `Functor`, including the type being derived. The `K1` impl has to exist already,
+/// e.g. from `#[derive(K1)]`.
+///
+/// Every type parameter has to be `'static`, like the rest of the hierarchy.
+#[proc_macro_derive(Functor)]
+pub fn derive_functor(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    functor::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,157 @@
+use std::marker::PhantomData;
+
+use higher_kinded::free::Free;
+use higher_kinded::functor::{Functor, FunctorOnce};
+use higher_kinded::monad::Monad;
+use higher_kinded::prelude::Either;
+use higher_kinded::recursion::Fix;
+use higher_kinded::{Functor, K1};
+
+#[derive(Debug, PartialEq, K1, Functor)]
+struct Labelled<T> {
+    label: &'static str,
+    values: Vec<(T, Option<T>)>,
+    best: Box<[T; 2]>,
+    checked: Result<T, String>,
+    marker: PhantomData<T>,
+}
+
+#[derive(Debug, PartialEq, K1, Functor)]
+enum Tree<T> {
+    Leaf(T),
+    Node(Box<Tree<T>>, Vec<Tree<T>>),
+    Empty,
+}
+
+#[derive(K1, Functor)]
+enum Cmd<K> {
+    Say(String, K),
+    Ask(Box<dyn FnOnce(i32) -> K>),
+}
+
+impl<K: 'static> FunctorOnce for Cmd<K> {}
+
+#[test]
+fn maps_structurally() {
+    let labelled = Labelled {
+        label: "x",
+        values: vec![(1, None), (2, Some(3))],
+        best: Box::new([4, 5]),
+        checked: Ok(6),
+        marker: PhantomData,
+    };
+    let expected = Labelled {
+        label: "x",
+        values: vec![(10, None), (20, Some(30))],
+        best: Box::new([40, 50]),
+        checked: Ok(60),
+        marker: PhantomData,
+    };
+    assert_eq!(labelled.fmap(|n| n * 10), expected);
+}
+
+#[test]
+fn maps_recursive_types() {
+    let tree = Tree::Node(
+        Box::new(Tree::Leaf(1)),
+        vec![Tree::Empty, Tree::Node(Box::new(Tree::Leaf(2)), vec![])],
+    );
+    let expected = Tree::Node(
+        Box::new(Tree::Leaf("1".to_owned())),
+        vec![
+            Tree::Empty,
+            Tree::Node(Box::new(Tree::Leaf("2".to_owned())), vec![]),
+        ],
+    );
+    assert_eq!(tree.fmap(|n| n.to_string()), expected);
+}
+
+#[test]
+fn derived_functors_drive_free() {
+    let say = |msg: &str| Free::<Cmd<()>, _>::lift_f(Cmd::Say(msg.to_owned(), ()));
+    let ask = || Free::<Cmd<()>, _>::lift_f(Cmd::Ask(Box::new(|n| n)));
+    let mut prog = say("hi").bind(move |()| ask()).fmap(|n| n + 1);
+
+    let mut said = Vec::new();
+    let n = loop {
+        prog = match prog.resume() {
+            Either::Right(n) => break n,
+            Either::Left(Cmd::Say(msg, next)) => {
+                said.push(msg);
+                next
+            }
+            Either::Left(Cmd::Ask(next)) => next(41),
+        };
+    };
+    assert_eq!((n, said), (42, vec!["hi".to_owned()]));
+}
+
+#[derive(Clone, K1, Functor)]
+enum ExprF<R> {
+    Lit(i64),
+    Var(&'static str),
+    Add(R, R),
+    Mul(R, R),
+    Neg(R),
+}
+
+type Expr = Fix<ExprF<()>>;
+
+fn lit(n: i64) -> Expr {
+    Fix::new(ExprF::Lit(n))
+}
+
+fn var(name: &'static str) -> Expr {
+    Fix::new(ExprF::Var(name))
+}
+
+fn add(a: Expr, b: Expr) -> Expr {
+    Fix::new(ExprF::Add(a, b))
+}
+
+fn mul(a: Expr, b: Expr) -> Expr {
+    Fix::new(ExprF::Mul(a, b))
+}
+
+fn neg(a: Expr) -> Expr {
+    Fix::new(ExprF::Neg(a))
+}
+
+fn eval(expr: Expr, x: i64) -> i64 {
+    expr.cata(move |layer: ExprF<i64>| match layer {
+        ExprF::Lit(n) => n,
+        ExprF::Var(_) => x,
+        ExprF::Add(a, b) => a + b,
+        ExprF::Mul(a, b) => a * b,
+        ExprF::Neg(a) => -a,
+    })
+}
+
+fn show(expr: Expr) -> String {
+    expr.cata(|layer: ExprF<String>| match layer {
+        ExprF::Lit(n) => n.to_string(),
+        ExprF::Var(name) => name.to_owned(),
+        ExprF::Add(a, b) => format!("({a} + {b})"),
+        ExprF::Mul(a, b) => format!("{a} * {b}"),
+        ExprF::Neg(a) => format!("-{a}"),
+    })
+}
+
+#[test]
+fn derived_base_functors_fold_with_cata() {
+    let poly = || add(mul(lit(3), mul(var("x"), var("x"))), neg(var("x")));
+    assert_eq!(eval(poly(), 2), 10);
+    assert_eq!(eval(poly(), -1), 4);
+    assert_eq!(show(poly()), "(3 * x * x + -x)");
+
+    // `x^n` unfolded from `Some(n)`, where `None` seeds a lone `x`.
+    let power = |n: u32| {
+        Fix::ana(Some(n), |seed| match seed {
+            None => ExprF::Var("x"),
+            Some(0) => ExprF::Lit(1),
+            Some(n) => ExprF::Mul(None, Some(n - 1)),
+        })
+    };
+    assert_eq!(show(power(2)), "x * x * 1");
+    assert_eq!(eval(power(3), 3), 27);
+}
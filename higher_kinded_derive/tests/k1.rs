@@ -0,0 +1,39 @@
+// These types only exist to be type checked.
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+use higher_kinded::kind::K1;
+use higher_kinded::K1;
+
+// Only type checks if `K` is `Inner = A` and `With<B> = W`.
+fn assert_k1<K: K1<Inner = A, With<B> = W>, A, B, W>() {}
+
+#[derive(K1)]
+struct Pair<A, B>(A, B);
+
+#[derive(K1)]
+struct Tagged<T>(PhantomData<T>);
+
+#[derive(K1)]
+enum Tree<T> {
+    Leaf(T),
+    Node(Box<Tree<T>>, Box<Tree<T>>),
+}
+
+#[derive(K1)]
+struct Borrowed<'a, C: Clone, const N: usize, T>
+where
+    C: Default,
+{
+    arr: [&'a C; N],
+    val: T,
+}
+
+#[test]
+fn derives_over_the_last_param() {
+    assert_k1::<Pair<u8, i32>, i32, String, Pair<u8, String>>();
+    assert_k1::<Tagged<()>, (), u8, Tagged<u8>>();
+    assert_k1::<Tree<i32>, i32, bool, Tree<bool>>();
+    assert_k1::<Borrowed<'static, u8, 2, ()>, (), i8, Borrowed<'static, u8, 2, i8>>();
+}
@@ -0,0 +1,127 @@
+//! Lining up structures of different shapes.
+
+use core::fmt;
+
+use crate::functor::Functor;
+use crate::these::These;
+
+/// A [`Functor`] whose values can be combined position by position, keeping the positions only
+/// one side has.
+///
+/// Where [`Applicative::zip_with`](crate::applicative::Applicative::zip_with) on `Vec` stops at
+/// the shorter vector, `align_with` carries on with [`These::This`] or [`These::That`] for the
+/// rest of the longer one.
+pub trait Semialign: Functor {
+    fn align_with<B, C>(
+        self,
+        other: Self::With<B>,
+        f: impl FnMut(These<Self::Inner, B>) -> C,
+    ) -> Self::With<C>;
+
+    fn align<B>(self, other: Self::With<B>) -> Self::With<These<Self::Inner, B>>
+    where
+        Self: Sized,
+    {
+        self.align_with(other, |these| these)
+    }
+
+    /// Pairs up the elements, with `None` standing in for the side that ran out.
+    fn zip_padded<B>(self, other: Self::With<B>) -> Self::With<(Option<Self::Inner>, Option<B>)>
+    where
+        Self: Sized,
+    {
+        self.align_with(other, These::into_options)
+    }
+}
+
+/// A [`Semialign`] with an empty structure, `nil`, that aligning with changes nothing:
+/// `x.align(Self::nil()) == x.fmap(These::This)`.
+pub trait Align: Semialign {
+    fn nil<X>() -> Self::With<X>;
+}
+
+/// A sequence whose zips say what happens when the lengths don't match.
+///
+/// [`Applicative::zip_with`](crate::applicative::Applicative::zip_with) on `Vec` silently drops
+/// the leftovers of the longer side. `Zip` makes that one choice among several:
+/// - [`zip_with_truncated`](Zip::zip_with_truncated) stops at the shorter sequence,
+/// - [`zip_with_padded`](Zip::zip_with_padded) and [`zip_with_fill`](Zip::zip_with_fill) carry
+///   on to the end of the longer one, filling in for the side that ran out,
+/// - [`try_zip_exact_with`](Zip::try_zip_exact_with) fails unless the lengths are the same.
+pub trait Zip: Semialign {
+    /// Combines the elements pairwise, stopping at the end of the shorter sequence.
+    fn zip_with_truncated<B, C>(
+        self,
+        other: Self::With<B>,
+        f: impl FnMut(Self::Inner, B) -> C,
+    ) -> Self::With<C>;
+
+    /// Combines the elements pairwise, or returns the lengths without calling `f` if they
+    /// differ.
+    fn try_zip_exact_with<B, C>(
+        self,
+        other: Self::With<B>,
+        f: impl FnMut(Self::Inner, B) -> C,
+    ) -> Result<Self::With<C>, LengthMismatch>;
+
+    fn try_zip_exact<B>(
+        self,
+        other: Self::With<B>,
+    ) -> Result<Self::With<(Self::Inner, B)>, LengthMismatch>
+    where
+        Self: Sized,
+    {
+        self.try_zip_exact_with(other, |a, b| (a, b))
+    }
+
+    /// Combines the elements pairwise up to the end of the longer sequence, calling `fill_left`
+    /// or `fill_right` for each element the shorter one is missing.
+    fn zip_with_fill<B, C>(
+        self,
+        other: Self::With<B>,
+        mut fill_left: impl FnMut() -> Self::Inner,
+        mut fill_right: impl FnMut() -> B,
+        mut f: impl FnMut(Self::Inner, B) -> C,
+    ) -> Self::With<C>
+    where
+        Self: Sized,
+    {
+        self.align_with(other, |these| match these {
+            These::This(a) => f(a, fill_right()),
+            These::That(b) => f(fill_left(), b),
+            These::Both(a, b) => f(a, b),
+        })
+    }
+
+    /// [`zip_with_fill`](Zip::zip_with_fill), padding with `Default::default()`.
+    fn zip_with_padded<B: Default, C>(
+        self,
+        other: Self::With<B>,
+        f: impl FnMut(Self::Inner, B) -> C,
+    ) -> Self::With<C>
+    where
+        Self: Sized,
+        Self::Inner: Default,
+    {
+        self.zip_with_fill(other, Default::default, Default::default, f)
+    }
+}
+
+/// The lengths of the two sides of a failed [`Zip::try_zip_exact_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "can't zip a sequence of length {} with one of length {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl core::error::Error for LengthMismatch {}
@@ -0,0 +1,32 @@
+//! Applicatives with a notion of failure and choice.
+
+use crate::applicative::Applicative;
+
+/// An [`Applicative`] with an identity `empty` and an associative choice `or`.
+///
+/// Instances should satisfy `Self::empty().or(x) == x == x.or(Self::empty())`. Like
+/// [`Applicative::pure`], `empty` can produce any application of the constructor.
+pub trait Alternative: Applicative {
+    fn empty<X: 'static>() -> Self::With<X>;
+
+    fn or(self, other: Self) -> Self;
+
+    /// Succeeds with `Some` if `self` does, and with `None` otherwise.
+    fn optional(self) -> Self::With<Option<Self::Inner>>
+    where
+        Self: Sized,
+        Self::Inner: 'static,
+        Self::With<Option<Self::Inner>>: Alternative,
+    {
+        self.fmap(Some).or(Self::pure(None))
+    }
+
+    /// Succeeds with `()` if `cond` holds, and is `empty` otherwise.
+    fn guard(cond: bool) -> Self::With<()> {
+        if cond {
+            Self::pure(())
+        } else {
+            Self::empty()
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Applicative functors.
+
+use crate::functor::Functor;
+
+/// A functor that can lift plain values and combine two effectful values.
+///
+/// `pure` lifts into any application of the constructor, not just `Self`, so generic code can
+/// build e.g. an `F::With<Vec<B>>` from nothing but an `F`. For concrete types that means the
+/// inner type of `Self` has to be spelled out, as in `Option::<()>::pure(1)`.
+///
+/// `zip_with` runs the effects of `self` before those of `b`. Like the closures, every type passed
+/// through these methods has to be `'static`, since lazy instances capture values of them.
+pub trait Applicative: Functor {
+    fn pure<X: 'static>(val: X) -> Self::With<X>;
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Self::With<B>,
+        f: impl FnMut(Self::Inner, B) -> C + 'static,
+    ) -> Self::With<C>;
+}
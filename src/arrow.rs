@@ -0,0 +1,153 @@
+//! Categories and arrows: composable function-like types.
+
+use crate::kind::K2;
+#[cfg(feature = "alloc")]
+use crate::profunctor::FnWrap;
+
+/// A [`K2`] whose values can be composed like functions from `Inner1` to `Inner2`.
+///
+/// Instances should satisfy `f.compose(Self::id()) == f == Self::id().compose(f)`, and
+/// `compose` should be associative.
+pub trait Category: K2 {
+    /// The arrow that returns its input unchanged.
+    fn id<A: 'static>() -> Self::With<A, A>;
+
+    /// Runs `prev`, then `self` on its output.
+    fn compose<Z: 'static>(self, prev: Self::With<Z, Self::Inner1>) -> Self::With<Z, Self::Inner2>;
+}
+
+/// A [`Category`] that plain functions can be lifted into, and that can run on one half of a
+/// pair while passing the other half through.
+///
+/// With these, arrows can be wired together into pipelines without naming their inputs:
+/// [`split`](Arrow::split) runs two arrows side by side on a pair, and
+/// [`fanout`](Arrow::fanout) runs two arrows on copies of the same input.
+///
+/// `K2` can't say that re-applying `Self::With` gives back the same family of arrows, so the
+/// combinators built from [`compose`](Category::compose) state it in their `where` clauses. They
+/// always hold for concrete arrow types.
+// The pair types in the signatures trip `type_complexity`, but naming them wouldn't help.
+#[allow(clippy::type_complexity)]
+pub trait Arrow: Category {
+    fn arr<A: 'static, B: 'static>(f: impl Fn(A) -> B + 'static) -> Self::With<A, B>;
+
+    /// Runs `self` on the first half of a pair, passing the second half through.
+    fn first<C: Clone + 'static>(self) -> Self::With<(Self::Inner1, C), (Self::Inner2, C)>;
+
+    /// Runs `self` on the second half of a pair, passing the first half through.
+    fn second<C: Clone + 'static>(self) -> Self::With<(C, Self::Inner1), (C, Self::Inner2)>;
+
+    /// Runs `self` on the first half of a pair and `other` on the second. This is Haskell's
+    /// `***`.
+    fn split<C, D>(
+        self,
+        other: Self::With<C, D>,
+    ) -> Self::With<(Self::Inner1, C), (Self::Inner2, D)>
+    where
+        Self: Sized,
+        Self::Inner1: 'static,
+        Self::Inner2: Clone + 'static,
+        C: Clone + 'static,
+        D: 'static,
+        Self::With<C, D>: Arrow,
+        <Self::With<C, D> as K2>::With<(Self::Inner2, C), (Self::Inner2, D)>: Category
+            + K2<
+                With<(Self::Inner1, C), (Self::Inner2, C)> = Self::With<
+                    (Self::Inner1, C),
+                    (Self::Inner2, C),
+                >,
+            > + K2<
+                With<(Self::Inner1, C), (Self::Inner2, D)> = Self::With<
+                    (Self::Inner1, C),
+                    (Self::Inner2, D),
+                >,
+            >,
+    {
+        other
+            .second::<Self::Inner2>()
+            .compose::<(Self::Inner1, C)>(self.first::<C>())
+    }
+
+    /// Runs `self` and `other` on copies of the same input, pairing up their outputs. This is
+    /// Haskell's `&&&`.
+    fn fanout<C>(
+        self,
+        other: Self::With<Self::Inner1, C>,
+    ) -> Self::With<Self::Inner1, (Self::Inner2, C)>
+    where
+        Self: Sized,
+        Self::Inner1: Clone + 'static,
+        Self::Inner2: Clone + 'static,
+        C: 'static,
+        Self::With<Self::Inner1, C>: Arrow,
+        <Self::With<Self::Inner1, C> as K2>::With<(Self::Inner2, Self::Inner1), (Self::Inner2, C)>:
+            Category
+                + K2<
+                    With<(Self::Inner1, Self::Inner1), (Self::Inner2, Self::Inner1)> = Self::With<
+                        (Self::Inner1, Self::Inner1),
+                        (Self::Inner2, Self::Inner1),
+                    >,
+                > + K2<
+                    With<(Self::Inner1, Self::Inner1), (Self::Inner2, C)> = Self::With<
+                        (Self::Inner1, Self::Inner1),
+                        (Self::Inner2, C),
+                    >,
+                >,
+        Self::With<(Self::Inner1, Self::Inner1), (Self::Inner2, C)>: Category
+            + K2<
+                With<Self::Inner1, (Self::Inner1, Self::Inner1)> = Self::With<
+                    Self::Inner1,
+                    (Self::Inner1, Self::Inner1),
+                >,
+            > + K2<With<Self::Inner1, (Self::Inner2, C)> = Self::With<Self::Inner1, (Self::Inner2, C)>>,
+    {
+        let dup = Self::arr(|a: Self::Inner1| (a.clone(), a));
+        self.split(other).compose::<Self::Inner1>(dup)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, B: 'static> Category for FnWrap<A, B> {
+    fn id<X: 'static>() -> FnWrap<X, X> {
+        FnWrap::new(|x| x)
+    }
+
+    fn compose<Z: 'static>(mut self, mut prev: FnWrap<Z, A>) -> FnWrap<Z, B> {
+        FnWrap::new(move |z| self.call(prev.call(z)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, B: 'static> Arrow for FnWrap<A, B> {
+    fn arr<X: 'static, Y: 'static>(f: impl Fn(X) -> Y + 'static) -> FnWrap<X, Y> {
+        FnWrap::new(f)
+    }
+
+    fn first<C: Clone + 'static>(mut self) -> FnWrap<(A, C), (B, C)> {
+        FnWrap::new(move |(a, c)| (self.call(a), c))
+    }
+
+    fn second<C: Clone + 'static>(mut self) -> FnWrap<(C, A), (C, B)> {
+        FnWrap::new(move |(c, a)| (c, self.call(a)))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn functions_compose_point_free() {
+        let len = FnWrap::new(|s: &str| s.len());
+        let double = FnWrap::new(|n: usize| n * 2);
+        let mut f = double.compose(len).compose(FnWrap::<(), ()>::id());
+        assert_eq!(f.call("abc"), 6);
+
+        let mut both = FnWrap::new(|n: i32| n + 1).split(FnWrap::<(), ()>::arr(|s: &str| s.len()));
+        assert_eq!(both.call((1, "ab")), (2, 2));
+
+        let mut stats = FnWrap::new(|v: Vec<i32>| v.iter().sum::<i32>())
+            .fanout(FnWrap::new(|v: Vec<i32>| v.len()));
+        assert_eq!(stats.call(vec![1, 2, 3]), (6, 3));
+    }
+}
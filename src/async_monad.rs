@@ -0,0 +1,90 @@
+//! Functors and monads that can be mapped and bound with `async` functions.
+
+use core::future::Future;
+
+use crate::functor::Functor;
+use crate::monad::Monad;
+
+/// A [`Functor`] that can be mapped with an `async` function, without blocking on it.
+///
+/// The mapped computation has to wait for `f`'s futures, so it's a
+/// [`Suspended`](AsyncFunctor::Suspended) one: a future of the result.
+/// [`FutureK`](crate::future::FutureK) suspends into itself, and [`Io`](crate::io::Io) into a
+/// `FutureK` that runs its effects when it's first polled.
+///
+/// `f` can be an `async` closure, or a plain closure that returns a future.
+///
+/// Instances should agree with [`fmap`](Functor::fmap):
+/// - awaiting `x.fmap_async(async |a| f(a))` gives the same result as running `x.fmap(f)`
+pub trait AsyncFunctor: Functor {
+    /// `Self` once it has to await something, producing a `B`.
+    type Suspended<B: 'static>: Future<Output = B>;
+
+    fn fmap_async<B: 'static>(
+        self,
+        f: impl AsyncFnMut(Self::Inner) -> B + 'static,
+    ) -> Self::Suspended<B>;
+}
+
+/// A [`Monad`] that can be bound with an `async` function, whose future gives the rest of the
+/// computation.
+///
+/// Instances should agree with [`bind`](Monad::bind):
+/// - awaiting `x.bind_async(async |a| f(a))` gives the same result as running `x.bind(f)`
+pub trait AsyncMonad: AsyncFunctor + Monad {
+    fn bind_async<B: 'static>(
+        self,
+        f: impl AsyncFnMut(Self::Inner) -> Self::With<B> + 'static,
+    ) -> Self::Suspended<B>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::applicative::Applicative;
+    use crate::future::FutureK;
+    use crate::io::Io;
+    use crate::util::block_on;
+
+    // Looks up a user's name and greets them, for any effect that can await the lookup.
+    fn greet<M: AsyncMonad<Inner = u32> + 'static>(user: M) -> M::Suspended<String> {
+        user.bind_async(async |id| {
+            let name = lookup(id).await;
+            M::pure(format!("hello, {name}"))
+        })
+    }
+
+    async fn lookup(id: u32) -> &'static str {
+        ["ann", "bob"][id as usize]
+    }
+
+    #[test]
+    fn awaits_inside_either_effect() {
+        assert_eq!(block_on(greet(FutureK::<u32>::pure(1))), "hello, bob");
+
+        let ran = Rc::new(RefCell::new(false));
+        let user = Io::new({
+            let ran = Rc::clone(&ran);
+            move || {
+                *ran.borrow_mut() = true;
+                0
+            }
+        });
+        let greeting = greet(user);
+        assert!(!*ran.borrow());
+        assert_eq!(block_on(greeting), "hello, ann");
+        assert!(*ran.borrow());
+    }
+
+    #[test]
+    fn fmap_async_awaits_the_function() {
+        let len =
+            FutureK::<&str>::pure("four").fmap_async(async |s| lookup(0).await.len() + s.len());
+        assert_eq!(block_on(len), 7);
+        let io = Io::new(|| 2).fmap_async(|n| async move { n * 3 });
+        assert_eq!(block_on(io), 6);
+    }
+}
@@ -0,0 +1,32 @@
+//! Functors in two parameters.
+
+use crate::kind::K2;
+
+/// A [`K2`] that can be mapped over in both of its parameters independently.
+///
+/// "First" and "second" refer to declaration order, so for `Result<T, E>` `map_first` maps the
+/// `Ok` value and `map_second` maps the error.
+///
+/// Instances should satisfy `x.bimap(|a| a, |b| b) == x`, and `bimap` should agree with
+/// `map_first` followed by `map_second`.
+pub trait Bifunctor: K2 {
+    fn bimap<C, D>(
+        self,
+        f: impl FnMut(Self::Inner1) -> C,
+        g: impl FnMut(Self::Inner2) -> D,
+    ) -> Self::With<C, D>;
+
+    fn map_first<C>(self, f: impl FnMut(Self::Inner1) -> C) -> Self::With<C, Self::Inner2>
+    where
+        Self: Sized,
+    {
+        self.bimap(f, |b| b)
+    }
+
+    fn map_second<D>(self, g: impl FnMut(Self::Inner2) -> D) -> Self::With<Self::Inner1, D>
+    where
+        Self: Sized,
+    {
+        self.bimap(|a| a, g)
+    }
+}
@@ -0,0 +1,307 @@
+//! The codensity monad, for reassociating binds.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_rec::MonadRec;
+use crate::util::once;
+
+type Erased = Box<dyn Any>;
+
+// The function passed to a `bind`. It's shared, since `lower` hands the binds still to run to
+// each result `M`'s `fmap` gives, and `fmap` alone doesn't promise there's only one.
+type Kont<M> = Rc<RefCell<dyn FnMut(Erased) -> Raw<M>>>;
+
+// A computation with the types of its results erased: how it starts, and the binds to run on its
+// result, first to last.
+struct Raw<M: K1> {
+    head: Head<M>,
+    binds: Vec<Kont<M>>,
+}
+
+enum Head<M: K1> {
+    Pure(Erased),
+    Lift(M::With<Erased>),
+}
+
+impl<M: K1> Raw<M> {
+    fn new(head: Head<M>) -> Self {
+        Raw {
+            head,
+            binds: Vec::new(),
+        }
+    }
+}
+
+// The binds still to run while lowering, as a stack. Each result of a lifted computation carries
+// on with its own copy, so it's a linked list that the copies share.
+struct Binds<M: K1>(Option<Rc<Link<M>>>);
+
+struct Link<M: K1> {
+    k: Kont<M>,
+    next: Binds<M>,
+}
+
+impl<M: K1> Binds<M> {
+    // `binds` are run first to last, so the last goes on the bottom.
+    fn push_all(&mut self, binds: Vec<Kont<M>>) {
+        for k in binds.into_iter().rev() {
+            let next = Binds(self.0.take());
+            self.0 = Some(Rc::new(Link { k, next }));
+        }
+    }
+
+    fn pop(&mut self) -> Option<Kont<M>> {
+        let link = self.0.take()?;
+        match Rc::try_unwrap(link) {
+            Ok(mut link) => {
+                self.0 = link.next.0.take();
+                Some(link.k)
+            }
+            Err(shared) => {
+                self.0 = shared.next.0.clone();
+                Some(shared.k.clone())
+            }
+        }
+    }
+}
+
+impl<M: K1> Clone for Binds<M> {
+    fn clone(&self) -> Self {
+        Binds(self.0.clone())
+    }
+}
+
+// Dropping a long stack would recurse once per link, so the links no other copy shares are
+// unlinked in a loop.
+impl<M: K1> Drop for Binds<M> {
+    fn drop(&mut self) {
+        let mut link = self.0.take();
+        while let Some(rc) = link {
+            link = match Rc::try_unwrap(rc) {
+                Ok(mut unique) => unique.next.0.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+// Where lowering has got to: the next step, and the binds to run on its result.
+struct Lowering<M: K1> {
+    head: Head<M>,
+    rest: Binds<M>,
+}
+
+/// A computation in the monad `M` whose [`bind`](Monad::bind)s are kept in a list, to be run
+/// when it's [`lower`](Codensity::lower)ed.
+///
+/// Binding onto a `Codensity` only adds the function to the end of the list, so however the
+/// binds were nested, they reach `M` nested to the right. That turns a loop that keeps binding
+/// onto the end of a computation from quadratic to linear for monads whose `bind` walks what
+/// came before, like [`Free`](crate::free::Free), which is what
+/// [`FreeC`](crate::free_c::FreeC) is. Lowering runs the list with `M`'s
+/// [`tail_rec_m`](MonadRec::tail_rec_m), one lifted computation at a time, so it doesn't grow
+/// the stack either, however long the list is.
+///
+/// As with `Free`, `M` only stands in for its constructor, e.g. `Codensity<Option<()>, A>`
+/// lowers to an `Option<A>`.
+///
+/// The [`Applicative`] and `Monad` instances need `M` to be a [`FunctorOnce`], since `zip_with`
+/// moves its second argument into the rest of the computation rather than cloning it. So monads
+/// that run the rest more than once, like `Vec`, [`Logic`](crate::logic::Logic) or `StreamK`, get
+/// no `Monad` here, and couldn't be lowered anyway, not being a `MonadRec`:
+///
+/// ```compile_fail
+/// use higher_kinded::codensity::Codensity;
+/// use higher_kinded::monad::Monad;
+///
+/// let pairs = Codensity::<Vec<()>, u32>::lift(vec![1, 2]).bind(|n| Codensity::lift(vec![n, n]));
+/// ```
+pub struct Codensity<M: K1, A> {
+    raw: Raw<M>,
+    result: PhantomData<fn() -> A>,
+}
+
+impl<M: K1 + 'static, A: 'static> Codensity<M, A> {
+    fn from_raw(raw: Raw<M>) -> Self {
+        Codensity {
+            raw,
+            result: PhantomData,
+        }
+    }
+
+    /// Passes the result of a computation in `M` on to the rest.
+    pub fn lift(ma: M::With<A>) -> Self
+    where
+        M::With<A>: Functor<Inner = A> + K1<With<Erased> = M::With<Erased>>,
+    {
+        let erased = ma.fmap(|a| Box::new(a) as Erased);
+        Codensity::from_raw(Raw::new(Head::Lift(erased)))
+    }
+
+    /// Runs the computation in `M`.
+    pub fn lower(self) -> M::With<A>
+    where
+        M::With<Erased>: MonadRec<Inner = Erased> + K1<With<A> = M::With<A>>,
+    {
+        let mut start = Lowering {
+            head: self.raw.head,
+            rest: Binds(None),
+        };
+        start.rest.push_all(self.raw.binds);
+        <M::With<Erased>>::tail_rec_m(start, |mut at: Lowering<M>| loop {
+            match at.head {
+                Head::Pure(a) => match at.rest.pop() {
+                    Some(k) => {
+                        let next = (k.borrow_mut())(a);
+                        at.rest.push_all(next.binds);
+                        at.head = next.head;
+                    }
+                    None => {
+                        let a = *a.downcast::<A>().expect("result of the wrong type");
+                        return <M::With<Erased>>::pure(Either::Right(a));
+                    }
+                },
+                Head::Lift(ma) => {
+                    let rest = at.rest;
+                    return ma.fmap(move |a| {
+                        Either::Left(Lowering {
+                            head: Head::Pure(a),
+                            rest: rest.clone(),
+                        })
+                    });
+                }
+            }
+        })
+    }
+
+    fn then<B: 'static>(mut self, mut f: impl FnMut(A) -> Raw<M> + 'static) -> Codensity<M, B> {
+        self.raw.binds.push(Rc::new(RefCell::new(move |a: Erased| {
+            f(*a.downcast::<A>().expect("result of the wrong type"))
+        })));
+        Codensity::from_raw(self.raw)
+    }
+}
+
+impl<M: K1, A> K1 for Codensity<M, A> {
+    type Inner = A;
+
+    type With<I> = Codensity<M, I>;
+}
+
+impl<M: K1 + 'static, A: 'static> Functor for Codensity<M, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Codensity<M, B> {
+        self.then(move |a| Raw::new(Head::Pure(Box::new(f(a)))))
+    }
+}
+
+impl<M: K1 + 'static, A: 'static> FunctorWith for Codensity<M, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Codensity<M, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Codensity<M, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<M: K1 + 'static, A: 'static> FunctorOnce for Codensity<M, A> where M::With<Erased>: FunctorOnce {}
+
+/// `zip_with` runs all of `self`, then all of `b`. `b` is moved into the rest of the
+/// computation rather than cloned, so `M` has to be a [`FunctorOnce`], which only runs it once.
+impl<M: K1 + 'static, A: 'static> Applicative for Codensity<M, A>
+where
+    M::With<Erased>: FunctorOnce,
+{
+    fn pure<X: 'static>(val: X) -> Codensity<M, X> {
+        Codensity::from_raw(Raw::new(Head::Pure(Box::new(val))))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Codensity<M, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Codensity<M, C> {
+        self.bind(once(move |a| b.fmap(once(move |b| f(a, b)))))
+    }
+}
+
+impl<M: K1 + 'static, A: 'static> Monad for Codensity<M, A>
+where
+    M::With<Erased>: FunctorOnce,
+{
+    fn bind<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> Codensity<M, B> + 'static,
+    ) -> Codensity<M, B> {
+        self.then(move |a| f(a).raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::free::Free;
+
+    type Program<A> = Free<Option<()>, A>;
+
+    fn run(mut prog: Program<u32>) -> Option<u32> {
+        loop {
+            match prog.resume() {
+                Either::Right(n) => return Some(n),
+                Either::Left(next) => prog = next?,
+            }
+        }
+    }
+
+    #[test]
+    fn left_nested_binds_over_free() {
+        let mut prog = Codensity::<Program<()>, u32>::pure(0);
+        for _ in 0..1_000 {
+            prog = prog.bind(|n| Codensity::lift(Free::lift_f(Some(n + 1))));
+        }
+        assert_eq!(run(prog.lower()), Some(1_000));
+
+        let stop = Codensity::<Program<()>, u32>::lift(Free::lift_f(None))
+            .bind(|n| Codensity::lift(Free::Pure(n + 1)));
+        assert_eq!(run(stop.lower()), None);
+    }
+
+    #[test]
+    fn lowers_to_the_base_monad() {
+        let sum = Codensity::<Option<()>, _>::lift(Some(2))
+            .zip_with(Codensity::lift(Some(3)), |a, b| a + b)
+            .fmap(|n| n * 10);
+        assert_eq!(sum.lower(), Some(50));
+        let none = Codensity::<Option<()>, i32>::lift(None).bind(|n| Codensity::lift(Some(n + 1)));
+        assert_eq!(none.lower(), None);
+    }
+
+    #[test]
+    fn lowers_long_computations_in_constant_stack() {
+        const LONG: u32 = 100_000;
+
+        let mut left = Codensity::<Option<()>, u32>::pure(0);
+        for _ in 0..LONG {
+            left = left.bind(|n| Codensity::lift(Some(n + 1)));
+        }
+        assert_eq!(left.lower(), Some(LONG));
+
+        fn count_up(n: u32) -> Codensity<Option<()>, u32> {
+            if n == LONG {
+                Codensity::<Option<()>, u32>::pure(n)
+            } else {
+                Codensity::lift(Some(n + 1)).bind(count_up)
+            }
+        }
+        assert_eq!(count_up(0).lower(), Some(LONG));
+    }
+}
@@ -0,0 +1,179 @@
+//! The cofree comonad over a functor.
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::comonad::Comonad;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+
+/// A tree with an `A` at every node and the node's children laid out in an `F` layer.
+///
+/// Where [`Free`](crate::free::Free) has values only at its leaves, `Cofree` annotates every
+/// level: `Cofree<Option<()>, A>` is a non-empty list, and `Cofree<Vec<()>, A>` is a rose tree.
+/// [`extend`](Comonad::extend) runs a function on every subtree, which makes it a natural way to
+/// compute annotations from the structure below each node. As with `Free`, `F` only stands in
+/// for its constructor and is conventionally applied to `()`.
+///
+/// The children are behind an `Rc` so that subtrees can be handed to `extend`'s function while
+/// they're still needed to build the rest of the result. Mapping and extending copy each layer
+/// of children, which only clones the `Rc`s inside, so those instances need the layer to be
+/// `Clone`, as it is for `Option` and `Vec`. That can't be stated for every application of the
+/// constructor at once, so there's no [`FunctorWith`] instance.
+pub struct Cofree<F: K1, A> {
+    head: A,
+    tail: Rc<F::With<Cofree<F, A>>>,
+}
+
+impl<F: K1, A: Clone> Clone for Cofree<F, A> {
+    fn clone(&self) -> Self {
+        Cofree {
+            head: self.head.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+
+type Mapper<A, B> = Rc<RefCell<dyn FnMut(A) -> B>>;
+
+type Unfolder<F, S, A> = Rc<RefCell<dyn FnMut(S) -> (A, <F as K1>::With<S>)>>;
+
+impl<F: FunctorWith + 'static, A: 'static> Cofree<F, A> {
+    pub fn new(head: A, tail: F::With<Cofree<F, A>>) -> Self {
+        Cofree {
+            head,
+            tail: Rc::new(tail),
+        }
+    }
+
+    /// Grows a tree from `seed`: `f` gives each node's value and the seeds of its children.
+    ///
+    /// The whole tree is built up front, so `f` has to eventually produce layers with no
+    /// children, like `None` or an empty `Vec`.
+    pub fn unfold<S: 'static>(seed: S, f: impl FnMut(S) -> (A, F::With<S>) + 'static) -> Self {
+        Cofree::unfold_shared(seed, Rc::new(RefCell::new(f)))
+    }
+
+    // Shares `f` between every level, like `Free`'s `bind_shared`.
+    fn unfold_shared<S: 'static>(seed: S, f: Unfolder<F, S, A>) -> Self {
+        let (head, layer) = (f.borrow_mut())(seed);
+        Cofree::new(
+            head,
+            F::fmap_with(layer, move |s| Cofree::unfold_shared(s, f.clone())),
+        )
+    }
+
+    /// Grows a tree in which each node's children are computed from its value.
+    pub fn coiter(head: A, mut f: impl FnMut(&A) -> F::With<A> + 'static) -> Self {
+        Cofree::unfold(head, move |a| {
+            let children = f(&a);
+            (a, children)
+        })
+    }
+
+    pub fn head(&self) -> &A {
+        &self.head
+    }
+
+    pub fn tail(&self) -> &F::With<Cofree<F, A>> {
+        &self.tail
+    }
+}
+
+impl<F: FunctorWith + 'static, A: 'static> Cofree<F, A>
+where
+    F::With<Cofree<F, A>>: Clone,
+{
+    // Takes the children out of their `Rc`, copying the layer if anything else still has it.
+    fn into_parts(self) -> (A, F::With<Cofree<F, A>>) {
+        let tail = Rc::try_unwrap(self.tail).unwrap_or_else(|tail| (*tail).clone());
+        (self.head, tail)
+    }
+
+    fn fmap_shared<B: 'static>(self, f: Mapper<A, B>) -> Cofree<F, B> {
+        let (head, tail) = self.into_parts();
+        let head = (f.borrow_mut())(head);
+        Cofree::new(
+            head,
+            F::fmap_with(tail, move |child: Self| child.fmap_shared(f.clone())),
+        )
+    }
+
+    fn extend_shared<B: 'static>(self, f: Mapper<Self, B>) -> Cofree<F, B> {
+        let tail = (*self.tail).clone();
+        let head = (f.borrow_mut())(self);
+        Cofree::new(
+            head,
+            F::fmap_with(tail, move |child: Self| child.extend_shared(f.clone())),
+        )
+    }
+}
+
+impl<F: K1, A> K1 for Cofree<F, A> {
+    type Inner = A;
+
+    type With<I> = Cofree<F, I>;
+}
+
+impl<F: FunctorWith + 'static, A: 'static> Functor for Cofree<F, A>
+where
+    F::With<Cofree<F, A>>: Clone,
+{
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Cofree<F, B> {
+        self.fmap_shared(Rc::new(RefCell::new(f)))
+    }
+}
+
+impl<F: FunctorWith + 'static, A: 'static> Comonad for Cofree<F, A>
+where
+    F::With<Cofree<F, A>>: Clone,
+{
+    fn extract(self) -> A {
+        self.head
+    }
+
+    fn extend<B: 'static>(self, f: impl FnMut(Self) -> B + 'static) -> Cofree<F, B> {
+        self.extend_shared(Rc::new(RefCell::new(f)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foldable::Foldable;
+
+    type Rose<A> = Cofree<Vec<()>, A>;
+
+    fn sum(tree: &Rose<i32>) -> i32 {
+        tree.head() + tree.tail().iter().map(sum).sum::<i32>()
+    }
+
+    #[test]
+    fn extend_annotates_subtrees() {
+        // 3 has children 1 and 2, 2 has child 1.
+        let tree = Rose::coiter(3, |&n| (1..n).rev().take(2).collect());
+        assert_eq!(sum(&tree), 3 + 2 + 1 + 1);
+        let sums = tree.extend(|t| sum(&t));
+        assert_eq!(*sums.head(), 7);
+        let children: Vec<i32> = sums.tail().iter().map(|t| *t.head()).collect();
+        assert_eq!(children, [3, 1]);
+    }
+
+    #[test]
+    fn non_empty_list() {
+        let countdown = Cofree::<Option<()>, u32>::unfold(3, |n| (n * 10, n.checked_sub(1)));
+        let suffix_lengths = countdown.clone().extend(|list| {
+            let mut len = 1;
+            let mut rest = list.tail().clone();
+            while let Some(next) = rest {
+                len += 1;
+                rest = next.tail().clone();
+            }
+            len
+        });
+        assert_eq!(*suffix_lengths.head(), 4);
+        let doubled = countdown.fmap(|n| n * 2);
+        assert_eq!(doubled.tail().clone().fold_left(0, |_, t| *t.head()), 40);
+        assert_eq!(doubled.extract(), 60);
+    }
+}
@@ -0,0 +1,32 @@
+//! Comonads, the dual of monads.
+
+use crate::functor::Functor;
+
+/// A functor whose values sit in a context that can always be taken apart again.
+///
+/// Where a monad lets you put a value into a context and chain computations that produce new
+/// contexts, a comonad lets you take a value out with [`extract`](Comonad::extract) and chain
+/// computations that consume a whole context with [`extend`](Comonad::extend). `extend` runs
+/// `f` once for every position in `self`, each time on `self` refocused at that position. Like
+/// `fmap`'s, its closure is `'static` so that lazy instances can hold on to it.
+///
+/// Instances should satisfy:
+/// - `w.extend(|w| w.extract()) == w`
+/// - `w.extend(f).extract() == f(w)`
+/// - `w.extend(g).extend(f) == w.extend(|w| f(w.extend(g)))`
+pub trait Comonad: Functor {
+    /// Returns the value at the focus.
+    fn extract(self) -> Self::Inner;
+
+    fn extend<B: 'static>(self, f: impl FnMut(Self) -> B + 'static) -> Self::With<B>
+    where
+        Self: Sized;
+
+    /// Replaces every value with the whole context focused at its position.
+    fn duplicate(self) -> Self::With<Self>
+    where
+        Self: Sized + 'static,
+    {
+        self.extend(|w| w)
+    }
+}
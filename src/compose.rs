@@ -0,0 +1,115 @@
+//! Composition of two functors.
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+
+/// An `F<G<A>>` seen as a single type constructor in `A`.
+///
+/// Like the [transformers](crate::trans), this is parameterised by the nested type itself, so
+/// `Compose<Vec<Option<A>>>` maps to `Compose<Vec<Option<B>>>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compose<FG>(pub FG);
+
+impl<FG> Compose<FG> {
+    pub fn into_inner(self) -> FG {
+        self.0
+    }
+}
+
+impl<FG: K1<Inner = G>, G: K1> K1 for Compose<FG> {
+    type Inner = G::Inner;
+
+    type With<I> = Compose<FG::With<G::With<I>>>;
+}
+
+impl<FG: Functor<Inner = G>, G: Functor + 'static> Functor for Compose<FG> {
+    fn fmap<B: 'static>(
+        self,
+        f: impl FnMut(G::Inner) -> B + 'static,
+    ) -> Compose<FG::With<G::With<B>>> {
+        // Every inner `G` needs the same `f`.
+        let f = Rc::new(RefCell::new(f));
+        Compose(self.0.fmap(move |g: G| {
+            let f = f.clone();
+            g.fmap(move |a| (f.borrow_mut())(a))
+        }))
+    }
+}
+
+impl<FG: FunctorWith<Inner = G>, G: FunctorWith + 'static> FunctorWith for Compose<FG> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Compose<FG::With<G::With<X>>>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Compose<FG::With<G::With<Y>>> {
+        let f = Rc::new(RefCell::new(f));
+        Compose(FG::fmap_with(fx.0, move |g| {
+            let f = f.clone();
+            G::fmap_with(g, move |x| (f.borrow_mut())(x))
+        }))
+    }
+}
+
+/// Runs the effects of `F` first, then those of each `G` inside.
+impl<FG: Applicative<Inner = G>, G: Applicative + 'static> Applicative for Compose<FG> {
+    fn pure<X: 'static>(val: X) -> Compose<FG::With<G::With<X>>> {
+        Compose(FG::pure(G::pure(val)))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Compose<FG::With<G::With<B>>>,
+        f: impl FnMut(G::Inner, B) -> C + 'static,
+    ) -> Compose<FG::With<G::With<C>>> {
+        let f = Rc::new(RefCell::new(f));
+        Compose(self.0.zip_with(b.0, move |ga: G, gb| {
+            let f = f.clone();
+            ga.zip_with(gb, move |a, b| (f.borrow_mut())(a, b))
+        }))
+    }
+}
+
+impl<FG: Foldable<Inner = G>, G: Foldable> Foldable for Compose<FG> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, G::Inner) -> B) -> B {
+        self.0.fold_left(init, |acc, g: G| g.fold_left(acc, &mut f))
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(G::Inner, B) -> B) -> B {
+        self.0
+            .fold_right(init, |g: G, acc| g.fold_right(acc, &mut f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_through_both_layers() {
+        let nested = Compose(vec![Some(1), None, Some(3)]);
+        assert_eq!(
+            nested.fmap(|n| n * 10).into_inner(),
+            [Some(10), None, Some(30)]
+        );
+    }
+
+    #[test]
+    fn zips_outer_then_inner() {
+        let a = Compose(Some(vec![1, 2]));
+        let b = Compose(Some(vec![10, 20, 30]));
+        assert_eq!(a.zip_with(b, |x, y| x + y).into_inner(), Some(vec![11, 22]));
+
+        let pure = Compose::<Option<Vec<()>>>::pure(5);
+        assert_eq!(pure.into_inner(), Some(vec![5]));
+    }
+
+    #[test]
+    fn folds_everything() {
+        let nested = Compose(vec![vec![1, 2], vec![], vec![3]]);
+        assert_eq!(nested.fold_left(0, |acc, n| acc * 10 + n), 123);
+    }
+}
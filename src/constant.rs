@@ -0,0 +1,229 @@
+//! The constant functor.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+use crate::applicative::Applicative;
+use crate::contravariant::Contravariant;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monoid::Monoid;
+use crate::try_functor::TryFunctor;
+
+/// Holds a `C` while pretending to hold a `V`; mapping over it never touches the `C`.
+///
+/// There's never a `V` inside, so the standard traits are implemented whenever `C` has them, with
+/// no bounds on `V`, and `Const` is `Send` or `Sync` whenever `C` is.
+pub struct Const<C, V> {
+    inner: C,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<C, V> Const<C, V> {
+    pub fn new(inner: C) -> Self {
+        Const {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    pub fn get(&self) -> &C {
+        &self.inner
+    }
+
+    /// Maps the value that's actually there, unlike [`fmap`](Functor::fmap).
+    pub fn map_const<D>(self, f: impl FnOnce(C) -> D) -> Const<D, V> {
+        Const::new(f(self.inner))
+    }
+
+    /// Changes the phantom type, keeping the `C`.
+    pub fn retag<W>(self) -> Const<C, W> {
+        Const::new(self.inner)
+    }
+}
+
+impl<C: Clone, V> Clone for Const<C, V> {
+    fn clone(&self) -> Self {
+        Const::new(self.inner.clone())
+    }
+}
+
+impl<C: Copy, V> Copy for Const<C, V> {}
+
+impl<C: Default, V> Default for Const<C, V> {
+    fn default() -> Self {
+        Const::new(C::default())
+    }
+}
+
+impl<C: fmt::Debug, V> fmt::Debug for Const<C, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Const").field(&self.inner).finish()
+    }
+}
+
+impl<C: PartialEq, V> PartialEq for Const<C, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<C: Eq, V> Eq for Const<C, V> {}
+
+impl<C: PartialOrd, V> PartialOrd for Const<C, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<C: Ord, V> Ord for Const<C, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<C: Hash, V> Hash for Const<C, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<C, V> K1 for Const<C, V> {
+    type Inner = V;
+
+    type With<I> = Const<C, I>;
+}
+
+impl<C, A> Functor for Const<C, A> {
+    fn fmap<B: 'static>(self, _: impl FnMut(A) -> B + 'static) -> Const<C, B> {
+        // mfw no type-changing-struct-update
+        Const {
+            inner: self.inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, A> FunctorWith for Const<C, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Const<C, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Const<C, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<C, A> FunctorOnce for Const<C, A> {}
+
+impl<C: Clone, A> FunctorRef for Const<C, A> {
+    fn fmap_ref<B>(&self, _: impl FnMut(&A) -> B) -> Const<C, B> {
+        Const {
+            inner: self.inner.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Zipping combines the `C`s, with `pure` starting from [`Monoid::empty`], so traversing with
+/// `Const` folds: `xs.traverse(|x| Const::new(f(x))).into_inner()` is `xs.fold_map(f)`. That's
+/// also how a [`Traversal`](crate::optics::Traversal)'s `modify_f` reads its targets out.
+impl<C: Monoid, A> Applicative for Const<C, A> {
+    fn pure<X: 'static>(_: X) -> Const<C, X> {
+        Const::new(C::empty())
+    }
+
+    fn zip_with<B: 'static, D: 'static>(
+        self,
+        b: Const<C, B>,
+        _: impl FnMut(A, B) -> D + 'static,
+    ) -> Const<C, D> {
+        Const::new(self.inner.combine(b.inner))
+    }
+}
+
+/// The `V` is phantom, so `Const` is contravariant in it just as much as it is covariant.
+impl<C, A> Contravariant for Const<C, A> {
+    fn contramap<B>(self, _: impl FnMut(B) -> A + 'static) -> Const<C, B> {
+        Const {
+            inner: self.inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A `Const` has no `V`s in it, so folds always return `init` untouched.
+impl<C, A> Foldable for Const<C, A> {
+    fn fold_left<B>(self, init: B, _: impl FnMut(B, A) -> B) -> B {
+        init
+    }
+
+    fn fold_right<B>(self, init: B, _: impl FnMut(A, B) -> B) -> B {
+        init
+    }
+}
+
+impl<C, A> TryFunctor for Const<C, A> {
+    fn try_fmap<B: 'static, E>(self, _: impl FnMut(A) -> Result<B, E>) -> Result<Const<C, B>, E> {
+        Ok(Const::new(self.inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::foldable::Foldable;
+    #[cfg(feature = "alloc")]
+    use crate::optics::traversed;
+    #[cfg(feature = "alloc")]
+    use crate::traversable::Traversable;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn traverse_folds() {
+        let words = vec!["a", "bc", "def"];
+        let joined = words
+            .clone()
+            .traverse(|s| Const::<String, ()>::new(s.to_owned()));
+        assert_eq!(joined.into_inner(), "abcdef");
+        assert_eq!(
+            words
+                .clone()
+                .traverse(|s| Const::<_, ()>::new(vec![s.len()]))
+                .into_inner(),
+            words.fold_map(|s| vec![s.len()])
+        );
+        let none: Const<String, Vec<()>> = Vec::<&str>::new().traverse(|s| Const::new(s.into()));
+        assert_eq!(none.into_inner(), "");
+    }
+
+    #[test]
+    fn ignores_the_phantom() {
+        struct NotClone;
+        let c = Const::<u32, NotClone>::new(3);
+        let copied = c;
+        assert_eq!(c, copied.clone());
+        assert_eq!(format!("{c:?}"), "Const(3)");
+        assert_eq!(*c.get(), 3);
+        assert!(c < Const::new(4));
+        let shown: Const<String, u8> = c.map_const(|n| n.to_string()).retag();
+        assert_eq!(shown.into_inner(), "3");
+        assert_eq!(Const::<String, NotClone>::default().into_inner(), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn views_through_a_traversal() {
+        let lengths = traversed::<Vec<&str>>().modify_f(vec!["ab", "c"], |s| {
+            Const::<Vec<usize>, _>::new(vec![s.len()])
+        });
+        assert_eq!(lengths.into_inner(), [2, 1]);
+    }
+}
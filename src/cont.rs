@@ -0,0 +1,165 @@
+//! The continuation monad.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+
+/// The rest of a computation: what to do with an `A` to get the final `R`.
+pub type Continuation<R, A> = Box<dyn FnOnce(A) -> R>;
+
+/// A computation in continuation-passing style: instead of returning an `A`, it is handed the
+/// continuation and decides how (or whether) to call it.
+///
+/// Continuations are `FnOnce`, so each one can be resumed at most once.
+pub struct Cont<R, A>(Box<dyn FnOnce(Continuation<R, A>) -> R>);
+
+impl<R, A> Cont<R, A> {
+    pub fn new(f: impl FnOnce(Continuation<R, A>) -> R + 'static) -> Self {
+        Cont(Box::new(f))
+    }
+
+    /// Runs the computation, passing its result to `k`.
+    pub fn run_cont(self, k: impl FnOnce(A) -> R + 'static) -> R {
+        (self.0)(Box::new(k))
+    }
+}
+
+impl<R: 'static> Cont<R, R> {
+    /// Runs the computation with the identity continuation.
+    pub fn eval_cont(self) -> R {
+        self.run_cont(|r| r)
+    }
+}
+
+/// The current continuation captured by [`call_cc`], usable to jump straight out of the body.
+pub struct Escape<R, A>(Rc<RefCell<Option<Continuation<R, A>>>>);
+
+impl<R, A> Clone for Escape<R, A> {
+    fn clone(&self) -> Self {
+        Escape(Rc::clone(&self.0))
+    }
+}
+
+impl<R: 'static, A: 'static> Escape<R, A> {
+    /// Abandons the rest of the current computation and returns `val` from the enclosing
+    /// [`call_cc`].
+    ///
+    /// # Panics
+    ///
+    /// When run after the `call_cc` has already returned, since its continuation is spent.
+    pub fn escape<B>(&self, val: A) -> Cont<R, B> {
+        let slot = Rc::clone(&self.0);
+        Cont::new(move |_| resume(&slot, val))
+    }
+}
+
+fn resume<R, A>(slot: &RefCell<Option<Continuation<R, A>>>, val: A) -> R {
+    let k = slot
+        .borrow_mut()
+        .take()
+        .expect("call_cc continuation resumed more than once");
+    k(val)
+}
+
+/// Calls `f` with the current continuation, so that `f` can return early through it.
+pub fn call_cc<R: 'static, A: 'static>(
+    f: impl FnOnce(Escape<R, A>) -> Cont<R, A> + 'static,
+) -> Cont<R, A> {
+    Cont::new(move |k| {
+        let slot = Rc::new(RefCell::new(Some(k)));
+        let escape = Escape(Rc::clone(&slot));
+        f(escape).run_cont(move |a| resume(&slot, a))
+    })
+}
+
+impl<R, A> K1 for Cont<R, A> {
+    type Inner = A;
+
+    type With<I> = Cont<R, I>;
+}
+
+impl<R: 'static, A: 'static> Functor for Cont<R, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Cont<R, B> {
+        Cont::new(move |k| self.run_cont(move |a| k(f(a))))
+    }
+}
+
+impl<R: 'static, A: 'static> FunctorWith for Cont<R, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Cont<R, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Cont<R, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<R: 'static, A: 'static> Applicative for Cont<R, A> {
+    fn pure<X: 'static>(val: X) -> Cont<R, X> {
+        Cont::new(move |k| k(val))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Cont<R, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Cont<R, C> {
+        Cont::new(move |k| self.run_cont(move |a| b.run_cont(move |b| k(f(a, b)))))
+    }
+}
+
+impl<R: 'static, A: 'static> Monad for Cont<R, A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Cont<R, B> + 'static) -> Cont<R, B> {
+        Cont::new(move |k| self.run_cont(move |a| f(a).run_cont(k)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pure<A: 'static>(a: A) -> Cont<String, A> {
+        Cont::<String, ()>::pure(a)
+    }
+
+    #[test]
+    fn continuation_receives_result() {
+        let c = pure(2).bind(|x| pure(x * 10)).fmap(|x| x + 1);
+        assert_eq!(c.run_cont(|x| format!("<{x}>")), "<21>");
+    }
+
+    fn checked_div(x: i32, y: i32) -> Cont<String, Result<i32, String>> {
+        call_cc(move |exit| {
+            let check = if y == 0 {
+                exit.escape(Err("divide by zero".to_owned()))
+            } else {
+                pure(())
+            };
+            check.bind(move |()| pure(Ok(x / y)))
+        })
+    }
+
+    #[test]
+    fn call_cc_escapes() {
+        let show = |r: Result<i32, String>| match r {
+            Ok(n) => n.to_string(),
+            Err(e) => e,
+        };
+        assert_eq!(checked_div(10, 2).run_cont(show), "5");
+        assert_eq!(checked_div(1, 0).run_cont(show), "divide by zero");
+    }
+
+    #[test]
+    fn escape_skips_rest() {
+        let c: Cont<i32, i32> = call_cc(|exit| {
+            exit.escape::<i32>(1)
+                .bind(|_| -> Cont<i32, i32> { unreachable!("escaped") })
+        })
+        .fmap(|x| x + 100);
+        assert_eq!(c.eval_cont(), 101);
+    }
+}
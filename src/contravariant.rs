@@ -0,0 +1,171 @@
+//! Contravariant functors, and the function wrappers that are the usual examples of them.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use crate::invariant::Invariant;
+use crate::kind::K1;
+
+/// A type constructor that *consumes* its parameter, so it maps "backwards": given a way to turn
+/// `B`s into the `A`s it consumes, it can consume `B`s instead.
+///
+/// The closure is `'static` because the usual instances store it for later.
+///
+/// Instances should satisfy:
+/// - `x.contramap(|a| a) == x`
+/// - `x.contramap(f).contramap(g) == x.contramap(|b| f(g(b)))`
+pub trait Contravariant: K1 {
+    fn contramap<B>(self, f: impl FnMut(B) -> Self::Inner + 'static) -> Self::With<B>;
+}
+
+/// A yes/no question about a `T`.
+#[cfg(feature = "alloc")]
+pub struct Predicate<T>(Box<dyn FnMut(T) -> bool>);
+
+#[cfg(feature = "alloc")]
+impl<T> Predicate<T> {
+    pub fn new(f: impl FnMut(T) -> bool + 'static) -> Self {
+        Predicate(Box::new(f))
+    }
+
+    pub fn test(&mut self, val: T) -> bool {
+        (self.0)(val)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> K1 for Predicate<T> {
+    type Inner = T;
+
+    type With<I> = Predicate<I>;
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Invariant for Predicate<A> {
+    fn invmap<B: 'static>(
+        self,
+        _: impl FnMut(A) -> B + 'static,
+        g: impl FnMut(B) -> A + 'static,
+    ) -> Predicate<B> {
+        self.contramap(g)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Contravariant for Predicate<A> {
+    fn contramap<B>(mut self, mut f: impl FnMut(B) -> A + 'static) -> Predicate<B> {
+        Predicate::new(move |b| self.test(f(b)))
+    }
+}
+
+/// An ordering on `T`s.
+#[cfg(feature = "alloc")]
+pub struct Comparison<T>(Box<dyn FnMut(T, T) -> Ordering>);
+
+#[cfg(feature = "alloc")]
+impl<T> Comparison<T> {
+    pub fn new(f: impl FnMut(T, T) -> Ordering + 'static) -> Self {
+        Comparison(Box::new(f))
+    }
+
+    pub fn compare(&mut self, a: T, b: T) -> Ordering {
+        (self.0)(a, b)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> K1 for Comparison<T> {
+    type Inner = T;
+
+    type With<I> = Comparison<I>;
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Invariant for Comparison<A> {
+    fn invmap<B: 'static>(
+        self,
+        _: impl FnMut(A) -> B + 'static,
+        g: impl FnMut(B) -> A + 'static,
+    ) -> Comparison<B> {
+        self.contramap(g)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Contravariant for Comparison<A> {
+    fn contramap<B>(mut self, mut f: impl FnMut(B) -> A + 'static) -> Comparison<B> {
+        Comparison::new(move |x, y| {
+            let x = f(x);
+            let y = f(y);
+            self.compare(x, y)
+        })
+    }
+}
+
+/// A function from `T` to `R`, flipped around so that `K1` abstracts over its argument.
+#[cfg(feature = "alloc")]
+pub struct Op<R, T>(Box<dyn FnMut(T) -> R>);
+
+#[cfg(feature = "alloc")]
+impl<R, T> Op<R, T> {
+    pub fn new(f: impl FnMut(T) -> R + 'static) -> Self {
+        Op(Box::new(f))
+    }
+
+    pub fn call(&mut self, val: T) -> R {
+        (self.0)(val)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, T> K1 for Op<R, T> {
+    type Inner = T;
+
+    type With<I> = Op<R, I>;
+}
+
+#[cfg(feature = "alloc")]
+impl<R: 'static, A: 'static> Invariant for Op<R, A> {
+    fn invmap<B: 'static>(
+        self,
+        _: impl FnMut(A) -> B + 'static,
+        g: impl FnMut(B) -> A + 'static,
+    ) -> Op<R, B> {
+        self.contramap(g)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: 'static, A: 'static> Contravariant for Op<R, A> {
+    fn contramap<B>(mut self, mut f: impl FnMut(B) -> A + 'static) -> Op<R, B> {
+        Op::new(move |b| self.call(f(b)))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicate_contramap() {
+        let mut long = Predicate::new(|n: usize| n > 3).contramap(|s: &str| s.len());
+        assert!(long.test("hello"));
+        assert!(!long.test("hi"));
+    }
+
+    #[test]
+    fn comparison_contramap() {
+        let mut by_len =
+            Comparison::new(|a: usize, b: usize| a.cmp(&b)).contramap(|s: String| s.len());
+        assert_eq!(by_len.compare("aa".into(), "b".into()), Ordering::Greater);
+    }
+
+    #[test]
+    fn op_contramap() {
+        let mut show = Op::new(|n: i32| n.to_string()).contramap(|(a, b): (i32, i32)| a + b);
+        assert_eq!(show.call((1, 2)), "3");
+    }
+}
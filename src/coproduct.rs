@@ -0,0 +1,282 @@
+//! Sums of functors, for building DSLs out of smaller instruction sets.
+
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use crate::free::Free;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::nat::NatTrans;
+
+/// Either an `F<A>` or a `G<A>`.
+///
+/// Used as the witness of a [`Free`] monad, this combines two instruction sets into one. Larger
+/// sums nest to the right, like `Coproduct<F, Coproduct<G, H>>`, and [`Inject`] and [`Project`]
+/// move instructions in and out of them without spelling out the nesting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coproduct<F, G> {
+    Left(F),
+    Right(G),
+}
+
+impl<F: K1, G: K1<Inner = F::Inner>> K1 for Coproduct<F, G> {
+    type Inner = F::Inner;
+
+    type With<I> = Coproduct<F::With<I>, G::With<I>>;
+}
+
+impl<F: Functor, G: Functor<Inner = F::Inner>> Functor for Coproduct<F, G> {
+    fn fmap<B: 'static>(
+        self,
+        f: impl FnMut(F::Inner) -> B + 'static,
+    ) -> Coproduct<F::With<B>, G::With<B>> {
+        match self {
+            Coproduct::Left(fa) => Coproduct::Left(fa.fmap(f)),
+            Coproduct::Right(ga) => Coproduct::Right(ga.fmap(f)),
+        }
+    }
+}
+
+impl<F: FunctorWith, G: FunctorWith<Inner = F::Inner>> FunctorWith for Coproduct<F, G> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Coproduct<F::With<X>, G::With<X>>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Coproduct<F::With<Y>, G::With<Y>> {
+        match fx {
+            Coproduct::Left(fx) => Coproduct::Left(F::fmap_with(fx, f)),
+            Coproduct::Right(gx) => Coproduct::Right(G::fmap_with(gx, f)),
+        }
+    }
+}
+
+impl<F: FunctorOnce, G: FunctorOnce<Inner = F::Inner>> FunctorOnce for Coproduct<F, G> {}
+
+/// Index for an [`Inject`] or [`Project`] that stops at the current position.
+pub enum Here {}
+
+/// Index for an [`Inject`] or [`Project`] that looks further to the right.
+pub struct There<I>(PhantomData<I>);
+
+/// A sum of functors, witnessed by `Self`, that has `Sub` as one of its summands.
+///
+/// `I` is [`Here`] or [`There`], and tells the instances apart so they don't overlap. It's
+/// always inferred.
+pub trait Inject<Sub: K1, I>: K1 {
+    fn inject<X>(sub: Sub::With<X>) -> Self::With<X>;
+}
+
+/// The inverse of [`Inject`]: checks whether an instruction came from `Sub`.
+pub trait Project<Sub: K1, I>: Inject<Sub, I> {
+    /// Returns the instruction as a `Sub` if it is one, or hands it back unchanged.
+    fn project<X>(sup: Self::With<X>) -> Result<Sub::With<X>, Self::With<X>>;
+}
+
+impl<F: K1> Inject<F, Here> for F {
+    fn inject<X>(sub: F::With<X>) -> F::With<X> {
+        sub
+    }
+}
+
+impl<F: K1> Project<F, Here> for F {
+    fn project<X>(sup: F::With<X>) -> Result<F::With<X>, F::With<X>> {
+        Ok(sup)
+    }
+}
+
+impl<F: K1, G: K1<Inner = F::Inner>> Inject<F, Here> for Coproduct<F, G> {
+    fn inject<X>(sub: F::With<X>) -> Coproduct<F::With<X>, G::With<X>> {
+        Coproduct::Left(sub)
+    }
+}
+
+impl<F: K1, G: K1<Inner = F::Inner>> Project<F, Here> for Coproduct<F, G> {
+    fn project<X>(
+        sup: Coproduct<F::With<X>, G::With<X>>,
+    ) -> Result<F::With<X>, Coproduct<F::With<X>, G::With<X>>> {
+        match sup {
+            Coproduct::Left(fx) => Ok(fx),
+            other => Err(other),
+        }
+    }
+}
+
+impl<F: K1, G: Inject<H, I, Inner = F::Inner>, H: K1, I> Inject<H, There<I>> for Coproduct<F, G> {
+    fn inject<X>(sub: H::With<X>) -> Coproduct<F::With<X>, G::With<X>> {
+        Coproduct::Right(G::inject(sub))
+    }
+}
+
+impl<F: K1, G: Project<H, I, Inner = F::Inner>, H: K1, I> Project<H, There<I>> for Coproduct<F, G> {
+    fn project<X>(
+        sup: Coproduct<F::With<X>, G::With<X>>,
+    ) -> Result<H::With<X>, Coproduct<F::With<X>, G::With<X>>> {
+        match sup {
+            Coproduct::Left(fx) => Err(Coproduct::Left(fx)),
+            Coproduct::Right(gx) => G::project(gx).map_err(Coproduct::Right),
+        }
+    }
+}
+
+/// [`Inject::inject`] as a natural transformation from `Sub` into a sum containing it.
+///
+/// Like for [`Inject`], the index `I` is always inferred.
+pub struct Injection<I>(PhantomData<fn() -> I>);
+
+impl<I> Injection<I> {
+    pub fn new() -> Self {
+        Injection(PhantomData)
+    }
+}
+
+impl<I> Default for Injection<I> {
+    fn default() -> Self {
+        Injection::new()
+    }
+}
+
+impl<Sub: K1, Sup: Inject<Sub, I>, I> NatTrans<Sub, Sup> for Injection<I> {
+    fn apply<A: 'static>(&mut self, fa: Sub::With<A>) -> Sup::With<A> {
+        Sup::inject(fa)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: FunctorWith + 'static, A: 'static> Free<F, A> {
+    /// Turns a single instruction from one of `F`'s summands into a program.
+    pub fn inject<Sub: K1, I>(sub: Sub::With<A>) -> Self
+    where
+        F: Inject<Sub, I>,
+    {
+        Free::lift_f(F::inject(sub))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::either::Either;
+    use crate::monad::Monad;
+
+    struct Say<K>(String, K);
+
+    struct Ask<K>(Box<dyn FnOnce(i32) -> K>);
+
+    impl<K> K1 for Say<K> {
+        type Inner = K;
+
+        type With<I> = Say<I>;
+    }
+
+    impl<K> Functor for Say<K> {
+        fn fmap<B: 'static>(self, mut f: impl FnMut(K) -> B + 'static) -> Say<B> {
+            Say(self.0, f(self.1))
+        }
+    }
+
+    impl<K> FunctorWith for Say<K> {
+        fn fmap_with<X: 'static, Y: 'static>(
+            fx: Say<X>,
+            f: impl FnMut(X) -> Y + 'static,
+        ) -> Say<Y> {
+            fx.fmap(f)
+        }
+    }
+
+    impl<K> FunctorOnce for Say<K> {}
+
+    impl<K> K1 for Ask<K> {
+        type Inner = K;
+
+        type With<I> = Ask<I>;
+    }
+
+    impl<K: 'static> Functor for Ask<K> {
+        fn fmap<B: 'static>(self, mut f: impl FnMut(K) -> B + 'static) -> Ask<B> {
+            Ask(Box::new(move |n| f((self.0)(n))))
+        }
+    }
+
+    impl<K: 'static> FunctorWith for Ask<K> {
+        fn fmap_with<X: 'static, Y: 'static>(
+            fx: Ask<X>,
+            f: impl FnMut(X) -> Y + 'static,
+        ) -> Ask<Y> {
+            fx.fmap(f)
+        }
+    }
+
+    impl<K: 'static> FunctorOnce for Ask<K> {}
+
+    // `Option` doubles as a "halt" instruction: `None` has no continuation.
+    type Dsl = Coproduct<Say<()>, Coproduct<Ask<()>, Option<()>>>;
+
+    fn say(msg: &str) -> Free<Dsl, ()> {
+        Free::inject::<Say<()>, _>(Say(msg.to_owned(), ()))
+    }
+
+    fn ask() -> Free<Dsl, i32> {
+        Free::inject::<Ask<()>, _>(Ask(Box::new(|n| n)))
+    }
+
+    fn halt<A: 'static>() -> Free<Dsl, A> {
+        Free::inject::<Option<()>, _>(None)
+    }
+
+    fn run(mut prog: Free<Dsl, i32>, input: i32) -> (Option<i32>, Vec<String>) {
+        let mut said = Vec::new();
+        loop {
+            let layer = match prog.resume() {
+                Either::Left(layer) => layer,
+                Either::Right(n) => return (Some(n), said),
+            };
+            let layer = match <Dsl as Project<Say<()>, _>>::project(layer) {
+                Ok(Say(msg, next)) => {
+                    said.push(msg);
+                    prog = next;
+                    continue;
+                }
+                Err(layer) => layer,
+            };
+            prog = match <Dsl as Project<Ask<()>, _>>::project(layer) {
+                Ok(Ask(next)) => next(input),
+                Err(layer) => match <Dsl as Project<Option<()>, _>>::project(layer) {
+                    Ok(next) => match next {
+                        Some(next) => next,
+                        None => return (None, said),
+                    },
+                    Err(_) => unreachable!("every summand was projected"),
+                },
+            };
+        }
+    }
+
+    #[test]
+    fn interprets_a_sum_of_instruction_sets() {
+        let prog = say("number?").bind(|()| ask()).bind(|n| {
+            if n < 0 {
+                halt()
+            } else {
+                say("thanks").fmap(move |()| n * 2)
+            }
+        });
+        assert_eq!(run(prog, 21).0, Some(42));
+
+        let prog = say("number?").bind(|()| ask()).bind(|_| halt::<i32>());
+        assert_eq!(run(prog, -1), (None, vec!["number?".to_owned()]));
+    }
+
+    #[test]
+    fn injection_is_natural() {
+        type Sum = Coproduct<Option<()>, Coproduct<Vec<()>, Either<(), ()>>>;
+        let sum = NatTrans::<Vec<()>, Sum>::apply(&mut Injection::new(), vec![1, 2]);
+        assert_eq!(sum, Coproduct::Right(Coproduct::Left(vec![1, 2])));
+    }
+
+    #[test]
+    fn maps_either_side() {
+        let left: Coproduct<Option<i32>, Vec<i32>> = Coproduct::Left(Some(1));
+        assert_eq!(left.fmap(|n| n + 1), Coproduct::Left(Some(2)));
+        let right: Coproduct<Option<i32>, Vec<i32>> = Coproduct::Right(vec![1, 2]);
+        assert_eq!(right.fmap(|n| n + 1), Coproduct::Right(vec![2, 3]));
+    }
+}
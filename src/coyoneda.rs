@@ -0,0 +1,137 @@
+//! The Coyoneda lemma as a data type, for fusing chains of `fmap`s.
+
+use alloc::boxed::Box;
+
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+
+/// An `F` with a mapping function waiting to be applied to its elements.
+///
+/// Mapping over a `Coyoneda` only composes the new function onto the pending one, so a chain
+/// of `fmap`s costs a single traversal of `F` when it's finally [`lower`](Coyoneda::lower)ed.
+/// That pays off for functors that are expensive to map, like a long `Vec` or a deep
+/// [`Free`](crate::free::Free). It's also a functor for any `F` at all, even ones with no
+/// `Functor` instance, until it has to be lowered.
+///
+/// As with the transformers, `F` is the functor already applied to its original elements, so
+/// `Coyoneda<Vec<i32>, String>` holds a `Vec<i32>` and will lower to a `Vec<String>`.
+pub struct Coyoneda<F: K1, A> {
+    fx: F,
+    f: Box<dyn FnMut(F::Inner) -> A>,
+}
+
+impl<F: K1> Coyoneda<F, F::Inner>
+where
+    F::Inner: 'static,
+{
+    pub fn lift(fx: F) -> Self {
+        Coyoneda {
+            fx,
+            f: Box::new(|x| x),
+        }
+    }
+}
+
+impl<F: K1, A> Coyoneda<F, A> {
+    /// Runs every pending `fmap` in a single pass.
+    pub fn lower(self) -> F::With<A>
+    where
+        F: Functor,
+        F::Inner: 'static,
+        A: 'static,
+    {
+        self.fx.fmap(self.f)
+    }
+}
+
+impl<F: K1, A> K1 for Coyoneda<F, A> {
+    type Inner = A;
+
+    type With<I> = Coyoneda<F, I>;
+}
+
+impl<F: K1, A: 'static> Functor for Coyoneda<F, A>
+where
+    F::Inner: 'static,
+{
+    fn fmap<B: 'static>(self, mut g: impl FnMut(A) -> B + 'static) -> Coyoneda<F, B> {
+        let mut f = self.f;
+        Coyoneda {
+            fx: self.fx,
+            f: Box::new(move |x| g(f(x))),
+        }
+    }
+}
+
+impl<F: K1, A: 'static> FunctorWith for Coyoneda<F, A>
+where
+    F::Inner: 'static,
+{
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Coyoneda<F, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Coyoneda<F, Y> {
+        fx.fmap(f)
+    }
+}
+
+/// Folds `F`, mapping each element on the way.
+impl<F: Foldable, A> Foldable for Coyoneda<F, A> {
+    fn fold_left<B>(self, init: B, mut g: impl FnMut(B, A) -> B) -> B {
+        let mut f = self.f;
+        self.fx.fold_left(init, |acc, x| g(acc, f(x)))
+    }
+
+    fn fold_right<B>(self, init: B, mut g: impl FnMut(A, B) -> B) -> B {
+        let mut f = self.f;
+        self.fx.fold_right(init, |x, acc| g(f(x), acc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static TRAVERSALS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // A `Vec` that counts how many times it's been mapped.
+    #[derive(Debug, PartialEq)]
+    struct Counted<T>(Vec<T>);
+
+    impl<T> K1 for Counted<T> {
+        type Inner = T;
+
+        type With<I> = Counted<I>;
+    }
+
+    impl<A> Functor for Counted<A> {
+        fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Counted<B> {
+            TRAVERSALS.set(TRAVERSALS.get() + 1);
+            Counted(self.0.into_iter().map(f).collect())
+        }
+    }
+
+    #[test]
+    fn fuses_maps() {
+        let mapped = Coyoneda::lift(Counted(vec![1, 2, 3]))
+            .fmap(|x| x + 1)
+            .fmap(|x| x * 10)
+            .fmap(|x: i32| x.to_string());
+        assert_eq!(TRAVERSALS.get(), 0);
+        assert_eq!(
+            mapped.lower(),
+            Counted(vec!["20".into(), "30".into(), "40".into()])
+        );
+        assert_eq!(TRAVERSALS.get(), 1);
+    }
+
+    #[test]
+    fn folds_through_the_pending_map() {
+        let mapped = Coyoneda::lift(vec![1, 2, 3]).fmap(|x| x * 2);
+        assert_eq!(mapped.fold_left(0, |acc, x| acc + x), 12);
+    }
+}
@@ -0,0 +1,278 @@
+//! Discrete probability distributions.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+
+/// A source of uniformly distributed random numbers, for [`Dist::sample`].
+///
+/// The crate doesn't depend on a random number generator, so this is implemented by the
+/// caller, usually by forwarding to whichever one the program already uses.
+pub trait Rng {
+    /// Returns a number in `[0, 1)`.
+    fn next_f64(&mut self) -> f64;
+}
+
+/// A finite probability distribution over values of type `A`, as a list of outcomes and their
+/// weights.
+///
+/// The weights don't have to add up to 1, and [`probability`](Dist::probability),
+/// [`expect`](Dist::expect) and [`sample`](Dist::sample) divide by their total, so conditioning
+/// with [`filter`](Dist::filter) needs no extra step. [`normalize`](Dist::normalize) rescales them
+/// for display. The same value can appear more than once, with its weight being the sum, until
+/// [`collapse`](Dist::collapse) merges the copies.
+///
+/// [`bind`](Monad::bind) runs the rest of a model for each outcome in turn, multiplying the
+/// weights along each path. The distributions it's given should be normalized, or the paths
+/// through the heavier ones count for more.
+///
+/// [`zip_with`](Applicative::zip_with) pairs outcomes up positionally, multiplying their
+/// weights, like `Vec`'s and for the reasons given on [`ZipVec`](crate::zip_vec::ZipVec). So it
+/// doesn't agree with `bind`: for the joint distribution of two independent models, use `bind`
+/// or [`product`](Dist::product).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dist<A>(Vec<(A, f64)>);
+
+impl<A> Dist<A> {
+    /// A distribution with the given outcomes and weights.
+    pub fn new(outcomes: Vec<(A, f64)>) -> Self {
+        Dist(outcomes)
+    }
+
+    /// Each of the items with the same probability.
+    pub fn uniform(items: impl IntoIterator<Item = A>) -> Self {
+        Dist(items.into_iter().map(|a| (a, 1.0)).collect()).normalize()
+    }
+
+    /// `a` with weight `p`, and `b` with the rest.
+    pub fn bernoulli(p: f64, a: A, b: A) -> Self {
+        Dist(vec![(a, p), (b, 1.0 - p)])
+    }
+
+    pub fn outcomes(&self) -> &[(A, f64)] {
+        &self.0
+    }
+
+    pub fn into_outcomes(self) -> Vec<(A, f64)> {
+        self.0
+    }
+
+    /// The sum of the weights.
+    pub fn total(&self) -> f64 {
+        self.0.iter().map(|(_, p)| p).sum()
+    }
+
+    /// Rescales the weights to add up to 1. An empty distribution, or one with a total weight of
+    /// 0, is left alone.
+    pub fn normalize(mut self) -> Self {
+        let total = self.total();
+        if total != 0.0 {
+            for (_, p) in &mut self.0 {
+                *p /= total;
+            }
+        }
+        self
+    }
+
+    /// Keeps only the outcomes that satisfy `pred`, which conditions the distribution on it.
+    pub fn filter(mut self, mut pred: impl FnMut(&A) -> bool) -> Self {
+        self.0.retain(|(a, _)| pred(a));
+        self
+    }
+
+    /// The probability of an outcome that satisfies `pred`.
+    pub fn probability(&self, mut pred: impl FnMut(&A) -> bool) -> f64 {
+        self.expect(|a| if pred(a) { 1.0 } else { 0.0 })
+    }
+
+    /// The expected value of `f` over the outcomes.
+    ///
+    /// This is NaN for an empty distribution.
+    pub fn expect(&self, mut f: impl FnMut(&A) -> f64) -> f64 {
+        let weighted: f64 = self.0.iter().map(|(a, p)| p * f(a)).sum();
+        weighted / self.total()
+    }
+
+    /// Picks an outcome at random, in proportion to its weight, or `None` if there are none.
+    pub fn sample(&self, rng: &mut impl Rng) -> Option<&A> {
+        let mut target = rng.next_f64() * self.total();
+        for (a, p) in &self.0 {
+            if target < *p {
+                return Some(a);
+            }
+            target -= p;
+        }
+        // Rounding can leave a sliver past the last weight.
+        self.0.iter().rev().find(|(_, p)| *p > 0.0).map(|(a, _)| a)
+    }
+
+    /// Merges the copies of each value, adding their weights, and sorts the outcomes.
+    pub fn collapse(self) -> Self
+    where
+        A: Ord,
+    {
+        let mut merged = BTreeMap::new();
+        for (a, p) in self.0 {
+            *merged.entry(a).or_insert(0.0) += p;
+        }
+        Dist(merged.into_iter().collect())
+    }
+
+    /// The joint distribution of `self` and an independent `other`.
+    ///
+    /// Every outcome of one side is paired with every outcome of the other, so both have to be
+    /// `Clone`.
+    pub fn product<B: Clone>(self, other: Dist<B>) -> Dist<(A, B)>
+    where
+        A: Clone,
+    {
+        self.0
+            .into_iter()
+            .flat_map(|(a, p)| {
+                other
+                    .0
+                    .iter()
+                    .map(move |(b, q)| ((a.clone(), b.clone()), p * q))
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl<A> From<Vec<(A, f64)>> for Dist<A> {
+    fn from(outcomes: Vec<(A, f64)>) -> Self {
+        Dist(outcomes)
+    }
+}
+
+impl<A> K1 for Dist<A> {
+    type Inner = A;
+
+    type With<I> = Dist<I>;
+}
+
+impl<A> Functor for Dist<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Dist<B> {
+        Dist(self.0.into_iter().map(|(a, p)| (f(a), p)).collect())
+    }
+}
+
+impl<A> FunctorWith for Dist<A> {
+    fn fmap_with<X: 'static, Y: 'static>(fx: Dist<X>, f: impl FnMut(X) -> Y + 'static) -> Dist<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorRef for Dist<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Dist<B> {
+        Dist(self.0.iter().map(|(a, p)| (f(a), *p)).collect())
+    }
+}
+
+impl<A> Applicative for Dist<A> {
+    fn pure<X: 'static>(val: X) -> Dist<X> {
+        Dist(vec![(val, 1.0)])
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Dist<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Dist<C> {
+        let pairs = self.0.into_iter().zip(b.0);
+        Dist(pairs.map(|((a, p), (b, q))| (f(a, b), p * q)).collect())
+    }
+}
+
+impl<A> Monad for Dist<A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Dist<B> + 'static) -> Dist<B> {
+        let paths = self.0.into_iter().flat_map(|(a, p)| {
+            let then = f(a).0;
+            then.into_iter().map(move |(b, q)| (b, p * q))
+        });
+        Dist(paths.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn die() -> Dist<u32> {
+        Dist::uniform(1..=6)
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn sums_of_dice() {
+        let two = die().bind(|a| die().fmap(move |b| a + b)).collapse();
+        assert_eq!(two.outcomes().len(), 11);
+        assert!(close(two.probability(|n| *n == 7), 1.0 / 6.0));
+        assert!(close(two.expect(|n| f64::from(*n)), 7.0));
+        let normalized = two.normalize();
+        assert!(close(normalized.outcomes()[0].1, 1.0 / 36.0));
+        assert!(close(normalized.total(), 1.0));
+    }
+
+    // The car is behind a random door, the contestant picks door 0, and the host opens one of
+    // the other two that doesn't have the car, giving `(car, opened)`.
+    fn monty_hall() -> Dist<(u32, u32)> {
+        Dist::uniform(0..3u32).bind(|car| {
+            let host = Dist::uniform((1..3).filter(move |&door| door != car));
+            host.fmap(move |opened| (car, opened))
+        })
+    }
+
+    #[test]
+    fn switching_doors_wins_two_thirds_of_the_time() {
+        let switch_wins = monty_hall().probability(|&(car, opened)| car == 3 - opened);
+        assert!(close(switch_wins, 2.0 / 3.0));
+
+        let given = monty_hall().filter(|&(_, opened)| opened == 2);
+        assert!(close(given.probability(|&(car, _)| car == 1), 2.0 / 3.0));
+    }
+
+    // A linear congruential generator, which is plenty for checking the proportions.
+    struct Lcg(u64);
+
+    impl Rng for Lcg {
+        fn next_f64(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    #[test]
+    fn samples_in_proportion() {
+        let coin = Dist::bernoulli(0.25, "heads", "tails");
+        let mut rng = Lcg(7);
+        let heads = (0..10_000)
+            .filter(|_| coin.sample(&mut rng) == Some(&"heads"))
+            .count();
+        assert!((2_250..2_750).contains(&heads), "{heads} heads");
+        assert_eq!(Dist::<u8>::uniform([]).sample(&mut rng), None);
+    }
+
+    #[test]
+    fn product_is_independent() {
+        let coins = Dist::bernoulli(0.5, true, false).product(die());
+        assert_eq!(coins.outcomes().len(), 12);
+        assert!(close(coins.probability(|&(h, n)| h && n > 4), 1.0 / 6.0));
+        let bound = Dist::bernoulli(0.5, true, false)
+            .bind(|h| die().fmap(move |n| (h, n)))
+            .collapse();
+        assert_eq!(bound, coins.collapse());
+        assert_eq!(die().bind(Dist::<()>::pure), die());
+    }
+}
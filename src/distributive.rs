@@ -0,0 +1,33 @@
+//! Functors with a fixed shape, which any other functor can be pushed inside.
+
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+
+/// The dual of [`Traversable`](crate::traversable::Traversable): a [`Functor`] that can be pulled
+/// out from inside any other functor `W`, turning a `W` of `Self`s into a `Self` of `W`s.
+///
+/// That only works when every value of `Self` has the same shape, like a fixed-size array, whose
+/// `N` slots can be split out of a `Vec<[A; N]>` as `N` vectors, transposing it. Instances that
+/// need to go over `w` more than once, like that one, do it by reference, so `W` has to be a
+/// [`FunctorRef`] as well.
+///
+/// Instances should satisfy:
+/// - `Self::distribute(Identity(x)) == x.fmap(Identity)`
+/// - `Self::distribute(Self::distribute(w)) == w`, when both are defined
+pub trait Distributive: Functor {
+    fn distribute<W>(w: W) -> Self::With<W::With<Self::Inner>>
+    where
+        W: Functor<Inner = Self> + FunctorRef + 'static;
+
+    /// Distributes `w` and combines each of the resulting `W`s with `f`.
+    fn cotraverse<W, B: 'static>(
+        w: W,
+        f: impl FnMut(W::With<Self::Inner>) -> B + 'static,
+    ) -> Self::With<B>
+    where
+        Self: FunctorWith + Sized,
+        W: Functor<Inner = Self> + FunctorRef + 'static,
+        W::With<Self::Inner>: 'static,
+    {
+        Self::fmap_with(Self::distribute(w), f)
+    }
+}
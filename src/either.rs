@@ -0,0 +1,175 @@
+//! A general purpose sum type.
+
+use crate::applicative::Applicative;
+use crate::bifunctor::Bifunctor;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::{K1, K2};
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::try_functor::TryFunctor;
+
+/// Either an `L` or an `R`.
+///
+/// As a [`K1`] it is right-biased: `fmap` and `bind` act on `Right` values and pass `Left` ones
+/// through untouched, the same way `Result` treats `Ok` and `Err`. Use [`Bifunctor`] to map the
+/// left side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Converts `Right` into `Ok` and `Left` into `Err`.
+    pub fn into_result(self) -> Result<R, L> {
+        match self {
+            Either::Left(l) => Err(l),
+            Either::Right(r) => Ok(r),
+        }
+    }
+
+    /// Converts `Ok` into `Right` and `Err` into `Left`.
+    pub fn from_result(res: Result<R, L>) -> Self {
+        match res {
+            Ok(r) => Either::Right(r),
+            Err(l) => Either::Left(l),
+        }
+    }
+}
+
+impl<L, R> K1 for Either<L, R> {
+    type Inner = R;
+
+    type With<I> = Either<L, I>;
+}
+
+impl<L, A> Functor for Either<L, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Either<L, B> {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(a) => Either::Right(f(a)),
+        }
+    }
+}
+
+impl<L, A> FunctorWith for Either<L, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Either<L, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Either<L, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<L, A> FunctorOnce for Either<L, A> {}
+
+impl<L: Clone, A> FunctorRef for Either<L, A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Either<L, B> {
+        match self {
+            Either::Left(l) => Either::Left(l.clone()),
+            Either::Right(a) => Either::Right(f(a)),
+        }
+    }
+}
+
+impl<L, A> Applicative for Either<L, A> {
+    fn pure<X: 'static>(val: X) -> Either<L, X> {
+        Either::Right(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Either<L, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Either<L, C> {
+        match (self, b) {
+            (Either::Left(l), _) | (_, Either::Left(l)) => Either::Left(l),
+            (Either::Right(a), Either::Right(b)) => Either::Right(f(a, b)),
+        }
+    }
+}
+
+impl<L, A> Monad for Either<L, A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Either<L, B> + 'static) -> Either<L, B> {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(a) => f(a),
+        }
+    }
+}
+
+impl<L, R> K2 for Either<L, R> {
+    type Inner1 = L;
+    type Inner2 = R;
+
+    type With<A, B> = Either<A, B>;
+}
+
+impl<L, R> Bifunctor for Either<L, R> {
+    fn bimap<C, D>(self, mut f: impl FnMut(L) -> C, mut g: impl FnMut(R) -> D) -> Either<C, D> {
+        match self {
+            Either::Left(l) => Either::Left(f(l)),
+            Either::Right(r) => Either::Right(g(r)),
+        }
+    }
+}
+
+impl<L, A> MonadError<L> for Either<L, A> {
+    fn throw_error<X: 'static>(err: L) -> Either<L, X> {
+        Either::Left(err)
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(L) -> Either<L, A> + 'static) -> Either<L, A> {
+        match self {
+            Either::Left(l) => handler(l),
+            right => right,
+        }
+    }
+}
+
+impl<L, A> TryFunctor for Either<L, A> {
+    fn try_fmap<B: 'static, E>(
+        self,
+        mut f: impl FnMut(A) -> Result<B, E>,
+    ) -> Result<Either<L, B>, E> {
+        Ok(match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(a) => Either::Right(f(a)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bimap_maps_present_side() {
+        let l: Either<i32, &str> = Either::Left(1);
+        let r: Either<i32, &str> = Either::Right("ab");
+        assert_eq!(l.bimap(|x| x + 1, str::len), Either::Left(2));
+        assert_eq!(r.bimap(|x| x + 1, str::len), Either::Right(2));
+        assert_eq!(r.map_first(|x| x + 1), Either::Right("ab"));
+    }
+
+    #[test]
+    fn right_biased_instances() {
+        let r: Either<&str, i32> = Either::Right(2);
+        let l: Either<&str, i32> = Either::Left("nope");
+        assert_eq!(r.fmap(|x| x * 2), Either::Right(4));
+        assert_eq!(l.fmap(|x| x * 2), Either::Left("nope"));
+        assert_eq!(r.zip_with(l, |a, b| a + b), Either::Left("nope"));
+        assert_eq!(r.bind(|x| Either::Right(x + 1)), Either::Right(3));
+        assert_eq!(Either::Right::<&str, _>(r).flatten(), r);
+    }
+
+    #[test]
+    fn result_round_trip() {
+        let r: Either<&str, i32> = Either::Right(2);
+        assert_eq!(r.into_result(), Ok(2));
+        assert_eq!(
+            Either::<&str, i32>::from_result(Err("e")),
+            Either::Left("e")
+        );
+    }
+}
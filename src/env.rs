@@ -0,0 +1,99 @@
+//! The environment comonad.
+
+use crate::comonad::Comonad;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::try_functor::TryFunctor;
+
+/// A value of type `A` annotated with an environment of type `E`.
+///
+/// This is the dual of [`Reader`](crate::reader::Reader): a reader waits to be given an
+/// environment, while an `Env` carries one along with its value and lets `extend`ed
+/// computations consult it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Env<E, A> {
+    env: E,
+    value: A,
+}
+
+impl<E, A> Env<E, A> {
+    pub fn new(env: E, value: A) -> Self {
+        Env { env, value }
+    }
+
+    pub fn into_parts(self) -> (E, A) {
+        (self.env, self.value)
+    }
+
+    pub fn ask(&self) -> &E {
+        &self.env
+    }
+
+    /// Returns a summary of the environment.
+    pub fn asks<R>(&self, f: impl FnOnce(&E) -> R) -> R {
+        f(&self.env)
+    }
+
+    /// Modifies the environment, leaving the value alone.
+    pub fn local(self, f: impl FnOnce(E) -> E) -> Self {
+        Env::new(f(self.env), self.value)
+    }
+}
+
+impl<E, A> K1 for Env<E, A> {
+    type Inner = A;
+
+    type With<I> = Env<E, I>;
+}
+
+impl<E, A> Functor for Env<E, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Env<E, B> {
+        Env::new(self.env, f(self.value))
+    }
+}
+
+impl<E, A> FunctorWith for Env<E, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Env<E, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Env<E, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<E: Clone, A> FunctorRef for Env<E, A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Env<E, B> {
+        Env::new(self.env.clone(), f(&self.value))
+    }
+}
+
+/// `extend` hands the whole `Env` to `f` and keeps a copy of the environment for the result.
+impl<E: Clone, A> Comonad for Env<E, A> {
+    fn extract(self) -> A {
+        self.value
+    }
+
+    fn extend<B: 'static>(self, mut f: impl FnMut(Env<E, A>) -> B + 'static) -> Env<E, B> {
+        Env::new(self.env.clone(), f(self))
+    }
+}
+
+impl<E, A> TryFunctor for Env<E, A> {
+    fn try_fmap<B: 'static, X>(self, mut f: impl FnMut(A) -> Result<B, X>) -> Result<Env<E, B>, X> {
+        Ok(Env::new(self.env, f(self.value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extend_reads_the_environment() {
+        let scaled = Env::new(3, 7).extend(|w| w.ask() * w.extract());
+        assert_eq!(scaled.into_parts(), (3, 21));
+        let env = Env::new("unit", 1.5).local(|_| "metres");
+        assert_eq!(env.asks(|e| e.len()), 6);
+        assert_eq!(env.duplicate().extract(), env);
+    }
+}
@@ -0,0 +1,408 @@
+//! Stack-safe lazy evaluation.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use core::mem;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_rec::MonadRec;
+use crate::util::once;
+
+type Erased = Box<dyn Any>;
+
+type Then = Box<dyn FnOnce(Erased) -> Node>;
+
+// The steps of a computation, with the types of the intermediate results erased so that
+// `value` can run any chain of them in a loop.
+enum Node {
+    Now(Erased),
+    Defer(Box<dyn FnOnce() -> Node>),
+    Bind(Box<Node>, Then),
+    Memo(Rc<Memo>),
+}
+
+// A computation shared by the clones of an `Eval`. The first to run it stores the result, and the
+// rest get copies of it.
+struct Memo {
+    state: RefCell<MemoState>,
+    copy: fn(&Erased) -> Erased,
+}
+
+enum MemoState {
+    Pending(Node),
+    Running,
+    Done(Erased),
+}
+
+/// A computation of an `A` that runs in constant stack space, however deeply its
+/// [`bind`](Monad::bind)s are nested.
+///
+/// Nothing runs until [`value`](Eval::value) is called, which evaluates the steps in a loop with
+/// the pending continuations on the heap rather than the call stack. That makes deep recursion
+/// that goes through [`defer`](Eval::defer) or `bind` safe, like mutually recursive functions or
+/// a [`fold_right`] over a long iterator.
+///
+/// Running an `Eval` uses it up, but one whose result is `Clone` can be cloned, and the clones
+/// share a single evaluation: the first to be run stores the result, and the others get copies
+/// of it. So a [`later`](Eval::later) value is computed at most once, however many clones of it
+/// are read.
+pub struct Eval<A> {
+    // In a cell so that `clone` can put the computation behind a `Memo` for both copies.
+    node: RefCell<Node>,
+    result: PhantomData<fn() -> A>,
+}
+
+impl<A: 'static> Eval<A> {
+    fn from_node(node: Node) -> Self {
+        Eval {
+            node: RefCell::new(node),
+            result: PhantomData,
+        }
+    }
+
+    /// An already computed value.
+    pub fn now(val: A) -> Self {
+        Eval::from_node(Node::Now(Box::new(val)))
+    }
+
+    /// A value computed by `f` when it's first needed, if ever, and shared with any clones.
+    pub fn later(f: impl FnOnce() -> A + 'static) -> Self {
+        Eval::from_node(Node::Defer(Box::new(move || Node::Now(Box::new(f())))))
+    }
+
+    /// A computation built by `f` when it's first needed.
+    ///
+    /// Recursive calls that go through `defer` don't grow the stack, as `f` only returns the
+    /// next step rather than running it.
+    pub fn defer(f: impl FnOnce() -> Eval<A> + 'static) -> Self {
+        Eval::from_node(Node::Defer(Box::new(move || f().into_node())))
+    }
+
+    fn into_node(mut self) -> Node {
+        mem::replace(self.node.get_mut(), Node::Now(Box::new(())))
+    }
+
+    /// Runs the computation.
+    pub fn value(self) -> A {
+        let mut stack: Vec<Then> = Vec::new();
+        let mut node = self.into_node();
+        loop {
+            node = match node {
+                Node::Now(val) => match stack.pop() {
+                    Some(then) => then(val),
+                    None => return *val.downcast::<A>().expect("result of the wrong type"),
+                },
+                Node::Defer(f) => f(),
+                Node::Bind(first, then) => {
+                    stack.push(then);
+                    *first
+                }
+                Node::Memo(memo) => {
+                    let state = mem::replace(&mut *memo.state.borrow_mut(), MemoState::Running);
+                    match state {
+                        MemoState::Pending(node) => {
+                            stack.push(Box::new(move |val| {
+                                let copy = (memo.copy)(&val);
+                                *memo.state.borrow_mut() = MemoState::Done(copy);
+                                Node::Now(val)
+                            }));
+                            node
+                        }
+                        MemoState::Done(val) => {
+                            let copy = (memo.copy)(&val);
+                            *memo.state.borrow_mut() = MemoState::Done(val);
+                            Node::Now(copy)
+                        }
+                        MemoState::Running => panic!("an `Eval` needed its own value"),
+                    }
+                }
+            };
+        }
+    }
+}
+
+impl<A: Clone + 'static> Clone for Eval<A> {
+    fn clone(&self) -> Self {
+        let mut node = self.node.borrow_mut();
+        if let Node::Memo(memo) = &*node {
+            return Eval::from_node(Node::Memo(memo.clone()));
+        }
+        let pending = mem::replace(&mut *node, Node::Now(Box::new(())));
+        let memo = Rc::new(Memo {
+            state: RefCell::new(MemoState::Pending(pending)),
+            copy: |val| {
+                Box::new(
+                    val.downcast_ref::<A>()
+                        .expect("result of the wrong type")
+                        .clone(),
+                )
+            },
+        });
+        *node = Node::Memo(memo.clone());
+        Eval::from_node(Node::Memo(memo))
+    }
+}
+
+/// Folds `iter` from the right, lazily.
+///
+/// `f` is given each item and the fold of the rest, which only runs if `f` uses it, so this
+/// can stop early and works on infinite iterators. As long as `f` combines the rest with
+/// [`bind`](Monad::bind) or [`fmap`](Functor::fmap) rather than forcing it, the fold runs in
+/// constant stack space. It is `Fn` rather than `FnMut` because forcing the rest with
+/// [`value`](Eval::value) calls `f` again before the first call has returned.
+pub fn fold_right<I, B>(
+    iter: I,
+    init: Eval<B>,
+    f: impl Fn(I::Item, Eval<B>) -> Eval<B> + 'static,
+) -> Eval<B>
+where
+    I: IntoIterator,
+    I::IntoIter: 'static,
+    B: 'static,
+{
+    fold_right_shared(iter.into_iter(), init, Rc::new(f))
+}
+
+type Folder<T, B> = Rc<dyn Fn(T, Eval<B>) -> Eval<B>>;
+
+fn fold_right_shared<I, B>(mut iter: I, init: Eval<B>, f: Folder<I::Item, B>) -> Eval<B>
+where
+    I: Iterator + 'static,
+    B: 'static,
+{
+    Eval::defer(move || match iter.next() {
+        None => init,
+        Some(item) => {
+            let rest = fold_right_shared(iter, init, f.clone());
+            f(item, rest)
+        }
+    })
+}
+
+// Dropping a chain of left-nested `bind`s would recurse once per link, so they're unlinked in a
+// loop instead, along with any computation only this `Eval` still shares.
+impl<A> Drop for Eval<A> {
+    fn drop(&mut self) {
+        let mut node = mem::replace(self.node.get_mut(), Node::Now(Box::new(())));
+        loop {
+            node = match node {
+                Node::Bind(first, _) => *first,
+                Node::Memo(memo) => match Rc::try_unwrap(memo) {
+                    Ok(memo) => match memo.state.into_inner() {
+                        MemoState::Pending(pending) => pending,
+                        _ => break,
+                    },
+                    Err(_) => break,
+                },
+                _ => break,
+            };
+        }
+    }
+}
+
+impl<A> K1 for Eval<A> {
+    type Inner = A;
+
+    type With<I> = Eval<I>;
+}
+
+impl<A: 'static> Functor for Eval<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Eval<B> {
+        self.bind(move |a| Eval::now(f(a)))
+    }
+}
+
+impl<A: 'static> FunctorWith for Eval<A> {
+    fn fmap_with<X: 'static, Y: 'static>(fx: Eval<X>, f: impl FnMut(X) -> Y + 'static) -> Eval<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A: 'static> FunctorOnce for Eval<A> {}
+
+impl<A: 'static> Applicative for Eval<A> {
+    fn pure<X: 'static>(val: X) -> Eval<X> {
+        Eval::now(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Eval<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Eval<C> {
+        self.bind(once(move |a| b.fmap(once(move |b| f(a, b)))))
+    }
+}
+
+impl<A: 'static> Monad for Eval<A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Eval<B> + 'static) -> Eval<B> {
+        Eval::from_node(Node::Bind(
+            Box::new(self.into_node()),
+            Box::new(move |a: Erased| {
+                let a = *a.downcast::<A>().expect("result of the wrong type");
+                f(a).into_node()
+            }),
+        ))
+    }
+}
+
+// `bind` is already stack-safe, but running each step on its own saves building up the chain.
+impl<A: 'static> MonadRec for Eval<A> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        mut f: impl FnMut(X) -> Eval<Either<X, Y>> + 'static,
+    ) -> Eval<Y> {
+        Eval::later(move || {
+            let mut x = init;
+            loop {
+                match f(x).value() {
+                    Either::Left(next) => x = next,
+                    Either::Right(y) => return y,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    const DEEP: u32 = 1_000_000;
+
+    #[test]
+    fn deep_bind_chains() {
+        let mut nested = Eval::now(0);
+        for _ in 0..DEEP {
+            nested = nested.bind(|n| Eval::now(n + 1));
+        }
+        assert_eq!(nested.value(), DEEP);
+
+        fn count_up(n: u32) -> Eval<u32> {
+            if n == DEEP {
+                Eval::now(n)
+            } else {
+                Eval::now(n + 1).bind(count_up)
+            }
+        }
+        assert_eq!(count_up(0).value(), DEEP);
+    }
+
+    #[test]
+    fn mutual_recursion_through_defer() {
+        fn is_even(n: u32) -> Eval<bool> {
+            if n == 0 {
+                Eval::now(true)
+            } else {
+                Eval::defer(move || is_odd(n - 1))
+            }
+        }
+        fn is_odd(n: u32) -> Eval<bool> {
+            if n == 0 {
+                Eval::now(false)
+            } else {
+                Eval::defer(move || is_even(n - 1))
+            }
+        }
+        assert!(is_even(DEEP).value());
+        assert!(is_odd(DEEP + 1).value());
+    }
+
+    #[test]
+    fn later_is_lazy() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let eval = Eval::later(move || {
+            counter.set(counter.get() + 1);
+            21
+        })
+        .fmap(|n| n * 2);
+        assert_eq!(calls.get(), 0);
+        assert_eq!(eval.value(), 42);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn clones_share_one_evaluation() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let eval = Eval::later(move || {
+            counter.set(counter.get() + 1);
+            21
+        })
+        .fmap(|n| n * 2);
+        let copy = eval.clone();
+        assert_eq!(copy.clone().value(), 42);
+        assert_eq!(eval.value(), 42);
+        assert_eq!(copy.value(), 42);
+        assert_eq!(calls.get(), 1);
+
+        let mut deep = Eval::now(0);
+        for _ in 0..DEEP {
+            deep = deep.bind(|n| Eval::now(n + 1));
+        }
+        let shared = deep.clone();
+        drop(deep);
+        drop(shared);
+    }
+
+    #[test]
+    fn fold_right_is_stack_safe_and_short_circuits() {
+        let sum = fold_right(0..u64::from(DEEP), Eval::now(0), |n, rest| {
+            rest.fmap(move |acc| acc + n)
+        });
+        assert_eq!(sum.value(), u64::from(DEEP) * u64::from(DEEP - 1) / 2);
+
+        let first_big = fold_right(1.., Eval::now(None), |n: u64, rest| {
+            if n * n > 50 {
+                Eval::now(Some(n))
+            } else {
+                rest
+            }
+        });
+        assert_eq!(first_big.value(), Some(8));
+
+        // Forcing the rest inside `f` calls it again before it returns.
+        let forced = fold_right(1..=3, Eval::now(0), |n, rest| Eval::now(n + rest.value()));
+        assert_eq!(forced.value(), 6);
+    }
+
+    #[test]
+    fn drops_deep_bind_chains() {
+        let mut nested = Eval::now(0);
+        for _ in 0..DEEP {
+            nested = nested.bind(|n| Eval::now(n + 1));
+        }
+        drop(nested);
+    }
+
+    #[test]
+    fn tail_rec_m_loops() {
+        let fib = Eval::<()>::tail_rec_m((0u64, 1u64, 90), |(a, b, n)| {
+            Eval::later(move || {
+                if n == 0 {
+                    Either::Right(a)
+                } else {
+                    Either::Left((b, a + b, n - 1))
+                }
+            })
+        });
+        assert_eq!(fib.value(), 2_880_067_194_370_816_120);
+    }
+
+    #[test]
+    fn zip_with_runs_both() {
+        let pair = Eval::later(|| 1).zip_with(Eval::defer(|| Eval::now("a")), |n, s| (n, s));
+        assert_eq!(pair.value(), (1, "a"));
+    }
+}
@@ -0,0 +1,49 @@
+//! Functors that can drop elements.
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::Functor;
+use crate::traversable::Traversable;
+
+/// A [`Functor`] whose elements can be removed while mapping over it.
+///
+/// Where `fmap` has to keep the shape, `filter_map` can leave holes empty. It should agree with
+/// `fmap` when `f` never returns `None`: `fa.filter_map(|a| Some(f(a))) == fa.fmap(f)`.
+///
+/// As with [`Functor::fmap`], the functions are `'static`, so that lazy instances can hold on to
+/// them until they're run.
+pub trait Filterable: Functor {
+    /// Maps every element, keeping only the `Some` results.
+    fn filter_map<B: 'static>(
+        self,
+        f: impl FnMut(Self::Inner) -> Option<B> + 'static,
+    ) -> Self::With<B>;
+
+    /// Maps every element, sending `Left` results to the first structure and `Right` results to
+    /// the second.
+    fn partition_map<B: 'static, C: 'static>(
+        self,
+        f: impl FnMut(Self::Inner) -> Either<B, C> + 'static,
+    ) -> (Self::With<B>, Self::With<C>);
+
+    /// Keeps only the elements that satisfy `pred`.
+    fn filter(self, mut pred: impl FnMut(&Self::Inner) -> bool + 'static) -> Self::With<Self::Inner>
+    where
+        Self: Sized,
+        Self::Inner: 'static,
+    {
+        self.filter_map(move |a| pred(&a).then_some(a))
+    }
+}
+
+/// A [`Traversable`] that can also drop elements while traversing, combining
+/// [`Filterable::filter_map`] with [`Traversable::traverse`].
+///
+/// `wither` runs the effect for every element in order, then keeps the elements that came back
+/// as `Some`. For example withering a `Vec<A>` with `A -> ResultOk<Option<B>, E>` stops at the
+/// first error, and otherwise skips the `None`s.
+pub trait Witherable: Filterable + Traversable {
+    fn wither<F, B: 'static>(self, f: impl FnMut(Self::Inner) -> F) -> F::With<Self::With<B>>
+    where
+        F: Applicative<Inner = Option<B>>;
+}
@@ -0,0 +1,22 @@
+//! Structures that can be reduced to a summary value.
+
+use crate::kind::K1;
+use crate::monoid::Monoid;
+
+/// A type constructor whose values can be folded, element by element, into a single result.
+///
+/// `fold_left` visits elements front to back and `fold_right` back to front; both should visit
+/// the same elements.
+pub trait Foldable: K1 {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, Self::Inner) -> B) -> B;
+
+    fn fold_right<B>(self, init: B, f: impl FnMut(Self::Inner, B) -> B) -> B;
+
+    /// Maps every element into a [`Monoid`] and combines the results from left to right.
+    fn fold_map<M: Monoid>(self, mut f: impl FnMut(Self::Inner) -> M) -> M
+    where
+        Self: Sized,
+    {
+        self.fold_left(M::empty(), |acc, a| acc.combine(f(a)))
+    }
+}
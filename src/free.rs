@@ -0,0 +1,366 @@
+//! The free monad over a functor.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_rec::MonadRec;
+use crate::nat::NatTrans;
+use crate::util::once;
+
+/// A tree of `F` layers with `A`s at the leaves.
+///
+/// `Free<F, A>` is a monad for any [`FunctorOnce`] `F`, which makes it a cheap way to define a
+/// DSL: write the instructions as a functor, build programs with [`lift_f`](Free::lift_f) and
+/// [`bind`](Monad::bind), and interpret them afterwards, either into another monad with
+/// [`fold_free`](Free::fold_free) or by walking the tree by hand with [`resume`](Free::resume).
+///
+/// That's narrower than any [`Functor`]: `zip_with` moves its second argument into the holes of
+/// the first rather than cloning it, so the [`Applicative`], `Monad` and [`MonadRec`] instances
+/// need each layer to have at most one hole. An instruction set with more, like `Vec<()>` for a
+/// choice between several continuations, still gets a `Free` that's a [`Functor`] and can be
+/// built with [`wrap`](Free::wrap) and `lift_f`, but not a monad. The same goes for the
+/// applicative that [`FreeAp::fold_map`](crate::free_ap::FreeAp::fold_map) runs in.
+///
+/// `F` only stands in for its constructor, so which type it's applied to doesn't matter. By
+/// convention it's `()`, e.g. `Free<Option<()>, A>`.
+///
+/// Each `bind` walks the tree built so far, so a program built by binding onto the end in a loop
+/// takes quadratic time. Build those as a [`FreeC`](crate::free_c::FreeC) instead.
+pub enum Free<F: K1, A> {
+    /// A finished computation.
+    Pure(A),
+    /// One instruction, holding the rest of the computation in each of its holes.
+    Roll(Box<F::With<Free<F, A>>>),
+    /// A computation that's only built when it's [`resume`](Free::resume)d.
+    ///
+    /// [`tail_rec_m`](MonadRec::tail_rec_m) puts these in the holes, and so does converting
+    /// from a [`FreeC`](crate::free_c::FreeC), which goes through it. A long program is then
+    /// built one instruction at a time as it's interpreted, rather than all at once with one
+    /// stack frame per instruction.
+    Defer(Box<dyn FnOnce() -> Free<F, A>>),
+}
+
+impl<F: FunctorWith + 'static, A: 'static> Free<F, A> {
+    /// Turns a single instruction into a program that returns its result.
+    pub fn lift_f(fa: F::With<A>) -> Self {
+        Free::Roll(Box::new(F::fmap_with(fa, Free::Pure)))
+    }
+
+    /// Wraps an instruction whose holes already hold the rest of the program.
+    pub fn wrap(layer: F::With<Free<F, A>>) -> Self {
+        Free::Roll(Box::new(layer))
+    }
+
+    /// Splits off the first instruction, or returns the result if there is none.
+    pub fn resume(self) -> Either<F::With<Free<F, A>>, A> {
+        let mut free = self;
+        loop {
+            match free {
+                Free::Pure(a) => return Either::Right(a),
+                Free::Roll(layer) => return Either::Left(*layer),
+                Free::Defer(build) => free = build(),
+            }
+        }
+    }
+
+    /// Whether this is a finished computation. A [`Defer`](Free::Defer) isn't, until it's
+    /// resumed.
+    pub fn is_pure(&self) -> bool {
+        matches!(self, Free::Pure(_))
+    }
+
+    /// Interprets the program in the monad `G`, turning each instruction into a `G` computation
+    /// with `nt` and sequencing them.
+    ///
+    /// The instructions are run with [`tail_rec_m`](MonadRec::tail_rec_m), so a long program
+    /// doesn't grow the stack.
+    pub fn fold_free<G: K1, N>(self, mut nt: N) -> G::With<A>
+    where
+        N: NatTrans<F, G> + 'static,
+        G::With<Self>: MonadRec<Inner = Self> + K1<With<A> = G::With<A>>,
+    {
+        <G::With<Self>>::tail_rec_m(self, move |free: Self| match free.resume() {
+            Either::Left(layer) => nt.apply::<Self>(layer).fmap(Either::Left),
+            Either::Right(a) => <G::With<Self>>::pure(Either::Right(a)),
+        })
+    }
+
+    /// Swaps every instruction for one in the functor `G`, with `nt`.
+    pub fn hoist_free<G, N>(self, nt: N) -> Free<G, A>
+    where
+        G: FunctorWith + 'static,
+        N: NatTrans<F, G> + 'static,
+    {
+        self.hoist_shared(Rc::new(RefCell::new(nt)))
+    }
+
+    fn hoist_shared<G, N>(self, nt: Rc<RefCell<N>>) -> Free<G, A>
+    where
+        G: FunctorWith + 'static,
+        N: NatTrans<F, G> + 'static,
+    {
+        match self {
+            Free::Pure(a) => Free::Pure(a),
+            Free::Roll(layer) => {
+                let hoisted = nt.borrow_mut().apply::<Self>(*layer);
+                Free::Roll(Box::new(G::fmap_with(hoisted, move |free: Self| {
+                    free.hoist_shared(nt.clone())
+                })))
+            }
+            Free::Defer(build) => Free::Defer(Box::new(move || build().hoist_shared(nt))),
+        }
+    }
+
+    // `bind` recurses into every layer with the same function, so it has to be shared. Passing
+    // the `Rc` down directly, rather than re-wrapping it in a new closure each time, keeps the
+    // recursion monomorphic.
+    fn bind_shared<B: 'static>(self, f: Binder<F, A, B>) -> Free<F, B> {
+        match self {
+            Free::Pure(a) => (f.borrow_mut())(a),
+            Free::Roll(layer) => Free::Roll(Box::new(F::fmap_with(*layer, move |free: Self| {
+                free.bind_shared(f.clone())
+            }))),
+            Free::Defer(build) => Free::bind_later(build, f),
+        }
+    }
+
+    // Kept out of `bind_shared`, as the closure would make every frame of its recursion bigger.
+    fn bind_later<B: 'static>(build: Box<dyn FnOnce() -> Self>, f: Binder<F, A, B>) -> Free<F, B> {
+        Free::Defer(Box::new(move || build().bind_shared(f)))
+    }
+}
+
+type Binder<F, A, B> = Rc<RefCell<dyn FnMut(A) -> Free<F, B>>>;
+
+impl<F: K1, A> K1 for Free<F, A> {
+    type Inner = A;
+
+    type With<I> = Free<F, I>;
+}
+
+impl<F: FunctorWith + 'static, A: 'static> Functor for Free<F, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Free<F, B> {
+        self.bind_shared(Rc::new(RefCell::new(move |a| Free::Pure(f(a)))))
+    }
+}
+
+impl<F: FunctorOnce + FunctorWith + 'static, A: 'static> FunctorOnce for Free<F, A> {}
+
+/// `zip_with` runs all of `self`, then all of `b`. `b` is moved into the continuation of each of
+/// `self`'s results rather than cloned, so `F` has to be a [`FunctorOnce`], with at most one hole
+/// in each instruction.
+impl<F: FunctorOnce + FunctorWith + 'static, A: 'static> Applicative for Free<F, A> {
+    fn pure<X: 'static>(val: X) -> Free<F, X> {
+        Free::Pure(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Free<F, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Free<F, C> {
+        self.bind(once(move |a| b.fmap(once(move |b| f(a, b)))))
+    }
+}
+
+impl<F: FunctorOnce + FunctorWith + 'static, A: 'static> Monad for Free<F, A> {
+    fn bind<B: 'static>(self, f: impl FnMut(A) -> Free<F, B> + 'static) -> Free<F, B> {
+        self.bind_shared(Rc::new(RefCell::new(f)))
+    }
+}
+
+/// Each step is bound onto behind a [`Defer`](Free::Defer), so the loop only goes as far as
+/// the program is resumed, one step at a time.
+impl<F: FunctorOnce + FunctorWith + 'static, A: 'static> MonadRec for Free<F, A> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        f: impl FnMut(X) -> Free<F, Either<X, Y>> + 'static,
+    ) -> Free<F, Y> {
+        tail_rec_shared(init, Rc::new(RefCell::new(f)))
+    }
+}
+
+type Step<F, X, Y> = Rc<RefCell<dyn FnMut(X) -> Free<F, Either<X, Y>>>>;
+
+fn tail_rec_shared<F, X, Y>(x: X, f: Step<F, X, Y>) -> Free<F, Y>
+where
+    F: FunctorWith + 'static,
+    X: 'static,
+    Y: 'static,
+{
+    Free::Defer(Box::new(move || {
+        let step = (f.borrow_mut())(x);
+        step.bind_shared(Rc::new(RefCell::new(move |next| match next {
+            Either::Left(x) => tail_rec_shared(x, f.clone()),
+            Either::Right(y) => Free::Pure(y),
+        })))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::State;
+
+    enum Cmd<K> {
+        Say(String, K),
+        Ask(Box<dyn FnOnce(i32) -> K>),
+    }
+
+    impl<K> K1 for Cmd<K> {
+        type Inner = K;
+
+        type With<I> = Cmd<I>;
+    }
+
+    impl<K: 'static> Functor for Cmd<K> {
+        fn fmap<B: 'static>(self, mut f: impl FnMut(K) -> B + 'static) -> Cmd<B> {
+            match self {
+                Cmd::Say(msg, k) => Cmd::Say(msg, f(k)),
+                Cmd::Ask(k) => Cmd::Ask(Box::new(move |n| f(k(n)))),
+            }
+        }
+    }
+
+    impl<K: 'static> FunctorWith for Cmd<K> {
+        fn fmap_with<X: 'static, Y: 'static>(
+            fx: Cmd<X>,
+            f: impl FnMut(X) -> Y + 'static,
+        ) -> Cmd<Y> {
+            fx.fmap(f)
+        }
+    }
+
+    impl<K: 'static> FunctorOnce for Cmd<K> {}
+
+    type Program<A> = Free<Cmd<()>, A>;
+
+    fn say(msg: &str) -> Program<()> {
+        Free::lift_f(Cmd::Say(msg.to_owned(), ()))
+    }
+
+    fn ask() -> Program<i32> {
+        Free::lift_f(Cmd::Ask(Box::new(|n| n)))
+    }
+
+    fn run<A: 'static>(
+        mut prog: Program<A>,
+        mut input: impl Iterator<Item = i32>,
+    ) -> (A, Vec<String>) {
+        let mut said = Vec::new();
+        loop {
+            match prog.resume() {
+                Either::Right(a) => return (a, said),
+                Either::Left(Cmd::Say(msg, next)) => {
+                    said.push(msg);
+                    prog = next;
+                }
+                Either::Left(Cmd::Ask(next)) => prog = next(input.next().unwrap()),
+            }
+        }
+    }
+
+    #[test]
+    fn interpret_dsl() {
+        let prog = say("first?")
+            .bind(|()| ask())
+            .bind(|a| say("second?").bind(move |()| ask().fmap(move |b| a + b)));
+        let (sum, said) = run(prog, [1, 2].into_iter());
+        assert_eq!(sum, 3);
+        assert_eq!(said, ["first?", "second?"]);
+    }
+
+    #[test]
+    fn zip_runs_left_first() {
+        let prog = say("a").zip_with(say("b"), |(), ()| 0);
+        assert_eq!(
+            run(prog, std::iter::empty()),
+            (0, vec!["a".into(), "b".into()])
+        );
+    }
+
+    #[test]
+    fn tail_rec_m_says_as_much_as_it_loops() {
+        let prog = Program::<()>::tail_rec_m(0, |n: u32| {
+            if n == 100_000 {
+                Free::Pure(Either::Right(n))
+            } else {
+                say("tick").fmap(move |()| Either::Left(n + 1))
+            }
+        });
+        let (n, said) = run(prog, std::iter::empty());
+        assert_eq!((n, said.len()), (100_000, 100_000));
+    }
+
+    #[test]
+    fn free_option_short_circuits() {
+        let stop = Free::<Option<()>, i32>::lift_f(None);
+        assert!(!stop.bind(|n| Free::Pure(n + 1)).is_pure());
+
+        let go = Free::<Option<()>, i32>::lift_f(Some(1)).bind(|n| Free::Pure(n + 1));
+        match go.resume() {
+            Either::Left(Some(rest)) => assert!(matches!(rest, Free::Pure(2))),
+            _ => panic!("expected a single `Some` layer"),
+        }
+    }
+
+    // Reads answers from the front of the first list and logs what's said to the second.
+    struct Scripted;
+
+    type Script = (Vec<i32>, Vec<String>);
+
+    impl NatTrans<Cmd<()>, State<Script, ()>> for Scripted {
+        fn apply<A: 'static>(&mut self, fa: Cmd<A>) -> State<Script, A> {
+            State::new(move |(mut input, mut said): Script| match fa {
+                Cmd::Say(msg, k) => {
+                    said.push(msg);
+                    (k, (input, said))
+                }
+                Cmd::Ask(k) => {
+                    let n = input.remove(0);
+                    (k(n), (input, said))
+                }
+            })
+        }
+    }
+
+    fn add_two() -> Program<i32> {
+        say("first?")
+            .bind(|()| ask())
+            .bind(|a| say("second?").bind(move |()| ask().fmap(move |b| a + b)))
+    }
+
+    #[test]
+    fn fold_free_into_state() {
+        let (sum, (input, said)) = add_two()
+            .fold_free(Scripted)
+            .run_state((vec![1, 2, 3], Vec::new()));
+        assert_eq!(sum, 3);
+        assert_eq!(input, [3]);
+        assert_eq!(said, ["first?", "second?"]);
+    }
+
+    struct Shout;
+
+    impl NatTrans<Cmd<()>, Cmd<()>> for Shout {
+        fn apply<A: 'static>(&mut self, fa: Cmd<A>) -> Cmd<A> {
+            match fa {
+                Cmd::Say(msg, k) => Cmd::Say(msg.to_uppercase(), k),
+                ask => ask,
+            }
+        }
+    }
+
+    #[test]
+    fn hoist_free_rewrites_every_layer() {
+        let (sum, said) = run(add_two().hoist_free(Shout), [4, 5].into_iter());
+        assert_eq!(sum, 9);
+        assert_eq!(said, ["FIRST?", "SECOND?"]);
+    }
+}
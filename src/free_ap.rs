@@ -0,0 +1,226 @@
+//! The free applicative over a functor.
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::Any;
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::monoid::Monoid;
+use crate::nat::NatTrans;
+use crate::traversable::Traversable;
+use crate::util::once;
+
+/// The result of an instruction in a [`FreeAp`], with its type erased.
+pub type Erased = Box<dyn Any>;
+
+type Build<A> = Box<dyn FnOnce(&mut dyn Iterator<Item = Erased>) -> A>;
+
+/// A list of `F` instructions, together with how to combine their results into an `A`.
+///
+/// Unlike [`Free`](crate::free::Free), a later instruction can't depend on the result of an
+/// earlier one, since there's no `bind`. In exchange every instruction is known before any of
+/// them run, so a program can be inspected with [`analyze`](FreeAp::analyze), e.g. to list the
+/// options a command-line parser accepts, before it's interpreted with
+/// [`fold_map`](FreeAp::fold_map). As with `Free`, `F` only stands in for its constructor and is
+/// conventionally applied to `()`.
+///
+/// The instructions are stored with their results erased to [`Erased`], and downcast back when
+/// they're combined.
+pub struct FreeAp<F: K1, A> {
+    effects: Vec<F::With<Erased>>,
+    build: Build<A>,
+}
+
+impl<F: FunctorWith + 'static, A: 'static> FreeAp<F, A> {
+    /// Turns a single instruction into a program that returns its result.
+    pub fn lift(fa: F::With<A>) -> Self {
+        let erased = F::fmap_with(fa, |a: A| Box::new(a) as Erased);
+        FreeAp {
+            effects: vec![erased],
+            build: Box::new(|results| {
+                let result = results.next().expect("missing result for an instruction");
+                *result.downcast::<A>().expect("result of the wrong type")
+            }),
+        }
+    }
+
+    /// The number of instructions in the program.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Maps every instruction, in order, into a monoid without running any of them.
+    pub fn analyze<M: Monoid>(&self, f: impl FnMut(&F::With<Erased>) -> M) -> M {
+        M::concat(self.effects.iter().map(f))
+    }
+
+    /// Interprets the program in the applicative `G` by running each instruction through `nt`,
+    /// then combining the results.
+    ///
+    /// The results are combined by a function that moves the program's
+    /// [`pure`](Applicative::pure) values out, so it can only run once, and `G` has to be a
+    /// [`FunctorOnce`] that produces at most one result for the whole program.
+    pub fn fold_map<G: K1>(self, mut nt: impl NatTrans<F, G>) -> G::With<A>
+    where
+        G::With<Erased>:
+            Applicative<Inner = Erased> + FunctorOnce + FunctorWith + K1<With<A> = G::With<A>>,
+    {
+        let build = self.build;
+        let results = self
+            .effects
+            .into_iter()
+            .map(|effect| nt.apply::<Erased>(effect))
+            .collect::<Vec<_>>()
+            .traverse(|g| g);
+        <G::With<Erased>>::fmap_with(
+            results,
+            once(move |results: Vec<Erased>| build(&mut results.into_iter())),
+        )
+    }
+}
+
+impl<F: K1, A> K1 for FreeAp<F, A> {
+    type Inner = A;
+
+    type With<I> = FreeAp<F, I>;
+}
+
+impl<F: FunctorWith + 'static, A: 'static> Functor for FreeAp<F, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> FreeAp<F, B> {
+        let build = self.build;
+        FreeAp {
+            effects: self.effects,
+            build: Box::new(move |results| f(build(results))),
+        }
+    }
+}
+
+impl<F: FunctorWith + 'static, A: 'static> FunctorWith for FreeAp<F, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: FreeAp<F, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> FreeAp<F, Y> {
+        fx.fmap(f)
+    }
+}
+
+/// `zip_with` puts the instructions of `self` before those of `b`.
+impl<F: FunctorWith + 'static, A: 'static> Applicative for FreeAp<F, A> {
+    fn pure<X: 'static>(val: X) -> FreeAp<F, X> {
+        FreeAp {
+            effects: Vec::new(),
+            build: Box::new(move |_| val),
+        }
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: FreeAp<F, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> FreeAp<F, C> {
+        let (mut effects, build_a) = (self.effects, self.build);
+        effects.extend(b.effects);
+        let build_b = b.build;
+        FreeAp {
+            effects,
+            build: Box::new(move |results| {
+                let a = build_a(&mut *results);
+                f(a, build_b(results))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// A command-line option with a default, parsed into a `K`.
+    struct Opt<K> {
+        name: &'static str,
+        default: Option<i32>,
+        k: Box<dyn FnOnce(i32) -> K>,
+    }
+
+    impl<K> K1 for Opt<K> {
+        type Inner = K;
+
+        type With<I> = Opt<I>;
+    }
+
+    impl<K: 'static> Functor for Opt<K> {
+        fn fmap<B: 'static>(self, mut f: impl FnMut(K) -> B + 'static) -> Opt<B> {
+            let k = self.k;
+            Opt {
+                name: self.name,
+                default: self.default,
+                k: Box::new(move |n| f(k(n))),
+            }
+        }
+    }
+
+    impl<K: 'static> FunctorWith for Opt<K> {
+        fn fmap_with<X: 'static, Y: 'static>(
+            fx: Opt<X>,
+            f: impl FnMut(X) -> Y + 'static,
+        ) -> Opt<Y> {
+            fx.fmap(f)
+        }
+    }
+
+    type Parser<A> = FreeAp<Opt<()>, A>;
+
+    fn opt(name: &'static str, default: Option<i32>) -> Parser<i32> {
+        FreeAp::lift(Opt {
+            name,
+            default,
+            k: Box::new(|n| n),
+        })
+    }
+
+    struct Lookup(HashMap<&'static str, i32>);
+
+    impl NatTrans<Opt<()>, Option<()>> for Lookup {
+        fn apply<A: 'static>(&mut self, fa: Opt<A>) -> Option<A> {
+            let n = self.0.get(fa.name).copied().or(fa.default)?;
+            Some((fa.k)(n))
+        }
+    }
+
+    fn area() -> Parser<i32> {
+        opt("width", None).zip_with(opt("height", Some(1)), |w, h| w * h)
+    }
+
+    #[test]
+    fn analyzes_before_running() {
+        let names = area().analyze(|opt| vec![opt.name]);
+        assert_eq!(names, ["width", "height"]);
+        let required = area().analyze(|opt| vec![opt.name; opt.default.is_none() as usize]);
+        assert_eq!(required, ["width"]);
+        assert_eq!(area().len(), 2);
+        assert!(Parser::<()>::pure(()).is_empty());
+    }
+
+    #[test]
+    fn fold_map_interprets() {
+        let args = |pairs: &[(&'static str, i32)]| Lookup(pairs.iter().copied().collect());
+        assert_eq!(
+            area().fold_map(args(&[("width", 3), ("height", 4)])),
+            Some(12)
+        );
+        assert_eq!(area().fold_map(args(&[("width", 3)])), Some(3));
+        assert_eq!(area().fold_map(args(&[("height", 4)])), None);
+
+        let prog = area().zip_with(opt("depth", Some(2)), |a, d| (a * d).to_string());
+        assert_eq!(prog.fold_map(args(&[("width", 5)])), Some("10".to_owned()));
+    }
+}
@@ -0,0 +1,165 @@
+//! The free monad with constant-time binds.
+
+use crate::codensity::Codensity;
+use crate::free::Free;
+use crate::functor::{FunctorOnce, FunctorWith};
+
+/// A [`Free`] program built under [`Codensity`], which makes [`bind`](crate::monad::Monad::bind)
+/// constant time.
+///
+/// Binding on a `Free` walks the whole tree built so far to reach its leaves, so a loop that
+/// keeps binding onto the end of the program takes quadratic time. Build the program as a
+/// `FreeC` instead, and convert it with [`Free::from`] to interpret it. The tree is then built
+/// one instruction at a time as it's [`resume`](Free::resume)d.
+pub type FreeC<F, A> = Codensity<Free<F, ()>, A>;
+
+impl<F: FunctorWith + 'static, A: 'static> FreeC<F, A> {
+    /// Turns a single instruction into a program that returns its result.
+    pub fn lift_f(fa: F::With<A>) -> Self {
+        Codensity::lift(Free::lift_f(fa))
+    }
+}
+
+impl<F: FunctorOnce + FunctorWith + 'static, A: 'static> FreeC<F, A> {
+    /// Wraps an instruction whose holes already hold the rest of the program.
+    pub fn wrap(layer: F::With<FreeC<F, A>>) -> Self {
+        Codensity::lift(Free::wrap(F::fmap_with(layer, Free::from)))
+    }
+}
+
+impl<F: FunctorWith + 'static, A: 'static> From<Free<F, A>> for FreeC<F, A> {
+    fn from(free: Free<F, A>) -> Self {
+        Codensity::lift(free)
+    }
+}
+
+impl<F: FunctorOnce + FunctorWith + 'static, A: 'static> From<FreeC<F, A>> for Free<F, A> {
+    fn from(free: FreeC<F, A>) -> Self {
+        free.lower()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::applicative::Applicative;
+    use crate::either::Either;
+    use crate::functor::Functor;
+    use crate::kind::K1;
+    use crate::monad::Monad;
+
+    thread_local! {
+        static MAPPED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // An instruction that counts how many times it's been mapped over.
+    struct Tick<K>(K);
+
+    impl<K> K1 for Tick<K> {
+        type Inner = K;
+
+        type With<I> = Tick<I>;
+    }
+
+    impl<K: 'static> Functor for Tick<K> {
+        fn fmap<B: 'static>(self, mut f: impl FnMut(K) -> B + 'static) -> Tick<B> {
+            MAPPED.with(|n| n.set(n.get() + 1));
+            Tick(f(self.0))
+        }
+    }
+
+    impl<K: 'static> FunctorWith for Tick<K> {
+        fn fmap_with<X: 'static, Y: 'static>(
+            fx: Tick<X>,
+            f: impl FnMut(X) -> Y + 'static,
+        ) -> Tick<Y> {
+            fx.fmap(f)
+        }
+    }
+
+    impl<K: 'static> FunctorOnce for Tick<K> {}
+
+    fn ticks(mut prog: Free<Tick<()>, u32>) -> (u32, u32) {
+        let mut count = 0;
+        loop {
+            match prog.resume() {
+                Either::Right(a) => return (a, count),
+                Either::Left(Tick(next)) => {
+                    count += 1;
+                    prog = next;
+                }
+            }
+        }
+    }
+
+    fn mapped_while(f: impl FnOnce()) -> usize {
+        let before = MAPPED.with(Cell::get);
+        f();
+        MAPPED.with(Cell::get) - before
+    }
+
+    const STEPS: u32 = 200;
+
+    #[test]
+    fn left_nested_binds_map_each_layer_once() {
+        let mut naive = 0;
+        let free_maps = mapped_while(|| {
+            let mut prog = Free::Pure(0);
+            for _ in 0..STEPS {
+                prog = prog.bind(|n| Free::lift_f(Tick(n + 1)));
+            }
+            naive = ticks(prog).0;
+        });
+
+        let mut church = (0, 0);
+        let free_c_maps = mapped_while(|| {
+            let mut prog = FreeC::<Tick<()>, u32>::pure(0);
+            for _ in 0..STEPS {
+                prog = prog.bind(|n| FreeC::lift_f(Tick(n + 1)));
+            }
+            church = ticks(Free::from(prog));
+        });
+
+        assert_eq!(naive, STEPS);
+        assert_eq!(church, (STEPS, STEPS));
+        // Every bind on the `Free` maps all the layers before it.
+        assert!(free_maps >= (STEPS * (STEPS - 1) / 2) as usize);
+        // Each layer is mapped a fixed number of times: by `Free::lift_f`, to erase its result
+        // for `Codensity`, and twice while lowering, to continue the loop from it.
+        assert_eq!(free_c_maps, 4 * STEPS as usize);
+    }
+
+    #[test]
+    fn long_programs_convert_in_constant_stack() {
+        const LONG: u32 = 100_000;
+
+        let mut left = FreeC::<Tick<()>, u32>::pure(0);
+        for _ in 0..LONG {
+            left = left.bind(|n| FreeC::lift_f(Tick(n + 1)));
+        }
+        assert_eq!(ticks(Free::from(left)), (LONG, LONG));
+
+        fn count_up(n: u32) -> FreeC<Tick<()>, u32> {
+            if n == LONG {
+                FreeC::<Tick<()>, u32>::pure(n)
+            } else {
+                FreeC::lift_f(Tick(n + 1)).bind(count_up)
+            }
+        }
+        assert_eq!(ticks(count_up(0).into()), (LONG, LONG));
+    }
+
+    #[test]
+    fn round_trips_through_free() {
+        let free = Free::lift_f(Tick(1u32)).bind(|n| Free::lift_f(Tick(n * 10)));
+        let church = FreeC::from(free)
+            .fmap(|n| n + 2)
+            .zip_with(FreeC::lift_f(Tick(0u32)), |a, b| a + b);
+        assert_eq!(ticks(Free::from(church)), (12, 3));
+
+        let wrapped = FreeC::wrap(Tick(FreeC::wrap(Tick(FreeC::<Tick<()>, u32>::pure(5)))));
+        assert_eq!(ticks(wrapped.into()), (5, 2));
+    }
+}
@@ -0,0 +1,82 @@
+//! Covariant functors.
+
+use crate::kind::K1;
+
+/// A type constructor that can be mapped over.
+///
+/// The mapping function is `FnMut` rather than `FnOnce` so that containers holding more than one
+/// value, like `Vec`, can call it once per element. It is `'static` so that lazy instances, like
+/// [`State`](crate::state::State), can hold on to it until they are run.
+///
+/// Instances should satisfy the functor laws:
+/// - `x.fmap(|a| a) == x`
+/// - `x.fmap(f).fmap(g) == x.fmap(|a| g(f(a)))`
+pub trait Functor: K1 {
+    fn fmap<B: 'static>(self, f: impl FnMut(Self::Inner) -> B + 'static) -> Self::With<B>;
+}
+
+/// A [`Functor`] that can map any application of its constructor, not just `Self`.
+///
+/// [`K1`] can't state that `<Self::With<X> as K1>::With<Y>` is `Self::With<Y>` for every `X` and
+/// `Y`, so generic code can't map a `Self::With<X>` and get back a `Self::With<Y>` through
+/// [`Functor::fmap`] alone. Types that nest their own constructor, like
+/// [`Free`](crate::free::Free), need exactly that. Every instance is just `fx.fmap(f)`, which
+/// type checks once the constructor is concrete.
+pub trait FunctorWith: Functor {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Self::With<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Self::With<Y>;
+}
+
+/// A [`Functor`] that calls the function passed to `fmap` at most once, like `Option` and unlike
+/// `Vec`. When it's also a [`Monad`](crate::monad::Monad), the same goes for `bind`.
+///
+/// Some instances built on top of another functor move a value into the function they map with
+/// rather than cloning it, like the second argument of [`Free`](crate::free::Free)'s `zip_with`.
+/// They ask for this instead, so that they can't be used with a functor that would need the
+/// value twice.
+pub trait FunctorOnce: Functor {}
+
+/// A type constructor that can be mapped over by reference, leaving `self` intact.
+///
+/// This suits containers whose contents can't be moved out, like a shared `Rc`, where
+/// [`Functor::fmap`] would have to clone them first. Since `self` is only borrowed for the
+/// duration of the call, `f` doesn't need to be `'static`. Parts of `self` that aren't mapped,
+/// like a `Result`'s error, are cloned.
+///
+/// When both are implemented, `x.fmap_ref(f)` should equal `x.clone().fmap(|a| f(&a))`.
+pub trait FunctorRef: K1 {
+    fn fmap_ref<B>(&self, f: impl FnMut(&Self::Inner) -> B) -> Self::With<B>;
+}
+
+/// A functor whose instances decide which element types it can map to.
+///
+/// Some containers can only hold elements with extra capabilities, like a `Cow<'a, T>`, which
+/// needs `T: Clone` (or `ToOwned`) to be well-formed at all, or a `BTreeSet`, which can only be
+/// built from `Ord` elements, so they can't have a [`Functor`] for every element type.
+/// `FunctorC<B>` is implemented for each target `B` separately, and its
+/// instances state the bounds `B` needs in their where-clauses. Every [`Functor`] is a
+/// `FunctorC<B>` for every `B`, so generic code written against `FunctorC` covers both.
+///
+/// Instances should satisfy the functor laws wherever both sides are defined:
+/// - `x.fmap_c(|a| a) == x`
+/// - `x.fmap_c(f).fmap_c(g) == x.fmap_c(|a| g(f(a)))`
+pub trait FunctorC<B> {
+    type Inner;
+
+    /// `Self` with its elements replaced by `B`s.
+    type Mapped;
+
+    fn fmap_c(self, f: impl FnMut(Self::Inner) -> B + 'static) -> Self::Mapped;
+}
+
+impl<F: Functor, B: 'static> FunctorC<B> for F {
+    type Inner = F::Inner;
+
+    type Mapped = F::With<B>;
+
+    fn fmap_c(self, f: impl FnMut(F::Inner) -> B + 'static) -> F::With<B> {
+        self.fmap(f)
+    }
+}
@@ -0,0 +1,183 @@
+//! Futures as a monad over their output.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures::future::{self, FutureExt, LocalBoxFuture};
+
+use crate::applicative::Applicative;
+use crate::async_monad::{AsyncFunctor, AsyncMonad};
+use crate::either::Either;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_rec::MonadRec;
+
+/// A future of an `A`, seen as a type constructor in `A`.
+///
+/// Every `async` block has its own type, so a `K1` can't be generic over the future itself. Like
+/// [`Io`](crate::io::Io), this boxes it instead, so that mapping and binding always give back a
+/// `FutureK`. The closures the hierarchy takes aren't `Send`, so neither is the future.
+///
+/// A `FutureK` is a future too, so it can be `.await`ed or handed to an executor directly.
+pub struct FutureK<A>(LocalBoxFuture<'static, A>);
+
+impl<A> FutureK<A> {
+    pub fn new(fut: impl Future<Output = A> + 'static) -> Self {
+        FutureK(fut.boxed_local())
+    }
+
+    pub fn into_inner(self) -> LocalBoxFuture<'static, A> {
+        self.0
+    }
+}
+
+impl<A> Future for FutureK<A> {
+    type Output = A;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<A> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+impl<A> K1 for FutureK<A> {
+    type Inner = A;
+
+    type With<I> = FutureK<I>;
+}
+
+impl<A: 'static> Functor for FutureK<A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> FutureK<B> {
+        FutureK::new(self.0.map(f))
+    }
+}
+
+impl<A: 'static> FunctorWith for FutureK<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: FutureK<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> FutureK<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A: 'static> FunctorOnce for FutureK<A> {}
+
+/// `zip_with` runs both futures concurrently, like [`join`](future::join).
+impl<A: 'static> Applicative for FutureK<A> {
+    fn pure<X: 'static>(val: X) -> FutureK<X> {
+        FutureK::new(future::ready(val))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: FutureK<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> FutureK<C> {
+        FutureK::new(future::join(self.0, b.0).map(move |(a, b)| f(a, b)))
+    }
+}
+
+impl<A: 'static> Monad for FutureK<A> {
+    fn bind<B: 'static>(self, f: impl FnMut(A) -> FutureK<B> + 'static) -> FutureK<B> {
+        FutureK::new(self.0.then(f))
+    }
+}
+
+impl<A: 'static> MonadRec for FutureK<A> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        mut f: impl FnMut(X) -> FutureK<Either<X, Y>> + 'static,
+    ) -> FutureK<Y> {
+        FutureK::new(async move {
+            let mut x = init;
+            loop {
+                match f(x).await {
+                    Either::Left(next) => x = next,
+                    Either::Right(y) => return y,
+                }
+            }
+        })
+    }
+}
+
+impl<A: 'static> AsyncFunctor for FutureK<A> {
+    type Suspended<B: 'static> = FutureK<B>;
+
+    fn fmap_async<B: 'static>(self, mut f: impl AsyncFnMut(A) -> B + 'static) -> FutureK<B> {
+        FutureK::new(async move { f(self.await).await })
+    }
+}
+
+impl<A: 'static> AsyncMonad for FutureK<A> {
+    fn bind_async<B: 'static>(
+        self,
+        mut f: impl AsyncFnMut(A) -> FutureK<B> + 'static,
+    ) -> FutureK<B> {
+        FutureK::new(async move { f(self.await).await.await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::util::block_on;
+
+    // Returns `Pending` once before it's ready, like a future that has to wait for something.
+    fn later<A: 'static>(val: A) -> FutureK<A> {
+        let mut waited = false;
+        let mut val = Some(val);
+        FutureK::new(future::poll_fn(move |cx| {
+            if waited {
+                Poll::Ready(val.take().unwrap())
+            } else {
+                waited = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }))
+    }
+
+    #[test]
+    fn binds_chain_outputs() {
+        let prog = later(2)
+            .fmap(|n| n * 10)
+            .bind(|n| later(n + 1))
+            .zip_with(FutureK::<&str>::pure(" apples"), |n, s| format!("{n}{s}"));
+        assert_eq!(block_on(prog), "21 apples");
+    }
+
+    type Log = Rc<RefCell<Vec<&'static str>>>;
+
+    fn step(log: &Log, name: &'static str) -> FutureK<()> {
+        let log = Rc::clone(log);
+        later(()).fmap(move |()| log.borrow_mut().push(name))
+    }
+
+    #[test]
+    fn zip_with_runs_both_at_once() {
+        let log = Log::default();
+        let both = step(&log, "a").bind({
+            let log = Rc::clone(&log);
+            move |()| step(&log, "b")
+        });
+        block_on(both.zip_with(step(&log, "c"), |(), ()| ()));
+        assert_eq!(*log.borrow(), ["a", "c", "b"]);
+    }
+
+    #[test]
+    fn tail_rec_m_loops() {
+        let count = FutureK::<()>::tail_rec_m(0u32, |n| {
+            later(if n == 100_000 {
+                Either::Right(n)
+            } else {
+                Either::Left(n + 1)
+            })
+        });
+        assert_eq!(block_on(count), 100_000);
+    }
+}
@@ -0,0 +1,198 @@
+//! The identity functor.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::applicative::Applicative;
+use crate::comonad::Comonad;
+use crate::distributive::Distributive;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::representable::Representable;
+use crate::traversable::Traversable;
+
+/// A transparent wrapper with no effects.
+///
+/// It derefs to the `T`. Coherence rules out `From<Identity<T>> for T`, so unwrapping it is
+/// [`into_inner`](Identity::into_inner), or a `.0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity<T>(pub T);
+
+impl<T> Identity<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    pub fn as_ref(&self) -> Identity<&T> {
+        Identity(&self.0)
+    }
+
+    pub fn as_mut(&mut self) -> Identity<&mut T> {
+        Identity(&mut self.0)
+    }
+}
+
+impl<T> From<T> for Identity<T> {
+    fn from(val: T) -> Self {
+        Identity(val)
+    }
+}
+
+impl<T> Deref for Identity<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Identity<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> K1 for Identity<T> {
+    type Inner = T;
+
+    type With<I> = Identity<I>;
+}
+
+impl<A> Functor for Identity<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Identity<B> {
+        Identity(f(self.0))
+    }
+}
+
+impl<A> FunctorWith for Identity<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Identity<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Identity<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorOnce for Identity<A> {}
+
+impl<A> FunctorRef for Identity<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Identity<B> {
+        Identity(f(&self.0))
+    }
+}
+
+impl<A> Applicative for Identity<A> {
+    fn pure<X: 'static>(val: X) -> Identity<X> {
+        Identity(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Identity<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Identity<C> {
+        Identity(f(self.0, b.0))
+    }
+}
+
+impl<A> Monad for Identity<A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Identity<B> + 'static) -> Identity<B> {
+        f(self.0)
+    }
+}
+
+impl<A> Comonad for Identity<A> {
+    fn extract(self) -> A {
+        self.0
+    }
+
+    fn extend<B: 'static>(self, mut f: impl FnMut(Identity<A>) -> B + 'static) -> Identity<B> {
+        Identity(f(self))
+    }
+}
+
+impl<A: 'static> Distributive for Identity<A> {
+    fn distribute<W>(w: W) -> Identity<W::With<A>>
+    where
+        W: Functor<Inner = Identity<A>> + FunctorRef + 'static,
+    {
+        Identity(w.fmap(Identity::into_inner))
+    }
+}
+
+impl<A> Representable for Identity<A> {
+    type Rep = ();
+
+    fn tabulate<X: 'static>(mut f: impl FnMut(()) -> X + 'static) -> Identity<X> {
+        Identity(f(()))
+    }
+
+    fn index(self, (): ()) -> A {
+        self.0
+    }
+}
+
+impl<A> Foldable for Identity<A> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, A) -> B) -> B {
+        f(init, self.0)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        f(self.0, init)
+    }
+}
+
+impl<A> Traversable for Identity<A> {
+    fn traverse<F, B: 'static>(self, mut f: impl FnMut(A) -> F) -> F::With<Identity<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        f(self.0).fmap(Identity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ident() {
+        let i = Identity(Identity(0));
+        assert_eq!(i.flatten(), Identity(0));
+    }
+
+    #[test]
+    fn comonad_ident() {
+        assert_eq!(Identity(2).extend(|w| w.extract() * 10), Identity(20));
+        assert_eq!(Identity(3).duplicate(), Identity(Identity(3)));
+    }
+
+    #[test]
+    fn wraps_transparently() {
+        let mut name = Identity::from(String::from("ada"));
+        name.push_str(" lovelace");
+        assert_eq!(name.len(), 12);
+        assert_eq!(name.as_ref().fmap_ref(|s| s.len()), Identity(12));
+        *name.as_mut().0 = String::from("grace");
+        assert_eq!(name.clone().into_inner(), "grace");
+        assert!(Identity(1) < Identity(2));
+        assert_eq!(Identity::<u8>::default(), Identity(0));
+    }
+
+    #[test]
+    fn distribute_ident() {
+        assert_eq!(Identity::distribute(Some(Identity(1))), Identity(Some(1)));
+    }
+
+    #[test]
+    fn represent_ident() {
+        assert_eq!(Identity::<()>::tabulate(|()| 5), Identity(5));
+        assert_eq!(Identity("x").index(()), "x");
+    }
+
+    #[test]
+    fn traverse_ident() {
+        assert_eq!(Identity(Some(1)).sequence(), Some(Identity(1)));
+    }
+}
@@ -0,0 +1,47 @@
+//! Functors, folds and traversals that also pass each element's position.
+
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::Functor;
+use crate::kind::K1;
+use crate::monoid::Monoid;
+use crate::traversable::Traversable;
+
+/// A [`K1`] whose elements each have a position of type `Index`, like a `Vec`'s `usize` or a
+/// map's key.
+pub trait Indexed: K1 {
+    type Index;
+}
+
+/// A [`Functor`] that can also hand `f` the index of each element.
+///
+/// `imap` should agree with `fmap` when `f` ignores the index.
+pub trait FunctorWithIndex: Functor + Indexed {
+    fn imap<B: 'static>(
+        self,
+        f: impl FnMut(Self::Index, Self::Inner) -> B + 'static,
+    ) -> Self::With<B>;
+}
+
+/// A [`Foldable`] that can also hand `f` the index of each element, visiting them in the same
+/// order as [`fold_left`](Foldable::fold_left).
+pub trait FoldableWithIndex: Foldable + Indexed {
+    fn ifold_left<B>(self, init: B, f: impl FnMut(B, Self::Index, Self::Inner) -> B) -> B;
+
+    fn ifold_map<M: Monoid>(self, mut f: impl FnMut(Self::Index, Self::Inner) -> M) -> M
+    where
+        Self: Sized,
+    {
+        self.ifold_left(M::empty(), |acc, i, a| acc.combine(f(i, a)))
+    }
+}
+
+/// A [`Traversable`] that can also hand `f` the index of each element.
+pub trait TraversableWithIndex: Traversable + FunctorWithIndex + FoldableWithIndex {
+    fn itraverse<F, B: 'static>(
+        self,
+        f: impl FnMut(Self::Index, Self::Inner) -> F,
+    ) -> F::With<Self::With<B>>
+    where
+        F: Applicative<Inner = B>;
+}
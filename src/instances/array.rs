@@ -0,0 +1,176 @@
+use crate::align::{LengthMismatch, Semialign, Zip};
+use crate::applicative::Applicative;
+use crate::distributive::Distributive;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::representable::Representable;
+use crate::these::These;
+use crate::traversable::Traversable;
+
+// There's no `Applicative`: a lawful `pure` has to fill all `N` slots from a single value, which
+// needs `Clone`, and the trait can't ask for that.
+
+impl<T, const N: usize> K1 for [T; N] {
+    type Inner = T;
+
+    type With<I> = [I; N];
+}
+
+impl<A, const N: usize> Functor for [A; N] {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> [B; N] {
+        self.map(f)
+    }
+}
+
+impl<A, const N: usize> FunctorWith for [A; N] {
+    fn fmap_with<X: 'static, Y: 'static>(fx: [X; N], f: impl FnMut(X) -> Y + 'static) -> [Y; N] {
+        fx.fmap(f)
+    }
+}
+
+impl<A, const N: usize> FunctorRef for [A; N] {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> [B; N] {
+        self.each_ref().map(f)
+    }
+}
+
+// Both sides always have `N` elements, so every position is a `These::Both`, and the zips never
+// truncate, pad or fail.
+impl<A, const N: usize> Semialign for [A; N] {
+    fn align_with<B, C>(self, other: [B; N], mut f: impl FnMut(These<A, B>) -> C) -> [C; N] {
+        self.zip_with_truncated(other, |a, b| f(These::Both(a, b)))
+    }
+}
+
+impl<A, const N: usize> Zip for [A; N] {
+    fn zip_with_truncated<B, C>(self, other: [B; N], mut f: impl FnMut(A, B) -> C) -> [C; N] {
+        let mut other = other.into_iter();
+        self.map(|a| f(a, other.next().expect("both arrays have `N` elements")))
+    }
+
+    fn try_zip_exact_with<B, C>(
+        self,
+        other: [B; N],
+        f: impl FnMut(A, B) -> C,
+    ) -> Result<[C; N], LengthMismatch> {
+        Ok(self.zip_with_truncated(other, f))
+    }
+}
+
+// Slot `i` of the result is `w` with every array replaced by a copy of its element `i`.
+impl<A: Clone + 'static, const N: usize> Distributive for [A; N] {
+    fn distribute<W>(w: W) -> [W::With<A>; N]
+    where
+        W: Functor<Inner = [A; N]> + FunctorRef + 'static,
+    {
+        core::array::from_fn(|i| w.fmap_ref(|arr| arr[i].clone()))
+    }
+}
+
+/// Indexed by the positions below `N`. `index` panics for any other.
+impl<A, const N: usize> Representable for [A; N] {
+    type Rep = usize;
+
+    fn tabulate<X: 'static>(f: impl FnMut(usize) -> X + 'static) -> [X; N] {
+        core::array::from_fn(f)
+    }
+
+    fn index(self, i: usize) -> A {
+        match self.into_iter().nth(i) {
+            Some(a) => a,
+            None => panic!("index {i} is out of bounds for an array of length {N}"),
+        }
+    }
+}
+
+impl<A, const N: usize> Foldable for [A; N] {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, A) -> B) -> B {
+        self.into_iter().fold(init, f)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        self.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl<A, const N: usize> Traversable for [A; N] {
+    fn traverse<F, B: 'static>(self, f: impl FnMut(A) -> F) -> F::With<[B; N]>
+    where
+        F: Applicative<Inner = B>,
+    {
+        // Nested from the right like `Vec`'s `traverse`, filling in a slot at each level. The
+        // outermost zip, for the first element, is the one that unwraps the filled array, so no
+        // other step needs to map over an `F`.
+        let mut acc = F::pure([const { None }; N]);
+        for (i, fb) in self.map(f).into_iter().enumerate().rev() {
+            if i == 0 {
+                return fb.zip_with(acc, |b, mut slots: [Option<B>; N]| {
+                    slots[0] = Some(b);
+                    slots.map(|slot| slot.expect("every slot is filled"))
+                });
+            }
+            acc = fb.zip_with(acc, move |b, mut slots: [Option<B>; N]| {
+                slots[i] = Some(b);
+                slots
+            });
+        }
+        F::pure(core::array::from_fn(|_| unreachable!("the array is empty")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn maps_in_place() {
+        assert_eq!([1, 2, 3].fmap(|n| n * 2), [2, 4, 6]);
+        assert_eq!(["a", "bc"].fmap_ref(|s| s.len()), [1, 2]);
+        assert_eq!([(); 0].fmap(|()| 1), [0; 0]);
+    }
+
+    #[test]
+    fn zips_element_by_element() {
+        assert_eq!([1, 2].zip_with_padded([10, 20], |a, b| a + b), [11, 22]);
+        assert_eq!([1, 2].try_zip_exact(["a", "b"]), Ok([(1, "a"), (2, "b")]));
+        assert_eq!([1].align([()]), [These::Both(1, ())]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn distribute_transposes() {
+        let rows = vec![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(
+            <[i32; 3]>::distribute(rows),
+            [vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(<[u8; 2]>::distribute(None::<[u8; 2]>), [None, None]);
+        let widest = <[u32; 2]>::cotraverse(vec![[1, 9], [5, 2]], |col| col.into_iter().max());
+        assert_eq!(widest, [Some(5), Some(9)]);
+    }
+
+    #[test]
+    fn folds_both_ways() {
+        let words = ["a", "b", "c"];
+        assert_eq!(words.fold_left(String::new(), |acc, s| acc + s), "abc");
+        assert_eq!(words.fold_right(String::new(), |s, acc| acc + s), "cba");
+    }
+
+    #[test]
+    fn traverse_runs_effects_in_order() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&calls);
+        let parsed = ["1", "2", "3"].traverse(move |s| {
+            log.borrow_mut().push(s);
+            s.parse::<i32>().ok()
+        });
+        assert_eq!(parsed, Some([1, 2, 3]));
+        assert_eq!(*calls.borrow(), ["1", "2", "3"]);
+        assert_eq!(["1", "x"].traverse(|s| s.parse::<i32>().ok()), None);
+        assert_eq!([0u8; 0].traverse(Some), Some([]));
+    }
+}
@@ -0,0 +1,113 @@
+use arrayvec::ArrayVec;
+
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::traversable::Traversable;
+
+impl<T, const N: usize> K1 for ArrayVec<T, N> {
+    type Inner = T;
+
+    type With<I> = ArrayVec<I, N>;
+}
+
+impl<A, const N: usize> Functor for ArrayVec<A, N> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> ArrayVec<B, N> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<A, const N: usize> FunctorWith for ArrayVec<A, N> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: ArrayVec<X, N>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> ArrayVec<Y, N> {
+        fx.fmap(f)
+    }
+}
+
+impl<A, const N: usize> FunctorRef for ArrayVec<A, N> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> ArrayVec<B, N> {
+        self.iter().map(f).collect()
+    }
+}
+
+// The capacity is fixed, so `pure` of a zero-capacity `ArrayVec` drops its value rather than
+// panicking. That empty `pure` breaks the identity laws at `N = 0`, since zipping with it
+// empties the other side as well. `zip_with` zips, as for `Vec`, so it never has more than
+// either input. There's no `Monad`: a `flat_map` `bind` would have to drop whatever went past
+// the capacity, which breaks its laws for any `N`.
+impl<A, const N: usize> Applicative for ArrayVec<A, N> {
+    fn pure<X: 'static>(val: X) -> ArrayVec<X, N> {
+        let mut out = ArrayVec::new();
+        let _ = out.try_push(val);
+        out
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: ArrayVec<B, N>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> ArrayVec<C, N> {
+        self.into_iter().zip(b).map(|(a, b)| f(a, b)).collect()
+    }
+}
+
+impl<A, const N: usize> Foldable for ArrayVec<A, N> {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, A) -> B) -> B {
+        self.into_iter().fold(init, f)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        self.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl<A, const N: usize> Traversable for ArrayVec<A, N> {
+    fn traverse<F, B: 'static>(self, f: impl FnMut(A) -> F) -> F::With<ArrayVec<B, N>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        // Nested from the right and reversed at the end, as for `Vec`. The effects fit in an
+        // `ArrayVec` of the same capacity, so this doesn't allocate.
+        let effects: ArrayVec<F, N> = self.into_iter().map(f).collect();
+        let mut acc = F::pure(ArrayVec::new());
+        for (i, fb) in effects.into_iter().enumerate().rev() {
+            acc = fb.zip_with(acc, move |b, mut bs: ArrayVec<B, N>| {
+                bs.push(b);
+                if i == 0 {
+                    bs.reverse();
+                }
+                bs
+            });
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array<const N: usize>(items: impl IntoIterator<Item = u32>) -> ArrayVec<u32, N> {
+        items.into_iter().collect()
+    }
+
+    #[test]
+    fn truncates_at_capacity() {
+        let v = array::<4>([1, 2, 3]);
+        assert_eq!(v.clone().fmap(|n| n * 2)[..], [2, 4, 6]);
+        assert_eq!(v.zip_with(array([5, 5]), |a, b| a + b)[..], [6, 7]);
+        assert!(ArrayVec::<u32, 0>::pure(1).is_empty());
+    }
+
+    #[test]
+    fn traverses_without_allocating() {
+        let v = array::<3>([1, 2, 3]);
+        let all = v.clone().traverse(|n| n.checked_sub(1));
+        assert_eq!(all.as_deref(), Some(&[0, 1, 2][..]));
+        assert_eq!(v.clone().traverse(|n| n.checked_sub(2)), None);
+        assert_eq!(v.fold_right(0, |n, acc| acc * 10 + n), 321);
+    }
+}
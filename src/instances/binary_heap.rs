@@ -0,0 +1,32 @@
+use alloc::collections::BinaryHeap;
+
+use crate::functor::FunctorC;
+
+// A `BinaryHeap` can only be rebuilt from elements that are `Ord`, so it's a `FunctorC` for
+// those rather than a `Functor`. Mapping rebuilds the heap in the new order.
+
+impl<A, B: Ord> FunctorC<B> for BinaryHeap<A> {
+    type Inner = A;
+
+    type Mapped = BinaryHeap<B>;
+
+    fn fmap_c(self, f: impl FnMut(A) -> B + 'static) -> BinaryHeap<B> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Reverse;
+
+    use super::*;
+
+    #[test]
+    fn heap_keeps_its_order() {
+        let heap = BinaryHeap::from([3, 1, 2]);
+        let mut min_heap = heap.fmap_c(Reverse);
+        assert_eq!(min_heap.pop(), Some(Reverse(1)));
+        let negated = BinaryHeap::from([1, 5]).fmap_c(|n: i32| -n);
+        assert_eq!(negated.into_sorted_vec(), [-5, -1]);
+    }
+}
@@ -0,0 +1,99 @@
+use alloc::boxed::Box;
+use core::alloc::Layout;
+use core::mem::{self, MaybeUninit};
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+
+impl<T> K1 for Box<T> {
+    type Inner = T;
+
+    type With<I> = Box<I>;
+}
+
+/// When `A` and `B` have the same size and alignment, the `B` is written into the existing
+/// allocation instead of a new one. Otherwise, this falls back to boxing the result.
+impl<A> Functor for Box<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Box<B> {
+        if Layout::new::<A>() != Layout::new::<B>() {
+            return Box::new(f(*self));
+        }
+        let ptr = Box::into_raw(self);
+        // Frees the allocation, without dropping the `A` that's been moved out, if `f` panics.
+        // SAFETY: `ptr` came from a `Box`, and a `MaybeUninit` is never dropped.
+        let guard = unsafe { Box::from_raw(ptr.cast::<MaybeUninit<A>>()) };
+        // SAFETY: `ptr` points to a live `A`, which is only read this once.
+        let b = f(unsafe { ptr.read() });
+        mem::forget(guard);
+        let ptr = ptr.cast::<B>();
+        // SAFETY: the layouts match, so the allocation is the one a `Box<B>` would have made.
+        unsafe {
+            ptr.write(b);
+            Box::from_raw(ptr)
+        }
+    }
+}
+
+impl<A> FunctorWith for Box<A> {
+    fn fmap_with<X: 'static, Y: 'static>(fx: Box<X>, f: impl FnMut(X) -> Y + 'static) -> Box<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorOnce for Box<A> {}
+
+impl<A> FunctorRef for Box<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Box<B> {
+        Box::new(f(self))
+    }
+}
+
+impl<A> Applicative for Box<A> {
+    fn pure<X: 'static>(val: X) -> Box<X> {
+        Box::new(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Box<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Box<C> {
+        Box::new(f(*self, *b))
+    }
+}
+
+impl<A> Monad for Box<A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Box<B> + 'static) -> Box<B> {
+        f(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_instances() {
+        assert_eq!(Box::new(2).fmap(|x| x * 3), Box::new(6));
+        assert_eq!(
+            Box::new(2).zip_with(Box::new("a"), |n, s| s.repeat(n)),
+            Box::new("aa".to_owned())
+        );
+        assert_eq!(Box::new(Box::new(1)).flatten(), Box::new(1));
+    }
+
+    #[test]
+    fn fmap_reuses_the_allocation_when_layouts_match() {
+        let boxed = Box::new(7u64);
+        let ptr = &*boxed as *const u64 as usize;
+        let signed = boxed.fmap(|n| -(n as i64));
+        assert_eq!(&*signed as *const i64 as usize, ptr);
+        assert_eq!(*signed, -7);
+        assert_eq!(*signed.fmap(|n| n as u8), 249);
+
+        let panicked = std::panic::catch_unwind(|| Box::new(1u32).fmap(|_| -> i32 { panic!() }));
+        assert!(panicked.is_err());
+    }
+}
@@ -0,0 +1,231 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::filterable::{Filterable, Witherable};
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::indexed::{FoldableWithIndex, FunctorWithIndex, Indexed, TraversableWithIndex};
+use crate::kind::K1;
+use crate::traversable::Traversable;
+
+// The instances map over the values and keep the keys. The folds and traversals visit the
+// entries in key order, and any effects run in that order too.
+
+impl<K, V> K1 for BTreeMap<K, V> {
+    type Inner = V;
+
+    type With<I> = BTreeMap<K, I>;
+}
+
+impl<K: Ord, V> Functor for BTreeMap<K, V> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(V) -> B + 'static) -> BTreeMap<K, B> {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<K: Ord, V> FunctorWith for BTreeMap<K, V> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: BTreeMap<K, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> BTreeMap<K, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<K: Ord + Clone, V> FunctorRef for BTreeMap<K, V> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&V) -> B) -> BTreeMap<K, B> {
+        self.iter().map(|(k, v)| (k.clone(), f(v))).collect()
+    }
+}
+
+impl<K, V> Foldable for BTreeMap<K, V> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, V) -> B) -> B {
+        self.into_iter().fold(init, |acc, (_, v)| f(acc, v))
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(V, B) -> B) -> B {
+        self.into_values().rev().fold(init, |acc, v| f(v, acc))
+    }
+}
+
+// The keys are cloned into each `zip_with`'s function, since an applicative like `Vec`'s can call
+// it more than once.
+impl<K, V> Traversable for BTreeMap<K, V>
+where
+    K: Ord + Clone + 'static,
+{
+    fn traverse<F, B: 'static>(self, mut f: impl FnMut(V) -> F) -> F::With<BTreeMap<K, B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        self.itraverse(move |_, v| f(v))
+    }
+}
+
+impl<K: Ord, V> Filterable for BTreeMap<K, V> {
+    fn filter_map<B: 'static>(self, mut f: impl FnMut(V) -> Option<B> + 'static) -> BTreeMap<K, B> {
+        self.into_iter()
+            .filter_map(|(k, v)| Some((k, f(v)?)))
+            .collect()
+    }
+
+    fn partition_map<B: 'static, C: 'static>(
+        self,
+        mut f: impl FnMut(V) -> Either<B, C> + 'static,
+    ) -> (BTreeMap<K, B>, BTreeMap<K, C>) {
+        let (mut lefts, mut rights) = (BTreeMap::new(), BTreeMap::new());
+        for (k, v) in self {
+            match f(v) {
+                Either::Left(b) => {
+                    lefts.insert(k, b);
+                }
+                Either::Right(c) => {
+                    rights.insert(k, c);
+                }
+            }
+        }
+        (lefts, rights)
+    }
+}
+
+impl<K, V> Witherable for BTreeMap<K, V>
+where
+    K: Ord + Clone + 'static,
+{
+    fn wither<F, B: 'static>(self, mut f: impl FnMut(V) -> F) -> F::With<BTreeMap<K, B>>
+    where
+        F: Applicative<Inner = Option<B>>,
+    {
+        let effects: Vec<(K, F)> = self.into_iter().map(|(k, v)| (k, f(v))).collect();
+        let mut acc = F::pure(BTreeMap::new());
+        for (k, fb) in effects.into_iter().rev() {
+            acc = fb.zip_with(acc, move |b, mut map: BTreeMap<K, B>| {
+                if let Some(b) = b {
+                    map.insert(k.clone(), b);
+                }
+                map
+            });
+        }
+        acc
+    }
+}
+
+impl<K, V> Indexed for BTreeMap<K, V> {
+    type Index = K;
+}
+
+impl<K: Ord + Clone, V> FunctorWithIndex for BTreeMap<K, V> {
+    fn imap<B: 'static>(self, mut f: impl FnMut(K, V) -> B + 'static) -> BTreeMap<K, B> {
+        self.into_iter()
+            .map(|(k, v)| (k.clone(), f(k, v)))
+            .collect()
+    }
+}
+
+impl<K, V> FoldableWithIndex for BTreeMap<K, V> {
+    fn ifold_left<B>(self, init: B, mut f: impl FnMut(B, K, V) -> B) -> B {
+        self.into_iter().fold(init, |acc, (k, v)| f(acc, k, v))
+    }
+}
+
+impl<K, V> TraversableWithIndex for BTreeMap<K, V>
+where
+    K: Ord + Clone + 'static,
+{
+    fn itraverse<F, B: 'static>(self, mut f: impl FnMut(K, V) -> F) -> F::With<BTreeMap<K, B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        // Nested from the right like `Vec`'s `traverse`, so the effects run in key order.
+        let effects: Vec<(K, F)> = self
+            .into_iter()
+            .map(|(k, v)| (k.clone(), f(k, v)))
+            .collect();
+        let mut acc = F::pure(BTreeMap::new());
+        for (k, fb) in effects.into_iter().rev() {
+            acc = fb.zip_with(acc, move |b, mut map: BTreeMap<K, B>| {
+                map.insert(k.clone(), b);
+                map
+            });
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn stock() -> BTreeMap<&'static str, u32> {
+        BTreeMap::from([("apples", 3), ("pears", 0), ("plums", 12)])
+    }
+
+    #[test]
+    fn maps_values_and_keeps_keys() {
+        let doubled = stock().fmap(|n| n * 2);
+        assert_eq!(
+            doubled,
+            BTreeMap::from([("apples", 6), ("pears", 0), ("plums", 24)])
+        );
+        let labels = stock().imap(|name, n| format!("{n} {name}"));
+        assert_eq!(
+            labels.into_values().collect::<Vec<_>>(),
+            ["3 apples", "0 pears", "12 plums"]
+        );
+        assert_eq!(stock().fmap_ref(|n| n + 1)["pears"], 1);
+    }
+
+    #[test]
+    fn folds_in_key_order() {
+        let names = stock().ifold_left(String::new(), |acc, name, _| acc + &name[..1]);
+        assert_eq!(names, "app");
+        let order = stock().fold_right(Vec::new(), |n, mut acc| {
+            acc.push(n);
+            acc
+        });
+        assert_eq!(order, [12, 0, 3]);
+        assert_eq!(stock().fold_map(|n| vec![n]), [3, 0, 12]);
+    }
+
+    #[test]
+    fn traverse_runs_effects_in_key_order() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&calls);
+        let restocked = stock().itraverse(move |name, n| {
+            log.borrow_mut().push(name);
+            n.checked_sub(1)
+        });
+        assert_eq!(restocked, None);
+        assert_eq!(*calls.borrow(), ["apples", "pears", "plums"]);
+
+        let halved = stock().traverse(|n| (n % 2 == 0).then_some(n / 2));
+        assert_eq!(halved, None);
+        let labelled = stock().traverse(|n| Some(n.to_string()));
+        assert_eq!(labelled.unwrap()["plums"], "12");
+    }
+
+    #[test]
+    fn filters_and_withers() {
+        let in_stock = stock().filter(|n| *n > 0);
+        assert_eq!(in_stock, BTreeMap::from([("apples", 3), ("plums", 12)]));
+        let (few, many) = stock().partition_map(|n| {
+            if n < 10 {
+                Either::Left(n)
+            } else {
+                Either::Right(n)
+            }
+        });
+        assert_eq!(few, BTreeMap::from([("apples", 3), ("pears", 0)]));
+        assert_eq!(many, BTreeMap::from([("plums", 12)]));
+        let withered = stock().wither(|n| Some((n != 3).then_some(n)));
+        assert_eq!(
+            withered,
+            Some(BTreeMap::from([("pears", 0), ("plums", 12)]))
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::collections::BTreeSet;
+
+use crate::functor::FunctorC;
+
+// A `BTreeSet` can only be rebuilt from elements that are `Ord`, so it's a `FunctorC` for those
+// rather than a `Functor`. Mapping re-sorts the elements, and the ones that `f` maps to the same
+// value merge.
+
+impl<A, B: Ord> FunctorC<B> for BTreeSet<A> {
+    type Inner = A;
+
+    type Mapped = BTreeSet<B>;
+
+    fn fmap_c(self, f: impl FnMut(A) -> B + 'static) -> BTreeSet<B> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Works on any container that can map `i32`s to `i32`s, whatever it needs of them.
+    fn negate<F: FunctorC<i32, Inner = i32>>(fa: F) -> F::Mapped {
+        fa.fmap_c(|n| -n)
+    }
+
+    #[test]
+    fn maps_and_resorts() {
+        let set = BTreeSet::from([1, 2, 3]);
+        assert!(negate(set.clone()).into_iter().eq([-3, -2, -1]));
+        assert_eq!(set.fmap_c(|n| n / 2), BTreeSet::from([0, 1]));
+        assert_eq!(negate(vec![1, 2]), [-1, -2]);
+    }
+}
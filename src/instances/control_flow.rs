@@ -0,0 +1,168 @@
+use core::ops::ControlFlow;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_rec::MonadRec;
+use crate::try_functor::TryFunctor;
+
+// `ControlFlow<B, C>` is a `K1` over the `Continue` value, with `Break` short-circuiting like an
+// error, the same as `?` treats it.
+
+impl<B, C> K1 for ControlFlow<B, C> {
+    type Inner = C;
+
+    type With<I> = ControlFlow<B, I>;
+}
+
+impl<B, A> Functor for ControlFlow<B, A> {
+    fn fmap<C: 'static>(self, mut f: impl FnMut(A) -> C + 'static) -> ControlFlow<B, C> {
+        match self {
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+            ControlFlow::Continue(a) => ControlFlow::Continue(f(a)),
+        }
+    }
+}
+
+impl<B, A> FunctorWith for ControlFlow<B, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: ControlFlow<B, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> ControlFlow<B, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<B: Clone, A> FunctorRef for ControlFlow<B, A> {
+    fn fmap_ref<C>(&self, mut f: impl FnMut(&A) -> C) -> ControlFlow<B, C> {
+        match self {
+            ControlFlow::Break(b) => ControlFlow::Break(b.clone()),
+            ControlFlow::Continue(a) => ControlFlow::Continue(f(a)),
+        }
+    }
+}
+
+impl<B, A> Applicative for ControlFlow<B, A> {
+    fn pure<X: 'static>(val: X) -> ControlFlow<B, X> {
+        ControlFlow::Continue(val)
+    }
+
+    fn zip_with<X: 'static, Y: 'static>(
+        self,
+        x: ControlFlow<B, X>,
+        mut f: impl FnMut(A, X) -> Y + 'static,
+    ) -> ControlFlow<B, Y> {
+        match (self, x) {
+            (ControlFlow::Break(b), _) | (_, ControlFlow::Break(b)) => ControlFlow::Break(b),
+            (ControlFlow::Continue(a), ControlFlow::Continue(x)) => ControlFlow::Continue(f(a, x)),
+        }
+    }
+}
+
+impl<B, A> Monad for ControlFlow<B, A> {
+    fn bind<C: 'static>(
+        self,
+        mut f: impl FnMut(A) -> ControlFlow<B, C> + 'static,
+    ) -> ControlFlow<B, C> {
+        match self {
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+            ControlFlow::Continue(a) => f(a),
+        }
+    }
+}
+
+impl<B, A> MonadRec for ControlFlow<B, A> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        mut f: impl FnMut(X) -> ControlFlow<B, Either<X, Y>> + 'static,
+    ) -> ControlFlow<B, Y> {
+        let mut x = init;
+        loop {
+            match f(x)? {
+                Either::Left(next) => x = next,
+                Either::Right(y) => return ControlFlow::Continue(y),
+            }
+        }
+    }
+}
+
+impl<B, A> TryFunctor for ControlFlow<B, A> {
+    fn try_fmap<C: 'static, E>(
+        self,
+        mut f: impl FnMut(A) -> Result<C, E>,
+    ) -> Result<ControlFlow<B, C>, E> {
+        Ok(match self {
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+            ControlFlow::Continue(a) => ControlFlow::Continue(f(a)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::traversable::Traversable;
+
+    // Generic code written against `Monad` stops at the first `Break`.
+    fn sum_while<M>(items: Vec<M>) -> M
+    where
+        M: Monad<Inner = u32, With<u32> = M>,
+    {
+        items.into_iter().fold(M::pure(0u32), |acc, m| {
+            acc.zip_with::<u32, u32>(m, |a, b| a + b)
+        })
+    }
+
+    #[test]
+    fn breaks_short_circuit() {
+        let keep = |n: u32| {
+            if n < 10 {
+                ControlFlow::Continue(n)
+            } else {
+                ControlFlow::Break(n)
+            }
+        };
+        assert_eq!(
+            sum_while::<ControlFlow<u32, u32>>(vec![keep(1), keep(2)]),
+            ControlFlow::Continue(3)
+        );
+        assert_eq!(
+            sum_while::<ControlFlow<u32, u32>>(vec![keep(1), keep(12), keep(20)]),
+            ControlFlow::Break(12)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn traverse_stops_at_the_first_break() {
+        let keep = |n: u32| {
+            if n < 10 {
+                ControlFlow::Continue(n)
+            } else {
+                ControlFlow::Break(n)
+            }
+        };
+        assert_eq!(vec![1, 2, 30, 40].traverse(keep), ControlFlow::Break(30));
+    }
+
+    #[test]
+    fn bind_and_loop() {
+        let halve = |n: u32| {
+            if n % 2 == 1 {
+                ControlFlow::Break(n)
+            } else {
+                ControlFlow::Continue(n / 2)
+            }
+        };
+        assert_eq!(
+            ControlFlow::Continue(8).bind(halve).bind(halve),
+            ControlFlow::<u32, u32>::Continue(2)
+        );
+        let odd_part =
+            ControlFlow::<u32, ()>::tail_rec_m(48, move |n| halve(n).fmap(Either::<_, ()>::Left));
+        assert_eq!(odd_part, ControlFlow::Break(3));
+    }
+}
@@ -0,0 +1,60 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::functor::FunctorC;
+
+// `Cow<'a, T>` needs `T: ToOwned`, so it can only be a `FunctorC`. Mapping needs an owned value
+// to hand to `f`, which copies a borrowed one, and always gives back an owned `Cow`.
+
+impl<'a, A: Clone, B: Clone + 'a> FunctorC<B> for Cow<'a, A> {
+    type Inner = A;
+
+    type Mapped = Cow<'a, B>;
+
+    fn fmap_c(self, mut f: impl FnMut(A) -> B + 'static) -> Cow<'a, B> {
+        Cow::Owned(f(self.into_owned()))
+    }
+}
+
+impl<'a> FunctorC<String> for Cow<'a, str> {
+    type Inner = String;
+
+    type Mapped = Cow<'a, str>;
+
+    fn fmap_c(self, mut f: impl FnMut(String) -> String + 'static) -> Cow<'a, str> {
+        Cow::Owned(f(self.into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functor::Functor;
+
+    // Generic over anything that can map its `u32`s to `String`s.
+    fn render<F: FunctorC<String, Inner = u32>>(fa: F) -> F::Mapped {
+        fa.fmap_c(|n| n.to_string())
+    }
+
+    #[test]
+    fn maps_owned_and_borrowed() {
+        let n = 4u32;
+        assert_eq!(render(Cow::Borrowed(&n)), Cow::<String>::Owned("4".into()));
+        assert_eq!(
+            render(Cow::<u32>::Owned(5)),
+            Cow::<String>::Owned("5".into())
+        );
+        assert_eq!(render(Some(6)), Some("6".to_owned()));
+        assert_eq!(render(vec![1, 2]), ["1", "2"]);
+    }
+
+    #[test]
+    fn maps_str() {
+        fn shout(s: Cow<'_, str>) -> Cow<'_, str> {
+            s.fmap_c(|s| s.to_uppercase())
+        }
+        assert_eq!(shout(Cow::Borrowed("hi")), "HI");
+        assert!(matches!(shout(Cow::Borrowed("hi")), Cow::Owned(_)));
+        assert_eq!(Some(1).fmap(|n| n + 1).fmap_c(|n| n * 2), Some(4));
+    }
+}
@@ -0,0 +1,163 @@
+//! Interop with the `either` crate's `Either`, which gets the same right-biased instances as this
+//! crate's own.
+
+use either::Either as Other;
+
+use crate::applicative::Applicative;
+use crate::bifunctor::Bifunctor;
+use crate::either::Either;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::{K1, K2};
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::try_functor::TryFunctor;
+
+impl<L, R> From<Other<L, R>> for Either<L, R> {
+    fn from(other: Other<L, R>) -> Self {
+        match other {
+            Other::Left(l) => Either::Left(l),
+            Other::Right(r) => Either::Right(r),
+        }
+    }
+}
+
+impl<L, R> From<Either<L, R>> for Other<L, R> {
+    fn from(either: Either<L, R>) -> Self {
+        match either {
+            Either::Left(l) => Other::Left(l),
+            Either::Right(r) => Other::Right(r),
+        }
+    }
+}
+
+impl<L, R> K1 for Other<L, R> {
+    type Inner = R;
+
+    type With<I> = Other<L, I>;
+}
+
+impl<L, A> Functor for Other<L, A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Other<L, B> {
+        self.map_right(f)
+    }
+}
+
+impl<L, A> FunctorWith for Other<L, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Other<L, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Other<L, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<L, A> FunctorOnce for Other<L, A> {}
+
+impl<L: Clone, A> FunctorRef for Other<L, A> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> Other<L, B> {
+        self.as_ref().map_left(L::clone).map_right(f)
+    }
+}
+
+impl<L, A> Applicative for Other<L, A> {
+    fn pure<X: 'static>(val: X) -> Other<L, X> {
+        Other::Right(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Other<L, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Other<L, C> {
+        match (self, b) {
+            (Other::Left(l), _) | (_, Other::Left(l)) => Other::Left(l),
+            (Other::Right(a), Other::Right(b)) => Other::Right(f(a, b)),
+        }
+    }
+}
+
+impl<L, A> Monad for Other<L, A> {
+    fn bind<B: 'static>(self, f: impl FnMut(A) -> Other<L, B> + 'static) -> Other<L, B> {
+        self.right_and_then(f)
+    }
+}
+
+impl<L, R> K2 for Other<L, R> {
+    type Inner1 = L;
+    type Inner2 = R;
+
+    type With<A, B> = Other<A, B>;
+}
+
+impl<L, R> Bifunctor for Other<L, R> {
+    fn bimap<C, D>(self, f: impl FnMut(L) -> C, g: impl FnMut(R) -> D) -> Other<C, D> {
+        self.map_left(f).map_right(g)
+    }
+}
+
+impl<L, A> MonadError<L> for Other<L, A> {
+    fn throw_error<X: 'static>(err: L) -> Other<L, X> {
+        Other::Left(err)
+    }
+
+    fn catch_error(self, handler: impl FnMut(L) -> Other<L, A> + 'static) -> Other<L, A> {
+        self.left_and_then(handler)
+    }
+}
+
+impl<L, A> TryFunctor for Other<L, A> {
+    fn try_fmap<B: 'static, E>(
+        self,
+        mut f: impl FnMut(A) -> Result<B, E>,
+    ) -> Result<Other<L, B>, E> {
+        Ok(match self {
+            Other::Left(l) => Other::Left(l),
+            Other::Right(a) => Other::Right(f(a)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::free::Free;
+
+    #[test]
+    fn right_biased_like_the_crates_own() {
+        let r: Other<&str, i32> = Other::Right(2);
+        let l: Other<&str, i32> = Other::Left("nope");
+        assert_eq!(r.fmap(|x| x * 2), Other::Right(4));
+        assert_eq!(r.zip_with(l, |a, b| a + b), Other::Left("nope"));
+        assert_eq!(r.bind(|x| Other::Right(x + 1)), Other::Right(3));
+        assert_eq!(
+            l.catch_error(|e| Other::Right(e.len() as i32)),
+            Other::Right(4)
+        );
+        assert_eq!(l.bimap(str::len, |x| x + 1), Other::Left(4));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn drives_free() {
+        type Prog = Free<Other<&'static str, ()>, i32>;
+        let run = |mut prog: Prog| loop {
+            match prog.resume() {
+                Either::Right(a) => return Other::Right(a),
+                Either::Left(Other::Left(e)) => return Other::Left(e),
+                Either::Left(Other::Right(next)) => prog = next,
+            }
+        };
+        let ok = Free::lift_f(Other::Right(1)).bind(|n| Free::lift_f(Other::Right(n + 1)));
+        assert_eq!(run(ok), Other::Right(2));
+        let stopped = Free::lift_f(Other::Left("stop")).zip_with(Free::Pure(1), |a: i32, b| a + b);
+        assert_eq!(run(stopped), Other::Left("stop"));
+    }
+
+    #[test]
+    fn converts_both_ways() {
+        let ours: Either<&str, i32> = Other::Right(1).into();
+        assert_eq!(ours, Either::Right(1));
+        assert_eq!(Other::from(Either::<_, i32>::Left("e")), Other::Left("e"));
+    }
+}
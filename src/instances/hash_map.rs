@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::vec::Vec;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::filterable::{Filterable, Witherable};
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::indexed::{FoldableWithIndex, FunctorWithIndex, Indexed, TraversableWithIndex};
+use crate::kind::K1;
+use crate::traversable::Traversable;
+
+// The instances map over the values and keep the keys. A `HashMap` has no order of its own, so
+// the folds and traversals visit the entries in whatever order `iter` gives, and any effects run
+// in that order too. Use a `BTreeMap` when the order matters.
+
+impl<K, V, S> K1 for HashMap<K, V, S> {
+    type Inner = V;
+
+    type With<I> = HashMap<K, I, S>;
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> Functor for HashMap<K, V, S> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(V) -> B + 'static) -> HashMap<K, B, S> {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> FunctorWith for HashMap<K, V, S> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: HashMap<K, X, S>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> HashMap<K, Y, S> {
+        fx.fmap(f)
+    }
+}
+
+impl<K: Eq + Hash + Clone, V, S: BuildHasher + Default> FunctorRef for HashMap<K, V, S> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&V) -> B) -> HashMap<K, B, S> {
+        self.iter().map(|(k, v)| (k.clone(), f(v))).collect()
+    }
+}
+
+impl<K, V, S> Foldable for HashMap<K, V, S> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, V) -> B) -> B {
+        self.into_iter().fold(init, |acc, (_, v)| f(acc, v))
+    }
+
+    // There's no reverse iterator, so this collects the values first.
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(V, B) -> B) -> B {
+        let values: Vec<V> = self.into_values().collect();
+        values.into_iter().rev().fold(init, |acc, v| f(v, acc))
+    }
+}
+
+// The keys are cloned into each `zip_with`'s function, since an applicative like `Vec`'s can call
+// it more than once.
+impl<K, V, S> Traversable for HashMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    S: BuildHasher + Default + 'static,
+{
+    fn traverse<F, B: 'static>(self, mut f: impl FnMut(V) -> F) -> F::With<HashMap<K, B, S>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        self.itraverse(move |_, v| f(v))
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> Filterable for HashMap<K, V, S> {
+    fn filter_map<B: 'static>(
+        self,
+        mut f: impl FnMut(V) -> Option<B> + 'static,
+    ) -> HashMap<K, B, S> {
+        self.into_iter()
+            .filter_map(|(k, v)| Some((k, f(v)?)))
+            .collect()
+    }
+
+    fn partition_map<B: 'static, C: 'static>(
+        self,
+        mut f: impl FnMut(V) -> Either<B, C> + 'static,
+    ) -> (HashMap<K, B, S>, HashMap<K, C, S>) {
+        let (mut lefts, mut rights) = (HashMap::default(), HashMap::default());
+        for (k, v) in self {
+            match f(v) {
+                Either::Left(b) => {
+                    lefts.insert(k, b);
+                }
+                Either::Right(c) => {
+                    rights.insert(k, c);
+                }
+            }
+        }
+        (lefts, rights)
+    }
+}
+
+impl<K, V, S> Witherable for HashMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    S: BuildHasher + Default + 'static,
+{
+    fn wither<F, B: 'static>(self, mut f: impl FnMut(V) -> F) -> F::With<HashMap<K, B, S>>
+    where
+        F: Applicative<Inner = Option<B>>,
+    {
+        let effects: Vec<(K, F)> = self.into_iter().map(|(k, v)| (k, f(v))).collect();
+        let mut acc = F::pure(HashMap::with_capacity_and_hasher(
+            effects.len(),
+            S::default(),
+        ));
+        for (k, fb) in effects.into_iter().rev() {
+            acc = fb.zip_with(acc, move |b, mut map: HashMap<K, B, S>| {
+                if let Some(b) = b {
+                    map.insert(k.clone(), b);
+                }
+                map
+            });
+        }
+        acc
+    }
+}
+
+impl<K, V, S> Indexed for HashMap<K, V, S> {
+    type Index = K;
+}
+
+impl<K: Eq + Hash + Clone, V, S: BuildHasher + Default> FunctorWithIndex for HashMap<K, V, S> {
+    fn imap<B: 'static>(self, mut f: impl FnMut(K, V) -> B + 'static) -> HashMap<K, B, S> {
+        self.into_iter()
+            .map(|(k, v)| (k.clone(), f(k, v)))
+            .collect()
+    }
+}
+
+impl<K, V, S> FoldableWithIndex for HashMap<K, V, S> {
+    fn ifold_left<B>(self, init: B, mut f: impl FnMut(B, K, V) -> B) -> B {
+        self.into_iter().fold(init, |acc, (k, v)| f(acc, k, v))
+    }
+}
+
+impl<K, V, S> TraversableWithIndex for HashMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    S: BuildHasher + Default + 'static,
+{
+    fn itraverse<F, B: 'static>(self, mut f: impl FnMut(K, V) -> F) -> F::With<HashMap<K, B, S>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        // Nested from the right like `Vec`'s `traverse`, so the effects run in iteration order.
+        let effects: Vec<(K, F)> = self
+            .into_iter()
+            .map(|(k, v)| (k.clone(), f(k, v)))
+            .collect();
+        let mut acc = F::pure(HashMap::with_capacity_and_hasher(
+            effects.len(),
+            S::default(),
+        ));
+        for (k, fb) in effects.into_iter().rev() {
+            acc = fb.zip_with(acc, move |b, mut map: HashMap<K, B, S>| {
+                map.insert(k.clone(), b);
+                map
+            });
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn ages() -> HashMap<&'static str, u32> {
+        HashMap::from([("ada", 36), ("alan", 41), ("grace", 85)])
+    }
+
+    #[test]
+    fn maps_values_and_keeps_keys() {
+        let next = ages().fmap(|n| n + 1);
+        assert_eq!(
+            next,
+            HashMap::from([("ada", 37), ("alan", 42), ("grace", 86)])
+        );
+        let labels = ages().imap(|name, n| format!("{name}: {n}"));
+        assert_eq!(labels["alan"], "alan: 41");
+        assert_eq!(ages().fmap_ref(|n| n * 2)["ada"], 72);
+    }
+
+    #[test]
+    fn folds_over_values() {
+        assert_eq!(ages().fold_left(0, |acc, n| acc + n), 162);
+        assert_eq!(ages().fold_right(0, |n, acc| acc + n), 162);
+        assert_eq!(ages().ifold_left(0, |acc, name, _| acc + name.len()), 12);
+    }
+
+    #[test]
+    fn traverse_runs_every_effect() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&calls);
+        let doubled = ages().traverse(move |n| {
+            log.borrow_mut().push(n);
+            n.checked_mul(2)
+        });
+        assert_eq!(doubled, Some(ages().fmap(|n| n * 2)));
+        let mut seen = calls.borrow().clone();
+        seen.sort();
+        assert_eq!(seen, [36, 41, 85]);
+
+        assert_eq!(ages().traverse(|n| (n < 80).then_some(n)), None);
+        let checked = ages().itraverse(|name, n| (name.len() > 2).then_some(n));
+        assert_eq!(checked, Some(ages()));
+    }
+
+    #[test]
+    fn filters_and_withers() {
+        let young = ages().filter(|n| *n < 50);
+        assert_eq!(young, HashMap::from([("ada", 36), ("alan", 41)]));
+        let (odd, even) = ages().partition_map(|n| {
+            if n % 2 == 1 {
+                Either::Left(n)
+            } else {
+                Either::Right(n)
+            }
+        });
+        assert_eq!(odd, HashMap::from([("alan", 41), ("grace", 85)]));
+        assert_eq!(even, HashMap::from([("ada", 36)]));
+        let withered = ages().wither(|n| Some((n > 40).then_some(n)));
+        assert_eq!(withered, Some(HashMap::from([("alan", 41), ("grace", 85)])));
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use crate::functor::FunctorC;
+
+// A `HashSet` can only be rebuilt from elements it can hash, so it's a `FunctorC` for those
+// rather than a `Functor`. Elements that `f` maps to the same value merge, so the result can be
+// smaller.
+
+impl<A, B, S> FunctorC<B> for HashSet<A, S>
+where
+    B: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Inner = A;
+
+    type Mapped = HashSet<B, S>;
+
+    fn fmap_c(self, f: impl FnMut(A) -> B + 'static) -> HashSet<B, S> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_and_merges() {
+        let words = HashSet::from(["a", "bb", "cc", "ddd"]);
+        assert_eq!(words.fmap_c(str::len), HashSet::from([1, 2, 3]));
+    }
+}
@@ -0,0 +1,142 @@
+use std::hash::{BuildHasher, Hash};
+use std::vec::Vec;
+
+use im::{HashMap, Vector};
+
+use crate::foldable::Foldable;
+use crate::functor::FunctorC;
+use crate::indexed::{FoldableWithIndex, Indexed};
+use crate::kind::K1;
+use crate::monoid::{Monoid, Semigroup};
+
+// Every operation on an `im` collection needs its elements to be `Clone`, since the structure
+// is shared between versions and copied on write. So they're `FunctorC`s for `Clone` targets
+// rather than `Functor`s, which also rules out `Traversable` and the applicative hierarchy.
+// `HashMap`'s instances map over the values and keep the keys, like `std`'s.
+
+impl<A> K1 for Vector<A> {
+    type Inner = A;
+
+    type With<I> = Vector<I>;
+}
+
+impl<A: Clone, B: Clone> FunctorC<B> for Vector<A> {
+    type Inner = A;
+
+    type Mapped = Vector<B>;
+
+    fn fmap_c(self, f: impl FnMut(A) -> B + 'static) -> Vector<B> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<A: Clone> Foldable for Vector<A> {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, A) -> B) -> B {
+        self.into_iter().fold(init, f)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        self.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl<A> Indexed for Vector<A> {
+    type Index = usize;
+}
+
+impl<A: Clone> FoldableWithIndex for Vector<A> {
+    fn ifold_left<B>(self, init: B, mut f: impl FnMut(B, usize, A) -> B) -> B {
+        self.into_iter()
+            .enumerate()
+            .fold(init, |acc, (i, a)| f(acc, i, a))
+    }
+}
+
+/// Concatenation, which shares the structure of both sides.
+impl<A: Clone> Semigroup for Vector<A> {
+    fn combine(mut self, other: Self) -> Self {
+        self.append(other);
+        self
+    }
+}
+
+impl<A: Clone> Monoid for Vector<A> {
+    fn empty() -> Self {
+        Vector::new()
+    }
+}
+
+impl<K, V, S> K1 for HashMap<K, V, S> {
+    type Inner = V;
+
+    type With<I> = HashMap<K, I, S>;
+}
+
+impl<K, V, B, S> FunctorC<B> for HashMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    B: Clone,
+    S: BuildHasher + Default,
+{
+    type Inner = V;
+
+    type Mapped = HashMap<K, B, S>;
+
+    fn fmap_c(self, mut f: impl FnMut(V) -> B + 'static) -> HashMap<K, B, S> {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone, S: BuildHasher> Foldable for HashMap<K, V, S> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, V) -> B) -> B {
+        self.into_iter().fold(init, |acc, (_, v)| f(acc, v))
+    }
+
+    // There's no reverse iterator, so this collects the values first.
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(V, B) -> B) -> B {
+        let values: Vec<V> = self.into_iter().map(|(_, v)| v).collect();
+        values.into_iter().rev().fold(init, |acc, v| f(v, acc))
+    }
+}
+
+impl<K, V, S> Indexed for HashMap<K, V, S> {
+    type Index = K;
+}
+
+impl<K: Eq + Hash + Clone, V: Clone, S: BuildHasher> FoldableWithIndex for HashMap<K, V, S> {
+    fn ifold_left<B>(self, init: B, mut f: impl FnMut(B, K, V) -> B) -> B {
+        self.into_iter().fold(init, |acc, (k, v)| f(acc, k, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use im::{hashmap, vector};
+
+    use super::*;
+    use crate::monoid::Sum;
+
+    #[test]
+    fn vector_maps_folds_and_concatenates() {
+        let v = vector![1u32, 2, 3];
+        assert_eq!(
+            v.clone().fmap_c(|n| f64::from(n) / 2.0),
+            vector![0.5, 1.0, 1.5]
+        );
+        assert_eq!(v.clone().fold_right(0, |n, acc| acc * 10 + n), 321);
+        let weighted = v.clone().ifold_left(0, |acc, i, n| acc + i as u32 * n);
+        assert_eq!(weighted, 8);
+        assert_eq!(v.combine(vector![4]), vector![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn hash_map_maps_values() {
+        let ages = hashmap! {"ann" => 31u32, "bob" => 27};
+        let next = ages.clone().fmap_c(|age| age + 1);
+        assert_eq!(next, hashmap! {"ann" => 32, "bob" => 28});
+        assert_eq!(ages.clone().fold_map(Sum), Sum(58));
+        let named = ages.ifold_left(0, |acc, name, _| acc + name.len());
+        assert_eq!(named, 6);
+    }
+}
@@ -0,0 +1,39 @@
+//! Instances for types from the standard library, and from other crates behind features named
+//! after them.
+
+mod array;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "alloc")]
+mod binary_heap;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+mod btree_map;
+#[cfg(feature = "alloc")]
+mod btree_set;
+mod control_flow;
+#[cfg(feature = "alloc")]
+mod cow;
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "std")]
+mod hash_map;
+#[cfg(feature = "std")]
+mod hash_set;
+#[cfg(feature = "im")]
+mod im;
+mod option;
+mod phantom;
+mod poll;
+#[cfg(feature = "alloc")]
+mod rc;
+mod result;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+mod str;
+#[cfg(feature = "alloc")]
+mod string;
+mod tuple;
+#[cfg(feature = "alloc")]
+mod vec;
@@ -0,0 +1,277 @@
+use crate::align::{Align, Semialign};
+use crate::alternative::Alternative;
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::filterable::{Filterable, Witherable};
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::monad_plus::MonadPlus;
+use crate::monad_rec::MonadRec;
+use crate::selective::Selective;
+use crate::these::These;
+use crate::traversable::Traversable;
+
+impl<T> K1 for Option<T> {
+    type Inner = T;
+
+    type With<I> = Option<I>;
+}
+
+impl<A> Functor for Option<A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Option<B> {
+        self.map(f)
+    }
+}
+
+impl<A> FunctorWith for Option<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Option<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Option<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorOnce for Option<A> {}
+
+impl<A> FunctorRef for Option<A> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> Option<B> {
+        self.as_ref().map(f)
+    }
+}
+
+impl<A> Applicative for Option<A> {
+    fn pure<X: 'static>(val: X) -> Option<X> {
+        Some(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Option<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Option<C> {
+        self.zip(b).map(|(a, b)| f(a, b))
+    }
+}
+
+impl<A> Monad for Option<A> {
+    fn bind<B: 'static>(self, f: impl FnMut(A) -> Option<B> + 'static) -> Option<B> {
+        self.and_then(f)
+    }
+}
+
+/// `None` carries no information, so the error type is `()`.
+impl<A> MonadError<()> for Option<A> {
+    fn throw_error<X: 'static>((): ()) -> Option<X> {
+        None
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(()) -> Option<A> + 'static) -> Option<A> {
+        self.or_else(|| handler(()))
+    }
+}
+
+impl<A> MonadRec for Option<A> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        mut f: impl FnMut(X) -> Option<Either<X, Y>> + 'static,
+    ) -> Option<Y> {
+        let mut x = init;
+        loop {
+            match f(x)? {
+                Either::Left(next) => x = next,
+                Either::Right(y) => return Some(y),
+            }
+        }
+    }
+}
+
+impl<A> Alternative for Option<A> {
+    fn empty<X: 'static>() -> Option<X> {
+        None
+    }
+
+    fn or(self, other: Option<A>) -> Option<A> {
+        Option::or(self, other)
+    }
+}
+
+impl<A> MonadPlus for Option<A> {}
+
+impl<A> Selective for Option<A> {
+    fn select<X: 'static, Y: 'static, F: FnOnce(X) -> Y + 'static>(
+        fxy: Option<Either<X, Y>>,
+        f: Option<F>,
+    ) -> Option<Y> {
+        match fxy? {
+            Either::Left(x) => f.map(|f| f(x)),
+            Either::Right(y) => Some(y),
+        }
+    }
+}
+
+impl<A> Semialign for Option<A> {
+    fn align_with<B, C>(self, other: Option<B>, f: impl FnMut(These<A, B>) -> C) -> Option<C> {
+        These::from_options(self, other).map(f)
+    }
+}
+
+impl<A> Align for Option<A> {
+    fn nil<X>() -> Option<X> {
+        None
+    }
+}
+
+impl<A> Foldable for Option<A> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, A) -> B) -> B {
+        match self {
+            Some(a) => f(init, a),
+            None => init,
+        }
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        match self {
+            Some(a) => f(a, init),
+            None => init,
+        }
+    }
+}
+
+impl<A> Traversable for Option<A> {
+    fn traverse<F, B: 'static>(self, mut f: impl FnMut(A) -> F) -> F::With<Option<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        match self {
+            Some(a) => f(a).fmap(Some),
+            None => F::pure(None),
+        }
+    }
+}
+
+impl<A> Filterable for Option<A> {
+    fn filter_map<B: 'static>(self, f: impl FnMut(A) -> Option<B> + 'static) -> Option<B> {
+        self.and_then(f)
+    }
+
+    fn partition_map<B: 'static, C: 'static>(
+        self,
+        mut f: impl FnMut(A) -> Either<B, C> + 'static,
+    ) -> (Option<B>, Option<C>) {
+        match self.map(&mut f) {
+            Some(Either::Left(b)) => (Some(b), None),
+            Some(Either::Right(c)) => (None, Some(c)),
+            None => (None, None),
+        }
+    }
+}
+
+impl<A> Witherable for Option<A> {
+    fn wither<F, B: 'static>(self, mut f: impl FnMut(A) -> F) -> F::With<Option<B>>
+    where
+        F: Applicative<Inner = Option<B>>,
+    {
+        match self {
+            Some(a) => f(a).fmap(|b| b),
+            None => F::pure(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fmap_matches_map() {
+        let double = |x: i32| x * 2;
+        for opt in [Some(3), None] {
+            assert_eq!(opt.fmap(double), opt.map(double));
+        }
+    }
+
+    #[test]
+    fn zip_with_matches_zip() {
+        // `Option::zip_with` is an unstable inherent method, so go through the trait.
+        for (a, b) in [(Some(1), Some("a")), (Some(1), None), (None, Some("a"))] {
+            assert_eq!(Applicative::zip_with(a, b, |n, s| (n, s)), a.zip(b));
+        }
+        assert_eq!(Option::<()>::pure(5), Some(5));
+    }
+
+    #[test]
+    fn bind_matches_and_then() {
+        let half = |x: i32| if x % 2 == 0 { Some(x / 2) } else { None };
+        for opt in [Some(4), Some(3), None] {
+            assert_eq!(opt.bind(half), opt.and_then(half));
+        }
+    }
+
+    #[test]
+    fn flatten_matches_std() {
+        for opt in [Some(Some(1)), Some(None), None] {
+            assert_eq!(Monad::flatten(opt), opt.flatten());
+        }
+    }
+
+    #[test]
+    fn throw_and_catch() {
+        assert_eq!(Option::<()>::throw_error::<i32>(()), None);
+        assert_eq!(None.catch_error(|()| Some(1)), Some(1));
+        assert_eq!(Some(2).catch_error(|()| Some(1)), Some(2));
+        assert_eq!(Option::<()>::lift_result(Ok::<_, ()>(3)), Some(3));
+    }
+
+    #[test]
+    fn alternative_picks_first_some() {
+        assert_eq!(Alternative::or(None, Some(2)), Some(2));
+        assert_eq!(Alternative::or(Some(1), Some(2)), Some(1));
+        assert_eq!(Some(1).optional(), Some(Some(1)));
+        assert_eq!(None::<i32>.optional(), Some(None));
+        let evens: Vec<_> = (0..5)
+            .filter_map(|x| Option::<()>::guard(x % 2 == 0).fmap(move |()| x))
+            .collect();
+        assert_eq!(evens, [0, 2, 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn folds_at_most_one() {
+        assert_eq!(Some(2).fold_left(1, |acc, x| acc + x), 3);
+        assert_eq!(None.fold_right(1, |x: i32, acc| acc + x), 1);
+        assert_eq!(Some(3).fold_map(|x| x.to_string()), "3");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn filters_and_withers() {
+        assert_eq!(Some(3).filter_map(|x| (x > 2).then_some(x * 2)), Some(6));
+        assert_eq!(
+            Some(1).partition_map(Either::<i32, ()>::Left),
+            (Some(1), None)
+        );
+        assert_eq!(Some(4).wither(|x| vec![Some(x), None]), [Some(4), None]);
+        assert_eq!(None::<i32>.wither(|x| vec![Some(x)]), [None]);
+    }
+
+    #[test]
+    fn aligns_either_side() {
+        assert_eq!(Some(1).align(None::<&str>), Some(These::This(1)));
+        assert_eq!(Some(1).zip_padded(Some("a")), Some((Some(1), Some("a"))));
+        assert_eq!(
+            Option::<()>::nil::<i32>().align(Some(2)),
+            Some(These::That(2))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sequence_option_of_vec() {
+        assert_eq!(Some(vec![1, 2]).sequence(), [Some(1), Some(2)]);
+        assert_eq!(None::<Vec<i32>>.sequence(), [None]);
+    }
+}
@@ -0,0 +1,101 @@
+use core::marker::PhantomData;
+
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::traversable::Traversable;
+
+// `PhantomData<T>` is the terminal functor, with exactly one value at every type and never a
+// `T` to pass to any of the functions. Every law holds trivially, which makes it the simplest
+// instance to check generic code's `With` bounds against.
+
+impl<T> K1 for PhantomData<T> {
+    type Inner = T;
+
+    type With<I> = PhantomData<I>;
+}
+
+impl<A> Functor for PhantomData<A> {
+    fn fmap<B: 'static>(self, _: impl FnMut(A) -> B + 'static) -> PhantomData<B> {
+        PhantomData
+    }
+}
+
+impl<A> FunctorWith for PhantomData<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        _: PhantomData<X>,
+        _: impl FnMut(X) -> Y + 'static,
+    ) -> PhantomData<Y> {
+        PhantomData
+    }
+}
+
+impl<A> FunctorRef for PhantomData<A> {
+    fn fmap_ref<B>(&self, _: impl FnMut(&A) -> B) -> PhantomData<B> {
+        PhantomData
+    }
+}
+
+impl<A> Applicative for PhantomData<A> {
+    fn pure<X: 'static>(_: X) -> PhantomData<X> {
+        PhantomData
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        _: PhantomData<B>,
+        _: impl FnMut(A, B) -> C + 'static,
+    ) -> PhantomData<C> {
+        PhantomData
+    }
+}
+
+impl<A> Monad for PhantomData<A> {
+    fn bind<B: 'static>(self, _: impl FnMut(A) -> PhantomData<B> + 'static) -> PhantomData<B> {
+        PhantomData
+    }
+}
+
+impl<A> Foldable for PhantomData<A> {
+    fn fold_left<B>(self, init: B, _: impl FnMut(B, A) -> B) -> B {
+        init
+    }
+
+    fn fold_right<B>(self, init: B, _: impl FnMut(A, B) -> B) -> B {
+        init
+    }
+}
+
+impl<A> Traversable for PhantomData<A> {
+    fn traverse<F, B: 'static>(self, _: impl FnMut(A) -> F) -> F::With<PhantomData<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        F::pure(PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Written against the traits alone, so the only way to get a `PhantomData<String>` back is
+    // through the `With` projections.
+    fn describe<M>(m: M) -> M::With<String>
+    where
+        M: Monad<Inner = u32, With<u32> = M>,
+    {
+        m.bind::<u32>(|n| M::pure(n + 1))
+            .zip_with::<&str, String>(M::pure("!"), |n, s| format!("{n}{s}"))
+    }
+
+    #[test]
+    fn every_operation_is_trivial() {
+        let _: PhantomData<String> = describe(PhantomData::<u32>);
+        assert_eq!(describe(Some(1)), Some(String::from("2!")));
+        assert_eq!(PhantomData::<u32>.fold_left(7, |acc, n| acc + n), 7);
+        assert_eq!(PhantomData::<u32>.traverse(Some), Some(PhantomData::<u32>));
+    }
+}
@@ -0,0 +1,53 @@
+use core::task::Poll;
+
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::try_functor::TryFunctor;
+
+impl<T> K1 for Poll<T> {
+    type Inner = T;
+
+    type With<I> = Poll<I>;
+}
+
+impl<A> Functor for Poll<A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Poll<B> {
+        self.map(f)
+    }
+}
+
+impl<A> FunctorWith for Poll<A> {
+    fn fmap_with<X: 'static, Y: 'static>(fx: Poll<X>, f: impl FnMut(X) -> Y + 'static) -> Poll<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorRef for Poll<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Poll<B> {
+        match self {
+            Poll::Ready(a) => Poll::Ready(f(a)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<A> TryFunctor for Poll<A> {
+    fn try_fmap<B: 'static, E>(self, mut f: impl FnMut(A) -> Result<B, E>) -> Result<Poll<B>, E> {
+        Ok(match self {
+            Poll::Ready(a) => Poll::Ready(f(a)?),
+            Poll::Pending => Poll::Pending,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_ready_values() {
+        assert_eq!(Poll::Ready(2).fmap(|n| n * 3), Poll::Ready(6));
+        assert_eq!(Poll::<i32>::Pending.fmap(|n| n * 3), Poll::Pending);
+        assert_eq!(Poll::Ready("ab").fmap_ref(|s| s.len()), Poll::Ready(2));
+    }
+}
@@ -0,0 +1,84 @@
+// Shared pointers only get `Functor` and `FunctorRef`: an `Applicative` would need to take the
+// other argument's pointee by value too, and its type isn't known to be `Clone`.
+
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+
+use crate::functor::{Functor, FunctorRef};
+use crate::kind::K1;
+
+impl<T> K1 for Rc<T> {
+    type Inner = T;
+
+    type With<I> = Rc<I>;
+}
+
+/// Moves the value out if this is the only `Rc` pointing at it, and clones it otherwise (see
+/// [`Rc::unwrap_or_clone`]).
+impl<A: Clone> Functor for Rc<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Rc<B> {
+        Rc::new(f(Rc::unwrap_or_clone(self)))
+    }
+}
+
+/// Maps without cloning the pointee, unlike [`Functor`].
+impl<A> FunctorRef for Rc<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Rc<B> {
+        Rc::new(f(self))
+    }
+}
+
+impl<T> K1 for Arc<T> {
+    type Inner = T;
+
+    type With<I> = Arc<I>;
+}
+
+/// Moves the value out if this is the only `Arc` pointing at it, and clones it otherwise (see
+/// [`Arc::unwrap_or_clone`]).
+impl<A: Clone> Functor for Arc<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Arc<B> {
+        Arc::new(f(Arc::unwrap_or_clone(self)))
+    }
+}
+
+/// Maps without cloning the pointee, unlike [`Functor`].
+impl<A> FunctorRef for Arc<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Arc<B> {
+        Arc::new(f(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_rc_moves() {
+        // `String` would be cloned if the pointer were shared; a unique one hands it over.
+        let s = Rc::new(String::from("hi"));
+        let ptr = s.as_ptr();
+        let moved = s.fmap(|s| s.as_ptr());
+        assert_eq!(*moved, ptr);
+    }
+
+    #[test]
+    fn fmap_ref_leaves_the_original() {
+        // Not `Clone`, so `fmap` isn't available at all.
+        struct Opaque(u32);
+        let rc = Rc::new(Opaque(3));
+        assert_eq!(*rc.fmap_ref(|o| o.0 + 1), 4);
+        assert_eq!(rc.0, 3);
+    }
+
+    #[test]
+    fn shared_arc_clones() {
+        let a = Arc::new(vec![1, 2]);
+        let mapped = Arc::clone(&a).fmap(|mut v| {
+            v.push(3);
+            v
+        });
+        assert_eq!(*a, [1, 2]);
+        assert_eq!(*mapped, [1, 2, 3]);
+    }
+}
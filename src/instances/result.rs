@@ -0,0 +1,29 @@
+use crate::bifunctor::Bifunctor;
+use crate::kind::K2;
+
+impl<T, E> K2 for Result<T, E> {
+    type Inner1 = T;
+    type Inner2 = E;
+
+    type With<A, B> = Result<A, B>;
+}
+
+impl<T, E> Bifunctor for Result<T, E> {
+    fn bimap<C, D>(self, f: impl FnMut(T) -> C, g: impl FnMut(E) -> D) -> Result<C, D> {
+        self.map(f).map_err(g)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_is_ok_second_is_err() {
+        let ok: Result<i32, i32> = Ok(1);
+        let err: Result<i32, i32> = Err(1);
+        assert_eq!(ok.map_first(|x| x + 1), Ok(2));
+        assert_eq!(ok.map_second(|x| x + 1), Ok(1));
+        assert_eq!(err.bimap(|x| x + 1, |x| x * 10), Err(10));
+    }
+}
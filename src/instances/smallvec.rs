@@ -0,0 +1,149 @@
+use alloc::vec::Vec;
+use core::alloc::Layout;
+
+use smallvec::{smallvec, SmallVec};
+
+use super::vec::map_in_place;
+use crate::alternative::Alternative;
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_plus::MonadPlus;
+use crate::traversable::Traversable;
+
+impl<T, const N: usize> K1 for SmallVec<[T; N]> {
+    type Inner = T;
+
+    type With<I> = SmallVec<[I; N]>;
+}
+
+/// The result has the same inline capacity, so elements that were stored inline stay inline.
+/// Once spilled, the heap buffer is reused when `A` and `B` have the same size and alignment,
+/// the same as for `Vec`.
+impl<A, const N: usize> Functor for SmallVec<[A; N]> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> SmallVec<[B; N]> {
+        if self.spilled() && Layout::new::<A>() == Layout::new::<B>() {
+            SmallVec::from_vec(map_in_place(self.into_vec(), f))
+        } else {
+            self.into_iter().map(f).collect()
+        }
+    }
+}
+
+impl<A, const N: usize> FunctorWith for SmallVec<[A; N]> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: SmallVec<[X; N]>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> SmallVec<[Y; N]> {
+        fx.fmap(f)
+    }
+}
+
+impl<A, const N: usize> FunctorRef for SmallVec<[A; N]> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> SmallVec<[B; N]> {
+        self.iter().map(f).collect()
+    }
+}
+
+// Zips, as for `Vec`. The result only spills to the heap if both inputs had.
+impl<A, const N: usize> Applicative for SmallVec<[A; N]> {
+    fn pure<X: 'static>(val: X) -> SmallVec<[X; N]> {
+        smallvec![val]
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: SmallVec<[B; N]>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> SmallVec<[C; N]> {
+        self.into_iter().zip(b).map(|(a, b)| f(a, b)).collect()
+    }
+}
+
+impl<A, const N: usize> Monad for SmallVec<[A; N]> {
+    fn bind<B: 'static>(self, f: impl FnMut(A) -> SmallVec<[B; N]> + 'static) -> SmallVec<[B; N]> {
+        self.into_iter().flat_map(f).collect()
+    }
+}
+
+impl<A, const N: usize> Alternative for SmallVec<[A; N]> {
+    fn empty<X: 'static>() -> SmallVec<[X; N]> {
+        SmallVec::new()
+    }
+
+    fn or(mut self, other: SmallVec<[A; N]>) -> SmallVec<[A; N]> {
+        self.extend(other);
+        self
+    }
+}
+
+impl<A, const N: usize> MonadPlus for SmallVec<[A; N]> {}
+
+impl<A, const N: usize> Foldable for SmallVec<[A; N]> {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, A) -> B) -> B {
+        self.into_iter().fold(init, f)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        self.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl<A, const N: usize> Traversable for SmallVec<[A; N]> {
+    fn traverse<F, B: 'static>(self, f: impl FnMut(A) -> F) -> F::With<SmallVec<[B; N]>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        // Nested from the right and reversed at the end, as for `Vec`.
+        let effects: Vec<F> = self.into_iter().map(f).collect();
+        let mut acc = F::pure(SmallVec::with_capacity(effects.len()));
+        for (i, fb) in effects.into_iter().enumerate().rev() {
+            acc = fb.zip_with(acc, move |b, mut bs: SmallVec<[B; N]>| {
+                bs.push(b);
+                if i == 0 {
+                    bs.reverse();
+                }
+                bs
+            });
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fmap_keeps_the_inline_buffer() {
+        let inline: SmallVec<[u32; 4]> = smallvec![1, 2, 3];
+        let mapped = inline.fmap(|n| u64::from(n) * 2);
+        assert!(!mapped.spilled());
+        assert_eq!(mapped[..], [2, 4, 6]);
+
+        let spilled: SmallVec<[u32; 2]> = (0..100).collect();
+        let ptr = spilled.as_ptr() as usize;
+        let signed = spilled.fmap(|n| -(n as i32));
+        assert_eq!(signed.as_ptr() as usize, ptr);
+        assert_eq!(signed[..3], [0, -1, -2]);
+    }
+
+    #[test]
+    fn traverses_and_alternates() {
+        let v: SmallVec<[u32; 4]> = smallvec![1, 2, 3];
+        let odd = v.clone().traverse(|n| (n % 2 == 1).then_some(n));
+        assert_eq!(odd, None);
+        let all = v.clone().traverse(|n| Some(n + 1));
+        assert_eq!(all.as_deref(), Some(&[2, 3, 4][..]));
+        assert_eq!(v.clone().or(smallvec![4]).fold_left(0, |a, b| a + b), 10);
+        assert_eq!(
+            v.fold_right(Vec::new(), |n, mut acc| {
+                acc.push(n);
+                acc
+            }),
+            [3, 2, 1]
+        );
+    }
+}
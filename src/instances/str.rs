@@ -0,0 +1,15 @@
+use crate::mono::{MonoFoldable, MonoKind};
+
+impl MonoKind for &str {
+    type Element = char;
+}
+
+impl MonoFoldable for &str {
+    fn ofold_left<B>(self, init: B, f: impl FnMut(B, char) -> B) -> B {
+        self.chars().fold(init, f)
+    }
+
+    fn ofold_right<B>(self, init: B, mut f: impl FnMut(char, B) -> B) -> B {
+        self.chars().rev().fold(init, |acc, c| f(c, acc))
+    }
+}
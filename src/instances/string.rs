@@ -0,0 +1,23 @@
+use alloc::string::String;
+
+use crate::mono::{MonoFoldable, MonoFunctor, MonoKind};
+
+impl MonoKind for String {
+    type Element = char;
+}
+
+impl MonoFunctor for String {
+    fn omap(self, f: impl FnMut(char) -> char + 'static) -> String {
+        self.chars().map(f).collect()
+    }
+}
+
+impl MonoFoldable for String {
+    fn ofold_left<B>(self, init: B, f: impl FnMut(B, char) -> B) -> B {
+        self.chars().fold(init, f)
+    }
+
+    fn ofold_right<B>(self, init: B, mut f: impl FnMut(char, B) -> B) -> B {
+        self.chars().rev().fold(init, |acc, c| f(c, acc))
+    }
+}
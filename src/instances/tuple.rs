@@ -0,0 +1,149 @@
+use crate::applicative::Applicative;
+use crate::bifunctor::Bifunctor;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::{K1, K2};
+use crate::monad::Monad;
+use crate::monoid::Monoid;
+use crate::traversable::Traversable;
+
+// As a `K1`, a pair is a value annotated with its first element, which the instances carry
+// along untouched, like `Writer`. When the annotation is a `Monoid`, it also accumulates through
+// `zip_with` and `bind`.
+
+impl<A, B> K2 for (A, B) {
+    type Inner1 = A;
+    type Inner2 = B;
+
+    type With<C, D> = (C, D);
+}
+
+impl<A, B> Bifunctor for (A, B) {
+    fn bimap<C, D>(self, mut f: impl FnMut(A) -> C, mut g: impl FnMut(B) -> D) -> (C, D) {
+        (f(self.0), g(self.1))
+    }
+}
+
+impl<A, B> K1 for (A, B) {
+    type Inner = B;
+
+    type With<I> = (A, I);
+}
+
+impl<A, B> Functor for (A, B) {
+    fn fmap<C: 'static>(self, mut f: impl FnMut(B) -> C + 'static) -> (A, C) {
+        (self.0, f(self.1))
+    }
+}
+
+impl<A, B> FunctorWith for (A, B) {
+    fn fmap_with<X: 'static, Y: 'static>(fx: (A, X), f: impl FnMut(X) -> Y + 'static) -> (A, Y) {
+        fx.fmap(f)
+    }
+}
+
+impl<A, B> FunctorOnce for (A, B) {}
+
+impl<A: Clone, B> FunctorRef for (A, B) {
+    fn fmap_ref<C>(&self, mut f: impl FnMut(&B) -> C) -> (A, C) {
+        (self.0.clone(), f(&self.1))
+    }
+}
+
+impl<A: Monoid, B> Applicative for (A, B) {
+    fn pure<X: 'static>(val: X) -> (A, X) {
+        (A::empty(), val)
+    }
+
+    fn zip_with<C: 'static, D: 'static>(
+        self,
+        c: (A, C),
+        mut f: impl FnMut(B, C) -> D + 'static,
+    ) -> (A, D) {
+        (self.0.combine(c.0), f(self.1, c.1))
+    }
+}
+
+impl<A: Monoid, B> Monad for (A, B) {
+    fn bind<C: 'static>(self, mut f: impl FnMut(B) -> (A, C) + 'static) -> (A, C) {
+        let (a, c) = f(self.1);
+        (self.0.combine(a), c)
+    }
+}
+
+impl<A, B> Foldable for (A, B) {
+    fn fold_left<C>(self, init: C, mut f: impl FnMut(C, B) -> C) -> C {
+        f(init, self.1)
+    }
+
+    fn fold_right<C>(self, init: C, mut f: impl FnMut(B, C) -> C) -> C {
+        f(self.1, init)
+    }
+}
+
+// The first element is cloned into the result of each of the effect's values, since an
+// applicative like `Vec`'s can have more than one.
+impl<A: Clone + 'static, B> Traversable for (A, B) {
+    fn traverse<F, C: 'static>(self, mut f: impl FnMut(B) -> F) -> F::With<(A, C)>
+    where
+        F: Applicative<Inner = C>,
+    {
+        let a = self.0;
+        f(self.1).fmap(move |c| (a.clone(), c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap_params<T: K2>(_: &T) -> T::With<T::Inner2, T::Inner1>
+    where
+        T::With<T::Inner2, T::Inner1>: Default,
+    {
+        Default::default()
+    }
+
+    #[test]
+    fn with_reapplies_both_params() {
+        let swapped: (String, u8) = swap_params(&(1u8, String::new()));
+        assert_eq!(swapped, (String::new(), 0));
+    }
+
+    #[test]
+    fn bimap_maps_both() {
+        assert_eq!((1, "ab").bimap(|x| x * 2, str::len), (2, 2));
+        assert_eq!((1, "ab").map_second(str::len), (1, 2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn functor_over_the_second() {
+        assert_eq!(("label", 2).fmap(|n| n * 10), ("label", 20));
+        assert_eq!(("label", 2).fmap_ref(|n| n + 1), ("label", 3));
+        assert_eq!(("label", 2).fold_left(1, |acc, n| acc + n), 3);
+        assert_eq!(
+            ("label", "7").traverse(|s| s.parse::<u8>().ok()),
+            Some(("label", 7))
+        );
+        assert_eq!(
+            ("label", vec![1, 2]).sequence(),
+            vec![("label", 1), ("label", 2)]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn monoid_annotations_accumulate() {
+        let step = |n: u32| (vec![format!("saw {n}")], n + 1);
+        assert_eq!(
+            (vec![String::from("start")], 1).bind(step).bind(step),
+            (vec!["start".into(), "saw 1".into(), "saw 2".into()], 3)
+        );
+        assert_eq!(<(String, ())>::pure(5), (String::new(), 5));
+        assert_eq!(
+            (String::from("a"), 1).zip_with((String::from("b"), 2), |x, y| x + y),
+            (String::from("ab"), 3)
+        );
+    }
+}
@@ -0,0 +1,454 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+
+use crate::align::{Align, LengthMismatch, Semialign, Zip};
+use crate::alternative::Alternative;
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::filterable::{Filterable, Witherable};
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::indexed::{FoldableWithIndex, FunctorWithIndex, Indexed, TraversableWithIndex};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_plus::MonadPlus;
+use crate::these::These;
+use crate::traversable::Traversable;
+
+impl<T> K1 for Vec<T> {
+    type Inner = T;
+
+    type With<I> = Vec<I>;
+}
+
+/// When `A` and `B` have the same size and alignment, the `B`s are written over the `A`s in the
+/// existing buffer instead of collecting into a new one. Otherwise, this falls back to
+/// collecting.
+impl<A> Functor for Vec<A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Vec<B> {
+        if Layout::new::<A>() == Layout::new::<B>() {
+            map_in_place(self, f)
+        } else {
+            self.into_iter().map(f).collect()
+        }
+    }
+}
+
+pub(super) fn map_in_place<A, B>(vec: Vec<A>, mut f: impl FnMut(A) -> B) -> Vec<B> {
+    debug_assert_eq!(Layout::new::<A>(), Layout::new::<B>());
+    let mut vec = ManuallyDrop::new(vec);
+    let mut guard = InPlace::<A, B> {
+        ptr: vec.as_mut_ptr(),
+        len: vec.len(),
+        cap: vec.capacity(),
+        mapped: 0,
+        types: PhantomData,
+    };
+    while guard.mapped < guard.len {
+        // SAFETY: the slots from `mapped` on still hold the original `A`s, and each is read once.
+        let a = unsafe { guard.ptr.add(guard.mapped).read() };
+        let b = f(a);
+        // SAFETY: the slot was just moved out of, and a `B` fits wherever an `A` did.
+        unsafe { guard.ptr.cast::<B>().add(guard.mapped).write(b) };
+        guard.mapped += 1;
+    }
+    let (ptr, len, cap) = (guard.ptr.cast::<B>(), guard.len, guard.cap);
+    mem::forget(guard);
+    // SAFETY: every slot now holds a `B`, and since the layouts match, the buffer is the same
+    // allocation a `Vec<B>` with this capacity would have made.
+    unsafe { Vec::from_raw_parts(ptr, len, cap) }
+}
+
+// A buffer partway through `map_in_place`: the first `mapped` slots hold `B`s, the next one has
+// been moved out of, and the rest still hold `A`s. Dropping it, if `f` panics, drops whatever
+// each slot holds and frees the buffer.
+struct InPlace<A, B> {
+    ptr: *mut A,
+    len: usize,
+    cap: usize,
+    mapped: usize,
+    types: PhantomData<(A, B)>,
+}
+
+impl<A, B> Drop for InPlace<A, B> {
+    fn drop(&mut self) {
+        let rest = self.mapped + 1;
+        // SAFETY: see the layout of the buffer above. The `Vec` has no elements, so dropping it
+        // just frees the buffer.
+        unsafe {
+            let done = ptr::slice_from_raw_parts_mut(self.ptr.cast::<B>(), self.mapped);
+            ptr::drop_in_place(done);
+            let left = ptr::slice_from_raw_parts_mut(self.ptr.add(rest), self.len - rest);
+            ptr::drop_in_place(left);
+            drop(Vec::from_raw_parts(self.ptr, 0, self.cap));
+        }
+    }
+}
+
+impl<A> FunctorWith for Vec<A> {
+    fn fmap_with<X: 'static, Y: 'static>(fx: Vec<X>, f: impl FnMut(X) -> Y + 'static) -> Vec<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorRef for Vec<A> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> Vec<B> {
+        self.iter().map(f).collect()
+    }
+}
+
+// Zips like `Iterator::zip` rather than taking the cartesian product. `ZipVec`'s docs say why,
+// and what it costs the laws.
+impl<A> Applicative for Vec<A> {
+    fn pure<X: 'static>(val: X) -> Vec<X> {
+        vec![val]
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Vec<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Vec<C> {
+        self.into_iter().zip(b).map(|(a, b)| f(a, b)).collect()
+    }
+}
+
+impl<A> Monad for Vec<A> {
+    fn bind<B: 'static>(self, f: impl FnMut(A) -> Vec<B> + 'static) -> Vec<B> {
+        self.into_iter().flat_map(f).collect()
+    }
+}
+
+impl<A> Alternative for Vec<A> {
+    fn empty<X: 'static>() -> Vec<X> {
+        Vec::new()
+    }
+
+    fn or(mut self, mut other: Vec<A>) -> Vec<A> {
+        self.append(&mut other);
+        self
+    }
+}
+
+impl<A> MonadPlus for Vec<A> {}
+
+impl<A> Semialign for Vec<A> {
+    fn align_with<B, C>(self, other: Vec<B>, mut f: impl FnMut(These<A, B>) -> C) -> Vec<C> {
+        let (mut a, mut b) = (self.into_iter(), other.into_iter());
+        let mut out = Vec::with_capacity(a.len().max(b.len()));
+        while let Some(these) = These::from_options(a.next(), b.next()) {
+            out.push(f(these));
+        }
+        out
+    }
+}
+
+impl<A> Align for Vec<A> {
+    fn nil<X>() -> Vec<X> {
+        Vec::new()
+    }
+}
+
+impl<A> Zip for Vec<A> {
+    fn zip_with_truncated<B, C>(self, other: Vec<B>, mut f: impl FnMut(A, B) -> C) -> Vec<C> {
+        self.into_iter().zip(other).map(|(a, b)| f(a, b)).collect()
+    }
+
+    fn try_zip_exact_with<B, C>(
+        self,
+        other: Vec<B>,
+        f: impl FnMut(A, B) -> C,
+    ) -> Result<Vec<C>, LengthMismatch> {
+        if self.len() != other.len() {
+            return Err(LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+        Ok(self.zip_with_truncated(other, f))
+    }
+}
+
+impl<A> Foldable for Vec<A> {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, A) -> B) -> B {
+        self.into_iter().fold(init, f)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        self.into_iter().rev().fold(init, |acc, a| f(a, acc))
+    }
+}
+
+impl<A> Traversable for Vec<A> {
+    fn traverse<F, B: 'static>(self, f: impl FnMut(A) -> F) -> F::With<Vec<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        // All we can do with an `F::With<Vec<B>>` is pass it as the second argument of
+        // `F::zip_with`, so the effects are nested from the right: `f(a0) <*> (f(a1) <*> ...)`.
+        // That keeps them in order, but builds the vector back to front, so the outermost zip
+        // reverses it.
+        let effects: Vec<F> = self.into_iter().map(f).collect();
+        let mut acc = F::pure(Vec::with_capacity(effects.len()));
+        for (i, fb) in effects.into_iter().enumerate().rev() {
+            acc = fb.zip_with(acc, move |b, mut bs| {
+                bs.push(b);
+                if i == 0 {
+                    bs.reverse();
+                }
+                bs
+            });
+        }
+        acc
+    }
+}
+
+impl<A> Filterable for Vec<A> {
+    fn filter_map<B: 'static>(self, f: impl FnMut(A) -> Option<B> + 'static) -> Vec<B> {
+        self.into_iter().filter_map(f).collect()
+    }
+
+    fn partition_map<B: 'static, C: 'static>(
+        self,
+        mut f: impl FnMut(A) -> Either<B, C> + 'static,
+    ) -> (Vec<B>, Vec<C>) {
+        let (mut lefts, mut rights) = (Vec::new(), Vec::new());
+        for a in self {
+            match f(a) {
+                Either::Left(b) => lefts.push(b),
+                Either::Right(c) => rights.push(c),
+            }
+        }
+        (lefts, rights)
+    }
+}
+
+impl<A> Witherable for Vec<A> {
+    fn wither<F, B: 'static>(self, f: impl FnMut(A) -> F) -> F::With<Vec<B>>
+    where
+        F: Applicative<Inner = Option<B>>,
+    {
+        // Nested from the right like `traverse`, so the kept elements are pushed back to front.
+        let effects: Vec<F> = self.into_iter().map(f).collect();
+        let mut acc = F::pure(Vec::new());
+        for (i, fb) in effects.into_iter().enumerate().rev() {
+            acc = fb.zip_with(acc, move |b, mut bs: Vec<B>| {
+                bs.extend(b);
+                if i == 0 {
+                    bs.reverse();
+                }
+                bs
+            });
+        }
+        acc
+    }
+}
+
+impl<A> Indexed for Vec<A> {
+    type Index = usize;
+}
+
+impl<A> FunctorWithIndex for Vec<A> {
+    fn imap<B: 'static>(self, mut f: impl FnMut(usize, A) -> B + 'static) -> Vec<B> {
+        self.into_iter().enumerate().map(|(i, a)| f(i, a)).collect()
+    }
+}
+
+impl<A> FoldableWithIndex for Vec<A> {
+    fn ifold_left<B>(self, init: B, mut f: impl FnMut(B, usize, A) -> B) -> B {
+        self.into_iter()
+            .enumerate()
+            .fold(init, |acc, (i, a)| f(acc, i, a))
+    }
+}
+
+impl<A> TraversableWithIndex for Vec<A> {
+    fn itraverse<F, B: 'static>(self, mut f: impl FnMut(usize, A) -> F) -> F::With<Vec<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        let indexed: Vec<(usize, A)> = self.into_iter().enumerate().collect();
+        indexed.traverse(|(i, a)| f(i, a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn fmap_calls_once_per_element() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+        let v = vec![1, 2, 3].fmap(move |x| {
+            counter.set(counter.get() + 1);
+            x.to_string()
+        });
+        assert_eq!(v, ["1", "2", "3"]);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn fmap_reuses_the_buffer_when_layouts_match() {
+        let v: Vec<u32> = (0..100).collect();
+        let ptr = v.as_ptr() as usize;
+        let signed = v.fmap(|n| -(n as i32));
+        assert_eq!(signed.as_ptr() as usize, ptr);
+        assert_eq!(signed[..3], [0, -1, -2]);
+
+        let wide = signed.fmap(i64::from);
+        assert_eq!(wide.len(), 100);
+        assert_eq!(vec![(); 3].fmap(|()| ()), [(), (), ()]);
+    }
+
+    #[test]
+    fn fmap_in_place_drops_everything_on_panic() {
+        let drops = Rc::new(Cell::new(0));
+        struct Counted(Rc<Cell<usize>>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+        let v: Vec<Counted> = (0..5).map(|_| Counted(Rc::clone(&drops))).collect();
+        let mut seen = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            v.fmap(move |c: Counted| {
+                seen += 1;
+                assert!(seen < 3, "boom");
+                c
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn zips_with_a_chosen_length() {
+        let (long, short) = (vec![1, 2, 3], vec![10, 20]);
+        assert_eq!(
+            long.clone().zip_with_truncated(short.clone(), |a, b| a + b),
+            [11, 22]
+        );
+        assert_eq!(
+            long.clone().zip_with_padded(short.clone(), |a, b| a + b),
+            [11, 22, 3]
+        );
+        assert_eq!(
+            short
+                .clone()
+                .zip_with_fill(long.clone(), || 0, || 100, |a, b| a * b),
+            [10, 40, 0]
+        );
+        assert_eq!(
+            long.clone().try_zip_exact(short),
+            Err(LengthMismatch { left: 3, right: 2 })
+        );
+        assert_eq!(
+            long.try_zip_exact(vec!["a", "b", "c"]),
+            Ok(vec![(1, "a"), (2, "b"), (3, "c")])
+        );
+    }
+
+    #[test]
+    fn zip_with_truncates() {
+        assert_eq!(vec![1, 2, 3].zip_with(vec![10, 20], |a, b| a + b), [11, 22]);
+    }
+
+    #[test]
+    fn bind_flat_maps() {
+        assert_eq!(vec![1, 2].bind(|x| vec![x; x]), [1, 2, 2]);
+        assert_eq!(vec![vec![1], vec![], vec![2, 3]].flatten(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn alternative_concatenates() {
+        assert_eq!(vec![1].or(vec![2, 3]), [1, 2, 3]);
+        assert_eq!(vec![1, 2].optional(), [Some(1), Some(2), None]);
+        let pairs = vec![1, 2, 3].bind(|x| Vec::<()>::guard(x != 2).fmap(move |()| x));
+        assert_eq!(pairs, [1, 3]);
+    }
+
+    #[test]
+    fn align_pads_the_shorter_side() {
+        let aligned = vec![1, 2, 3].align(vec!["a"]);
+        assert_eq!(
+            aligned,
+            [These::Both(1, "a"), These::This(2), These::This(3)]
+        );
+        let padded = vec![1].zip_padded(vec!["a", "b"]);
+        assert_eq!(padded, [(Some(1), Some("a")), (None, Some("b"))]);
+        assert_eq!(vec![1].align(Vec::<()>::nil::<()>()), [These::This(1)]);
+    }
+
+    #[test]
+    fn fold_directions() {
+        let v = vec!["a", "b", "c"];
+        assert_eq!(v.clone().fold_left(String::new(), |acc, s| acc + s), "abc");
+        assert_eq!(v.clone().fold_right(String::new(), |s, acc| acc + s), "cba");
+        assert_eq!(v.fold_map(|s| vec![s, s]), ["a", "a", "b", "b", "c", "c"]);
+    }
+
+    #[test]
+    fn filters_and_partitions() {
+        let v = vec![1, 2, 3, 4];
+        assert_eq!(
+            v.clone().filter_map(|n| (n % 2 == 0).then_some(n * 10)),
+            [20, 40]
+        );
+        assert_eq!(v.clone().filter(|n| *n > 2), [3, 4]);
+        let (small, big) = v.partition_map(|n| {
+            if n < 3 {
+                Either::Left(n)
+            } else {
+                Either::Right(n.to_string())
+            }
+        });
+        assert_eq!(
+            (small, big),
+            (vec![1, 2], vec!["3".to_owned(), "4".to_owned()])
+        );
+    }
+
+    #[test]
+    fn wither_runs_effects_in_order() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&calls);
+        let kept = vec![1, 2, 3, 4].wither(move |n| {
+            log.borrow_mut().push(n);
+            (n != 4).then_some((n % 2 == 1).then_some(n))
+        });
+        assert_eq!(kept, None);
+        assert_eq!(*calls.borrow(), [1, 2, 3, 4]);
+        assert_eq!(
+            vec![1, 2, 3].wither(|n| Some((n != 2).then_some(n))),
+            Some(vec![1, 3])
+        );
+    }
+
+    #[test]
+    fn indexed_instances_pass_positions() {
+        let v = vec!["a", "b", "c"];
+        assert_eq!(v.clone().imap(|i, s| format!("{i}{s}")), ["0a", "1b", "2c"]);
+        assert_eq!(v.clone().ifold_left(0, |acc, i, _| acc + i), 3);
+        assert_eq!(v.clone().ifold_map(|i, s| vec![s; i]), ["b", "c", "c"]);
+        let first_only = |i: usize, s: &'static str| (i == 0).then_some(s);
+        assert_eq!(vec!["x", "y"].itraverse(first_only), None);
+        assert_eq!(vec!["x"].itraverse(first_only), Some(vec!["x"]));
+    }
+
+    #[test]
+    fn traverse_keeps_order() {
+        let halve = |x: i32| if x % 2 == 0 { Some(x / 2) } else { None };
+        assert_eq!(vec![2, 4, 6].traverse(halve), Some(vec![1, 2, 3]));
+        assert_eq!(vec![2, 3, 6].traverse(halve), None);
+        assert_eq!(Vec::<i32>::new().traverse(halve), Some(vec![]));
+    }
+}
@@ -0,0 +1,71 @@
+//! Type constructors that can be mapped given a way there and a way back.
+
+use crate::functor::Functor;
+use crate::kind::K1;
+
+/// A type constructor that can change its parameter from `A` to `B` given functions both ways,
+/// because it both produces and consumes `A`s.
+///
+/// Every [`Functor`] is invariant by ignoring `g`, and every
+/// [`Contravariant`](crate::contravariant::Contravariant) functor by ignoring `f`. The instances
+/// beyond those are the types that are neither, like [`Endo`](crate::monoid::Endo), which takes
+/// an `A` and gives one back, or a codec that both encodes and decodes.
+///
+/// The method isn't called `imap`, which [`FunctorWithIndex`](crate::indexed::FunctorWithIndex)
+/// already uses for mapping with indices.
+///
+/// Instances should satisfy:
+/// - `x.invmap(|a| a, |a| a) == x`
+/// - `x.invmap(f1, g1).invmap(f2, g2) == x.invmap(|a| f2(f1(a)), |c| g1(g2(c)))`
+pub trait Invariant: K1 {
+    fn invmap<B: 'static>(
+        self,
+        f: impl FnMut(Self::Inner) -> B + 'static,
+        g: impl FnMut(B) -> Self::Inner + 'static,
+    ) -> Self::With<B>;
+}
+
+impl<T: Functor> Invariant for T {
+    fn invmap<B: 'static>(
+        self,
+        f: impl FnMut(T::Inner) -> B + 'static,
+        _: impl FnMut(B) -> T::Inner + 'static,
+    ) -> T::With<B> {
+        self.fmap(f)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::contravariant::Predicate;
+    use crate::monoid::Endo;
+
+    #[derive(Debug, PartialEq)]
+    struct Celsius(f64);
+
+    fn to_celsius(f: f64) -> Celsius {
+        Celsius((f - 32.0) * 5.0 / 9.0)
+    }
+
+    fn to_fahrenheit(c: Celsius) -> f64 {
+        c.0 * 9.0 / 5.0 + 32.0
+    }
+
+    #[test]
+    fn covariant_and_contravariant_use_one_side() {
+        assert_eq!(
+            Some(212.0).invmap(to_celsius, to_fahrenheit),
+            Some(Celsius(100.0))
+        );
+        let mut freezing = Predicate::new(|f: f64| f <= 32.0).invmap(to_celsius, to_fahrenheit);
+        assert!(freezing.test(Celsius(-1.0)));
+        assert!(!freezing.test(Celsius(1.0)));
+    }
+
+    #[test]
+    fn endo_uses_both() {
+        let warm_up = Endo::new(|f: f64| f + 18.0).invmap(to_celsius, to_fahrenheit);
+        assert_eq!(warm_up.apply(Celsius(0.0)), Celsius(10.0));
+    }
+}
@@ -0,0 +1,242 @@
+//! Side effects as values.
+
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use core::any::Any;
+
+use crate::applicative::Applicative;
+#[cfg(feature = "futures")]
+use crate::async_monad::{AsyncFunctor, AsyncMonad};
+use crate::either::Either;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+#[cfg(feature = "futures")]
+use crate::future::FutureK;
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_bracket::MonadBracket;
+use crate::monad_rec::MonadRec;
+
+/// A side-effecting computation of an `A` that hasn't run yet.
+///
+/// Building an `Io`, or combining it with [`fmap`](Functor::fmap) and [`bind`](Monad::bind),
+/// doesn't perform any of its effects. They all run, in order, when
+/// [`unsafe_run`](Io::unsafe_run) is called at the edge of the program, so everything before that
+/// can pass effects around and compose them like any other value.
+///
+/// Like [`State`](crate::state::State), an `Io` is a boxed `FnOnce` and can only be run once.
+/// Running it a second time means building it again.
+pub struct Io<A>(Box<dyn FnOnce() -> A>);
+
+impl<A> Io<A> {
+    /// Wraps the side effects in `f`, which run when the `Io` does.
+    pub fn new(f: impl FnOnce() -> A + 'static) -> Self {
+        Io(Box::new(f))
+    }
+
+    /// Performs the effects and returns the result.
+    ///
+    /// Nothing about this is `unsafe` in Rust's sense. The name marks the point where the
+    /// effects stop being values, which should be as few places as possible, ideally one.
+    pub fn unsafe_run(self) -> A {
+        (self.0)()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: 'static> Io<A> {
+    /// Turns a panic while running `self` into an `Err` carrying its payload, the same as
+    /// [`std::panic::catch_unwind`].
+    ///
+    /// The effects that ran before the panic aren't undone.
+    pub fn attempt(self) -> Io<Result<A, Box<dyn Any + Send>>> {
+        Io::new(move || {
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || self.unsafe_run()))
+        })
+    }
+}
+
+impl<A> K1 for Io<A> {
+    type Inner = A;
+
+    type With<I> = Io<I>;
+}
+
+impl<A: 'static> Functor for Io<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Io<B> {
+        Io::new(move || f(self.unsafe_run()))
+    }
+}
+
+impl<A: 'static> FunctorWith for Io<A> {
+    fn fmap_with<X: 'static, Y: 'static>(fx: Io<X>, f: impl FnMut(X) -> Y + 'static) -> Io<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A: 'static> FunctorOnce for Io<A> {}
+
+/// `zip_with` runs `self`'s effects before `b`'s.
+impl<A: 'static> Applicative for Io<A> {
+    fn pure<X: 'static>(val: X) -> Io<X> {
+        Io::new(move || val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Io<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Io<C> {
+        Io::new(move || {
+            let a = self.unsafe_run();
+            f(a, b.unsafe_run())
+        })
+    }
+}
+
+impl<A: 'static> Monad for Io<A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Io<B> + 'static) -> Io<B> {
+        Io::new(move || f(self.unsafe_run()).unsafe_run())
+    }
+}
+
+impl<A: 'static> MonadRec for Io<A> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        mut f: impl FnMut(X) -> Io<Either<X, Y>> + 'static,
+    ) -> Io<Y> {
+        Io::new(move || {
+            let mut x = init;
+            loop {
+                match f(x).unsafe_run() {
+                    Either::Left(next) => x = next,
+                    Either::Right(y) => return y,
+                }
+            }
+        })
+    }
+}
+
+// Runs the release step when dropped, which happens during unwinding if using the resource panics.
+struct Release<R, F: FnOnce(R) -> Io<()>>(Option<(R, F)>);
+
+impl<R, F: FnOnce(R) -> Io<()>> Drop for Release<R, F> {
+    fn drop(&mut self) {
+        if let Some((resource, release)) = self.0.take() {
+            release(resource).unsafe_run();
+        }
+    }
+}
+
+/// A panic while using the resource releases it before the panic carries on, so a release that
+/// panics too aborts the process.
+impl<A: 'static> MonadBracket for Io<A> {
+    fn bracket<R: Clone + 'static>(
+        acquire: Io<R>,
+        use_: impl FnOnce(R) -> Io<A> + 'static,
+        release: impl FnOnce(R) -> Io<()> + 'static,
+    ) -> Io<A> {
+        Io::new(move || {
+            let resource = acquire.unsafe_run();
+            let guard = Release(Some((resource.clone(), release)));
+            let a = use_(resource).unsafe_run();
+            drop(guard);
+            a
+        })
+    }
+}
+
+/// The effects run when the `FutureK` is first polled, on whichever thread polls it, so they
+/// shouldn't block.
+#[cfg(feature = "futures")]
+impl<A: 'static> AsyncFunctor for Io<A> {
+    type Suspended<B: 'static> = FutureK<B>;
+
+    fn fmap_async<B: 'static>(self, mut f: impl AsyncFnMut(A) -> B + 'static) -> FutureK<B> {
+        FutureK::new(async move { f(self.unsafe_run()).await })
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<A: 'static> AsyncMonad for Io<A> {
+    fn bind_async<B: 'static>(self, mut f: impl AsyncFnMut(A) -> Io<B> + 'static) -> FutureK<B> {
+        FutureK::new(async move { f(self.unsafe_run()).await.unsafe_run() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    fn say(log: &Log, line: &str) -> Io<()> {
+        let (log, line) = (Rc::clone(log), line.to_owned());
+        Io::new(move || log.borrow_mut().push(line))
+    }
+
+    #[test]
+    fn nothing_runs_until_unsafe_run() {
+        let log = Log::default();
+        let prog = say(&log, "hello")
+            .bind({
+                let log = Rc::clone(&log);
+                move |()| say(&log, "world")
+            })
+            .zip_with(Io::new(|| 2), |(), n| n * 21);
+        assert!(log.borrow().is_empty());
+        assert_eq!(prog.unsafe_run(), 42);
+        assert_eq!(*log.borrow(), ["hello", "world"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn attempt_catches_panics() {
+        let ok = Io::new(|| 1).attempt().unsafe_run();
+        assert_eq!(ok.ok(), Some(1));
+        let failed = Io::new(|| -> u32 { panic!("boom") }).attempt().unsafe_run();
+        assert_eq!(failed.unwrap_err().downcast_ref::<&str>(), Some(&"boom"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bracket_releases_after_a_panic() {
+        let log = Log::default();
+        let (acquire, release) = (Rc::clone(&log), Rc::clone(&log));
+        let prog = Io::bracket(
+            say(&log, "open").fmap(|()| "file"),
+            |name| Io::new(move || -> u32 { panic!("reading {name} failed") }),
+            move |name| say(&release, &format!("close {name}")),
+        );
+        assert!(prog.attempt().unsafe_run().is_err());
+        assert_eq!(*acquire.borrow(), ["open", "close file"]);
+
+        let finished = say(&log, "work")
+            .fmap(|()| 7)
+            .guarantee(say(&log, "done"))
+            .unsafe_run();
+        assert_eq!(finished, 7);
+        assert_eq!(log.borrow()[2..], ["work", "done"]);
+    }
+
+    #[test]
+    fn tail_rec_m_loops() {
+        let log = Log::default();
+        let count = Io::<()>::tail_rec_m(0u32, {
+            let log = Rc::clone(&log);
+            move |n| {
+                say(&log, &n.to_string()).fmap(move |()| {
+                    if n == 1_000_000 {
+                        Either::Right(n)
+                    } else {
+                        Either::Left(n + 1)
+                    }
+                })
+            }
+        });
+        assert_eq!(count.unsafe_run(), 1_000_000);
+        assert_eq!(log.borrow().len(), 1_000_001);
+    }
+}
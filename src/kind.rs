@@ -0,0 +1,55 @@
+//! Type constructors of kind `* -> *`.
+
+/// A type constructor applied to its last type parameter.
+///
+/// `Self::Inner` is that parameter, and `Self::With<I>` is the same constructor applied to `I`
+/// instead. For example, `Identity<T>` has `Inner = T` and `With<I> = Identity<I>`.
+pub trait K1 {
+    type Inner;
+    // The bound `With<I>: K1<Inner = I>` ensures that Inner matches the last applied type parameter.
+    // The bound `K1<With<Self::Inner> = Self>` ensures that the projection's projection points
+    // back to `Self`. This is to disallow impl's of the form:
+    // ```rust
+    // struct Id1<T>(T);
+    // struct Id2<T>(T);
+    // impl<T> K1 for Id1<T> {
+    //     type Inner = T;
+    //     type With<I> = Id2<I>;
+    // }
+    // impl<T> K1 for Id2<T> {
+    //     type Inner = T;
+    //     type With<I> = Id2<I>;
+    // }
+    // ```
+    //
+    // We need the third bound to ensure that the projection's projection is the same as the
+    // projection of `Self`. Note that if this bound was not present, then the following impl would
+    // be legal:
+    // ```rust
+    // struct Id1<T>(T);
+    // struct Id2<T>(T);
+    // impl<T> K1 for Id1<T> {
+    //     type Inner = T;
+    //     type With<I> = Id2<I>;
+    // }
+    // impl<T> K1 for Id2<T> {
+    //     type Inner = T;
+    //     type With<I> = Id1<I>;
+    // }
+    // ```
+    type With<I>: K1<Inner = I> + K1<With<Self::Inner> = Self> + K1<With<I> = Self::With<I>>;
+}
+
+/// A type constructor applied to its last two type parameters.
+///
+/// This is [`K1`] for constructors like `Result<T, E>` or `(A, B)` that are worth abstracting over
+/// in both parameters at once. `Inner1` and `Inner2` are the applied parameters, in declaration
+/// order, and `With<A, B>` re-applies the constructor to new ones.
+pub trait K2 {
+    type Inner1;
+    type Inner2;
+    // Same self-consistency requirements as `K1::With`, just with two parameters.
+    type With<A, B>: K2<Inner1 = A, Inner2 = B>
+        + K2<With<Self::Inner1, Self::Inner2> = Self>
+        + K2<With<A, B> = Self::With<A, B>>;
+}
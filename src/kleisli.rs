@@ -0,0 +1,237 @@
+//! Kleisli arrows: monadic functions as values.
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::applicative::Applicative;
+use crate::arrow::{Arrow, Category};
+use crate::either::Either;
+use crate::functor::FunctorWith;
+use crate::kind::{K1, K2};
+use crate::monad::Monad;
+use crate::profunctor::{Choice, Profunctor, Strong};
+
+/// A function `A -> M::With<B>`.
+///
+/// Kleisli arrows compose like plain functions, threading the monad's effect through with
+/// [`bind`](Monad::bind). As with [`Free`](crate::free::Free), `M` only stands in for its
+/// constructor and is conventionally applied to `()`, e.g. `Kleisli<Option<()>, &str, i32>`.
+///
+/// The function is reference counted, so cloning an arrow is cheap and it can be run any number
+/// of times.
+pub struct Kleisli<M: K1, A, B>(Rc<dyn Fn(A) -> M::With<B>>);
+
+impl<M: K1, A, B> Clone for Kleisli<M, A, B> {
+    fn clone(&self) -> Self {
+        Kleisli(self.0.clone())
+    }
+}
+
+impl<M: K1, A, B> Kleisli<M, A, B> {
+    pub fn new(f: impl Fn(A) -> M::With<B> + 'static) -> Self {
+        Kleisli(Rc::new(f))
+    }
+
+    pub fn run(&self, a: A) -> M::With<B> {
+        (self.0)(a)
+    }
+}
+
+impl<M: Monad + 'static, A: 'static, B: 'static> Kleisli<M, A, B> {
+    /// Runs `self`, then feeds its results to `next`. This is Haskell's `>=>`.
+    pub fn and_then<C: 'static>(self, next: Kleisli<M, B, C>) -> Kleisli<M, A, C>
+    where
+        M::With<B>: Monad<With<C> = M::With<C>>,
+    {
+        Kleisli::new(move |a| {
+            let next = next.clone();
+            (self.0)(a).bind::<C>(move |b| next.run(b))
+        })
+    }
+
+    /// Runs `prev`, then feeds its results to `self`. This is Haskell's `<=<`.
+    pub fn compose<Z: 'static>(self, prev: Kleisli<M, Z, A>) -> Kleisli<M, Z, B>
+    where
+        M::With<A>: Monad<With<B> = M::With<B>>,
+    {
+        prev.and_then(self)
+    }
+
+    /// Lifts a plain function into an arrow with no effects.
+    pub fn arr(f: impl Fn(A) -> B + 'static) -> Self {
+        Kleisli::new(move |a| M::pure(f(a)))
+    }
+}
+
+impl<M: Monad + 'static, A: 'static> Kleisli<M, A, A> {
+    /// The arrow that returns its input unchanged; the unit of [`and_then`](Self::and_then).
+    pub fn id() -> Self {
+        Kleisli::new(M::pure)
+    }
+}
+
+impl<M: FunctorWith + 'static, A: 'static, B: 'static> Kleisli<M, A, B> {
+    /// Runs `self` on the first half of a pair, passing the second half through.
+    pub fn first<C: Clone + 'static>(self) -> Kleisli<M, (A, C), (B, C)> {
+        Kleisli::new(move |(a, c): (A, C)| M::fmap_with(self.run(a), move |b| (b, c.clone())))
+    }
+
+    /// Runs `self` on the second half of a pair, passing the first half through.
+    pub fn second<C: Clone + 'static>(self) -> Kleisli<M, (C, A), (C, B)> {
+        Kleisli::new(move |(c, a): (C, A)| M::fmap_with(self.run(a), move |b| (c.clone(), b)))
+    }
+}
+
+impl<M: K1, A, B> K2 for Kleisli<M, A, B> {
+    type Inner1 = A;
+    type Inner2 = B;
+
+    type With<C, D> = Kleisli<M, C, D>;
+}
+
+impl<M: FunctorWith + 'static, A: 'static, B: 'static> Profunctor for Kleisli<M, A, B> {
+    fn dimap<C: 'static, D: 'static>(
+        self,
+        f: impl FnMut(C) -> A + 'static,
+        g: impl FnMut(B) -> D + 'static,
+    ) -> Kleisli<M, C, D> {
+        // The arrow can be run any number of times, each needing `g` for its own `fmap`.
+        let (f, g) = (RefCell::new(f), Rc::new(RefCell::new(g)));
+        Kleisli::new(move |c| {
+            let g = g.clone();
+            M::fmap_with(self.run((f.borrow_mut())(c)), move |b| (g.borrow_mut())(b))
+        })
+    }
+}
+
+impl<M: FunctorWith + 'static, A: 'static, B: 'static> Strong for Kleisli<M, A, B> {
+    fn first_p<C: Clone + 'static>(self) -> Kleisli<M, (A, C), (B, C)> {
+        self.first()
+    }
+
+    fn second_p<C: Clone + 'static>(self) -> Kleisli<M, (C, A), (C, B)> {
+        self.second()
+    }
+}
+
+/// Needs `M: Applicative` to wrap the passed-through side with `pure`.
+impl<M: Applicative + FunctorWith + 'static, A: 'static, B: 'static> Choice for Kleisli<M, A, B> {
+    fn left_p<C: 'static>(self) -> Kleisli<M, Either<A, C>, Either<B, C>> {
+        Kleisli::new(move |ac| match ac {
+            Either::Left(a) => M::fmap_with(self.run(a), Either::Left),
+            Either::Right(c) => M::pure(Either::Right(c)),
+        })
+    }
+
+    fn right_p<C: 'static>(self) -> Kleisli<M, Either<C, A>, Either<C, B>> {
+        Kleisli::new(move |ca| match ca {
+            Either::Left(c) => M::pure(Either::Left(c)),
+            Either::Right(a) => M::fmap_with(self.run(a), Either::Right),
+        })
+    }
+}
+
+impl<M, A: 'static, B: 'static> Category for Kleisli<M, A, B>
+where
+    M: Monad + 'static,
+    M::With<A>: Monad<With<B> = M::With<B>>,
+{
+    fn id<X: 'static>() -> Kleisli<M, X, X> {
+        Kleisli::new(M::pure)
+    }
+
+    fn compose<Z: 'static>(self, prev: Kleisli<M, Z, A>) -> Kleisli<M, Z, B> {
+        prev.and_then(self)
+    }
+}
+
+impl<M, A: 'static, B: 'static> Arrow for Kleisli<M, A, B>
+where
+    M: Monad + FunctorWith + 'static,
+    M::With<A>: Monad<With<B> = M::With<B>>,
+{
+    fn arr<X: 'static, Y: 'static>(f: impl Fn(X) -> Y + 'static) -> Kleisli<M, X, Y> {
+        Kleisli::arr(f)
+    }
+
+    fn first<C: Clone + 'static>(self) -> Kleisli<M, (A, C), (B, C)> {
+        Kleisli::first(self)
+    }
+
+    fn second<C: Clone + 'static>(self) -> Kleisli<M, (C, A), (C, B)> {
+        Kleisli::second(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Parse<A> = Kleisli<Option<()>, A, i32>;
+
+    fn parse() -> Parse<&'static str> {
+        Kleisli::new(|s: &str| s.parse().ok())
+    }
+
+    fn halve() -> Parse<i32> {
+        Kleisli::new(|n: i32| (n % 2 == 0).then_some(n / 2))
+    }
+
+    #[test]
+    fn composes_left_to_right() {
+        let pipeline = parse().and_then(halve()).and_then(halve());
+        assert_eq!(pipeline.run("12"), Some(3));
+        assert_eq!(pipeline.run("6"), None);
+        assert_eq!(pipeline.run("x"), None);
+        assert_eq!(halve().compose(parse()).run("8"), Some(4));
+    }
+
+    #[test]
+    fn id_and_arr_are_pure() {
+        let k = Kleisli::<Vec<()>, i32, i32>::id()
+            .and_then(Kleisli::new(|n: i32| vec![n, -n]))
+            .and_then(Kleisli::arr(|n| n * 10));
+        assert_eq!(k.run(1), [10, -10]);
+    }
+
+    #[test]
+    fn first_and_second_pass_through() {
+        assert_eq!(halve().first().run((4, "x")), Some((2, "x")));
+        assert_eq!(halve().second().run(('y', 3)), None);
+    }
+
+    #[test]
+    fn profunctor_classes() {
+        let k = halve().dimap(|s: &str| s.len() as i32, |n| n * 100);
+        assert_eq!(k.run("abcd"), Some(200));
+        assert_eq!(k.run("abc"), None);
+
+        let either = halve().left_p::<&str>();
+        assert_eq!(either.run(Either::Left(8)), Some(Either::Left(4)));
+        assert_eq!(
+            either.run(Either::Right("skip")),
+            Some(Either::Right("skip"))
+        );
+
+        // Over a list monad `right_p` lets a prism-like arrow fan out only on the matching case.
+        let split = Kleisli::<Vec<()>, i32, i32>::new(|n| vec![n, -n]).right_p::<char>();
+        assert_eq!(
+            split.run(Either::Right(2)),
+            [Either::Right(2), Either::Right(-2)]
+        );
+        assert_eq!(split.run(Either::Left('x')), [Either::Left('x')]);
+        assert_eq!(halve().second_p::<bool>().run((true, 6)), Some((true, 3)));
+    }
+
+    #[test]
+    fn arrow_combinators() {
+        let both = parse().split(halve());
+        assert_eq!(both.run(("7", 4)), Some((7, 2)));
+        assert_eq!(both.run(("7", 3)), None);
+
+        let fan = halve().fanout(Kleisli::new(|n: i32| Some(n + 1)));
+        assert_eq!(fan.run(10), Some((5, 11)));
+        let piped = Category::compose(halve(), <Parse<i32>>::id());
+        assert_eq!(piped.run(6), Some(3));
+    }
+}
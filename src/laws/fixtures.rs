@@ -0,0 +1,20 @@
+//! Unlawful instances for checking that the law checks catch them.
+
+use crate::functor::Functor;
+use crate::kind::K1;
+
+// Drops every element after the first, which breaks the identity law.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Truncating<T>(pub(super) Vec<T>);
+
+impl<T> K1 for Truncating<T> {
+    type Inner = T;
+
+    type With<I> = Truncating<I>;
+}
+
+impl<A> Functor for Truncating<A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Truncating<B> {
+        Truncating(self.0.into_iter().take(1).map(f).collect())
+    }
+}
@@ -0,0 +1,256 @@
+//! Assertions for checking that instances obey their laws.
+//!
+//! Each macro takes the type under test followed by named arguments. The arguments are pasted
+//! into every law that uses them, so each is evaluated afresh every time and acts as a generator
+//! for a new value. They should all produce the same value each time.
+//!
+//! Both sides of every law are compared with `assert_eq!`, so the results need `PartialEq` and
+//! `Debug`. Types without them, like [`State`](crate::state::State), can pass an `observe`
+//! closure that turns each side into something comparable, e.g. by running it. It is pasted in
+//! like the other arguments, so it can be used at several types as long as its body type checks
+//! for each.
+//!
+//! ```
+//! use higher_kinded::assert_monad_laws;
+//!
+//! assert_monad_laws!(
+//!     Option<i32>,
+//!     value: Some(3),
+//!     pure: 4,
+//!     f: |x: i32| x.checked_mul(2),
+//!     g: |x: i32| (x > 5).then_some(x - 5),
+//! );
+//! ```
+
+#[cfg(test)]
+mod fixtures;
+#[cfg(feature = "proptest")]
+pub mod prop;
+
+/// Applies `observe` to `val`. The macros call it instead of the closure directly so that the
+/// closure's parameter type is inferred from `val`.
+#[doc(hidden)]
+pub fn __observe<T, R>(val: T, observe: impl FnOnce(T) -> R) -> R {
+    observe(val)
+}
+
+/// Asserts the [`Functor`](crate::functor::Functor) laws:
+/// - identity: `value.fmap(|a| a) == value`
+/// - composition: `value.fmap(f).fmap(g) == value.fmap(|a| g(f(a)))`
+///
+/// See the [module documentation](crate::laws) for how the arguments are used.
+#[macro_export]
+macro_rules! assert_functor_laws {
+    (
+        $ty:ty,
+        value: $value:expr,
+        f: $f:expr,
+        g: $g:expr,
+        observe: $observe:expr $(,)?
+    ) => {{
+        use $crate::functor::Functor as _;
+
+        let lhs: $ty = $value;
+        let rhs: $ty = $value;
+        assert_eq!(
+            $crate::laws::__observe(lhs.fmap(|a| a), $observe),
+            $crate::laws::__observe(rhs, $observe),
+            "functor identity law",
+        );
+
+        let lhs: $ty = $value;
+        let rhs: $ty = $value;
+        assert_eq!(
+            $crate::laws::__observe(lhs.fmap($f).fmap($g), $observe),
+            $crate::laws::__observe(
+                rhs.fmap(|a| ($g)(($f)(a))),
+                $observe
+            ),
+            "functor composition law",
+        );
+    }};
+    ($ty:ty, value: $value:expr, f: $f:expr, g: $g:expr $(,)?) => {
+        $crate::assert_functor_laws!($ty, value: $value, f: $f, g: $g, observe: |x| x)
+    };
+}
+
+/// Asserts the [`Applicative`](crate::applicative::Applicative) laws, stated for `zip_with`:
+/// - left identity: `pure(()).zip_with(value, |(), a| a) == value`
+/// - right identity: `value.zip_with(pure(()), |a, ()| a) == value`
+/// - homomorphism: `pure(x).fmap(f) == pure(f(x))`, where `x` is the `pure` argument
+/// - associativity: zipping three copies of `value` gives the same result whichever pair is
+///   zipped first
+///
+/// Associativity compares tuples of `value`'s elements, so they need `PartialEq` and `Debug` too
+/// unless `observe` gets rid of them. See the [module documentation](crate::laws) for how the
+/// arguments are used.
+#[macro_export]
+macro_rules! assert_applicative_laws {
+    (
+        $ty:ty,
+        value: $value:expr,
+        pure: $pure:expr,
+        f: $f:expr,
+        observe: $observe:expr $(,)?
+    ) => {{
+        // `Option::zip_with` is an unstable inherent method, so `zip_with` is called through the
+        // trait to avoid the name collision.
+        use $crate::applicative::Applicative;
+        use $crate::functor::Functor as _;
+
+        let unit = <$ty as Applicative>::pure(());
+        let rhs: $ty = $value;
+        assert_eq!(
+            $crate::laws::__observe(Applicative::zip_with(unit, $value, |(), a| a), $observe),
+            $crate::laws::__observe(rhs, $observe),
+            "applicative left identity law",
+        );
+
+        let lhs: $ty = $value;
+        let rhs: $ty = $value;
+        let unit = <$ty as Applicative>::pure(());
+        assert_eq!(
+            $crate::laws::__observe(Applicative::zip_with(lhs, unit, |a, ()| a), $observe),
+            $crate::laws::__observe(rhs, $observe),
+            "applicative right identity law",
+        );
+
+        let lhs = <$ty as Applicative>::pure($pure).fmap($f);
+        let rhs = <$ty as Applicative>::pure(($f)($pure));
+        assert_eq!(
+            $crate::laws::__observe(lhs, $observe),
+            $crate::laws::__observe(rhs, $observe),
+            "applicative homomorphism law",
+        );
+
+        let (a, b, c): ($ty, $ty, $ty) = ($value, $value, $value);
+        let ab = Applicative::zip_with(a, b, |a, b| (a, b));
+        let lhs = Applicative::zip_with(ab, c, |(a, b), c| (a, b, c));
+        let (a, b, c): ($ty, $ty, $ty) = ($value, $value, $value);
+        let bc = Applicative::zip_with(b, c, |b, c| (b, c));
+        let rhs = Applicative::zip_with(a, bc, |a, (b, c)| (a, b, c));
+        assert_eq!(
+            $crate::laws::__observe(lhs, $observe),
+            $crate::laws::__observe(rhs, $observe),
+            "applicative associativity law",
+        );
+    }};
+    ($ty:ty, value: $value:expr, pure: $pure:expr, f: $f:expr $(,)?) => {
+        $crate::assert_applicative_laws!($ty, value: $value, pure: $pure, f: $f, observe: |x| x)
+    };
+}
+
+/// Asserts the [`Monad`](crate::monad::Monad) laws:
+/// - left identity: `pure(x).bind(f) == f(x)`, where `x` is the `pure` argument
+/// - right identity: `value.bind(pure) == value`
+/// - associativity: `value.bind(f).bind(g) == value.bind(|a| f(a).bind(g))`
+///
+/// `f` and `g` both return the type under test. See the [module documentation](crate::laws) for
+/// how the arguments are used.
+#[macro_export]
+macro_rules! assert_monad_laws {
+    (
+        $ty:ty,
+        value: $value:expr,
+        pure: $pure:expr,
+        f: $f:expr,
+        g: $g:expr,
+        observe: $observe:expr $(,)?
+    ) => {{
+        use $crate::monad::Monad as _;
+
+        let lhs = <$ty as $crate::applicative::Applicative>::pure($pure).bind($f);
+        let rhs: $ty = ($f)($pure);
+        assert_eq!(
+            $crate::laws::__observe(lhs, $observe),
+            $crate::laws::__observe(rhs, $observe),
+            "monad left identity law",
+        );
+
+        let lhs: $ty = $value;
+        let rhs: $ty = $value;
+        assert_eq!(
+            $crate::laws::__observe(
+                lhs.bind(|a| <$ty as $crate::applicative::Applicative>::pure(a)),
+                $observe
+            ),
+            $crate::laws::__observe(rhs, $observe),
+            "monad right identity law",
+        );
+
+        let lhs: $ty = $value;
+        let rhs: $ty = $value;
+        assert_eq!(
+            $crate::laws::__observe(lhs.bind($f).bind($g), $observe),
+            $crate::laws::__observe(
+                rhs.bind(|a| ($f)(a).bind($g)),
+                $observe
+            ),
+            "monad associativity law",
+        );
+    }};
+    ($ty:ty, value: $value:expr, pure: $pure:expr, f: $f:expr, g: $g:expr $(,)?) => {
+        $crate::assert_monad_laws!($ty, value: $value, pure: $pure, f: $f, g: $g, observe: |x| x)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::Truncating;
+    #[cfg(feature = "alloc")]
+    use crate::functor::Functor;
+    use crate::identity::Identity;
+    use crate::result::ResultOk;
+    #[cfg(feature = "alloc")]
+    use crate::state::State;
+
+    #[test]
+    fn std_instances_are_lawful() {
+        assert_applicative_laws!(Option<i32>, value: Some(1), pure: 3, f: |x: i32| x - 1);
+        assert_monad_laws!(
+            ResultOk<i32, &str>,
+            value: ResultOk(Ok(1)),
+            pure: 0,
+            f: |x: i32| ResultOk(if x > 0 { Ok(x) } else { Err("not positive") }),
+            g: |x: i32| ResultOk(Ok(x + 1)),
+        );
+        assert_applicative_laws!(Identity<&str>, value: Identity("a"), pure: "b", f: str::len);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_is_lawful() {
+        assert_functor_laws!(Vec<i32>, value: vec![1, 2, 3], f: |x: i32| x + 1, g: |x: i32| x * 2);
+        assert_monad_laws!(
+            Vec<i32>,
+            value: vec![1, 2],
+            pure: 3,
+            f: |x: i32| vec![x, -x],
+            g: |x: i32| vec![x; x.unsigned_abs() as usize],
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn observe_runs_lazy_instances() {
+        assert_monad_laws!(
+            State<i32, i32>,
+            value: State::new(|s| (s, s + 1)),
+            pure: 5,
+            f: |x: i32| State::new(move |s| (x * s, s)),
+            g: |x: i32| State::put(x).fmap(move |()| -x),
+            observe: |st| st.run_state(2),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "functor identity law")]
+    fn catches_unlawful_instances() {
+        assert_functor_laws!(
+            Truncating<i32>,
+            value: Truncating(vec![1, 2]),
+            f: |x: i32| x,
+            g: |x: i32| x,
+        );
+    }
+}
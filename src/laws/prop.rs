@@ -0,0 +1,184 @@
+//! Property-based law checks, and [`Arbitrary`] impls for this crate's types.
+//!
+//! Each check runs its laws against many values drawn from a strategy, and panics with the
+//! smallest counterexample proptest can find. The functions are plain `fn` pointers, so they
+//! can be called any number of times; closures that don't capture anything coerce to them.
+//!
+//! Both sides of each law are compared directly, which needs `Clone`, `PartialEq` and `Debug`.
+//! Lazy types like [`State`](crate::state::State) have none of those, so check them with the
+//! [`assert_*_laws!`](crate::assert_monad_laws) macros and an `observe` closure instead.
+//!
+//! ```
+//! use higher_kinded::laws::prop::check_monad_laws;
+//! use proptest::prelude::*;
+//!
+//! check_monad_laws(
+//!     any::<Option<i32>>(),
+//!     any::<i32>(),
+//!     |x| x.checked_mul(2),
+//!     |x| x.checked_sub(1),
+//! );
+//! ```
+
+use core::fmt::Debug;
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::prop_assert_eq;
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use proptest::test_runner::{TestCaseResult, TestRunner};
+
+use crate::either::Either;
+use crate::functor::Functor;
+use crate::identity::Identity;
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::non_empty::NonEmptyVec;
+use crate::result::ResultOk;
+use crate::validation::Validation;
+
+fn run<S: Strategy>(values: S, test: impl Fn(S::Value) -> TestCaseResult) {
+    if let Err(err) = TestRunner::default().run(&values, test) {
+        panic!("{err}");
+    }
+}
+
+/// Checks the [`Functor`] laws for every value `values` generates:
+/// - identity: `x.fmap(|a| a) == x`
+/// - composition: `x.fmap(f).fmap(g) == x.fmap(|a| g(f(a)))`
+pub fn check_functor_laws<T, S>(values: S, f: fn(T::Inner) -> T::Inner, g: fn(T::Inner) -> T::Inner)
+where
+    S: Strategy<Value = T>,
+    T: Functor + K1<With<<T as K1>::Inner> = T> + Clone + PartialEq + Debug,
+    T::Inner: 'static,
+{
+    run(values, |x| {
+        prop_assert_eq!(x.clone().fmap(|a| a), x.clone(), "functor identity law");
+        prop_assert_eq!(
+            x.clone().fmap(f).fmap(g),
+            x.fmap(move |a| g(f(a))),
+            "functor composition law"
+        );
+        Ok(())
+    });
+}
+
+/// Checks the [`Monad`] laws for every value `values` and `pures` generate:
+/// - left identity: `pure(a).bind(f) == f(a)`
+/// - right identity: `x.bind(pure) == x`
+/// - associativity: `x.bind(f).bind(g) == x.bind(|a| f(a).bind(g))`
+pub fn check_monad_laws<T, S, P>(values: S, pures: P, f: fn(T::Inner) -> T, g: fn(T::Inner) -> T)
+where
+    S: Strategy<Value = T>,
+    P: Strategy<Value = T::Inner>,
+    T: Monad + K1<With<<T as K1>::Inner> = T> + Clone + PartialEq + Debug + 'static,
+    T::Inner: Clone + Debug + 'static,
+{
+    run((values, pures), |(x, a)| {
+        prop_assert_eq!(
+            T::pure::<T::Inner>(a.clone()).bind::<T::Inner>(f),
+            f(a),
+            "monad left identity law"
+        );
+        prop_assert_eq!(
+            x.clone().bind::<T::Inner>(T::pure::<T::Inner>),
+            x.clone(),
+            "monad right identity law"
+        );
+        prop_assert_eq!(
+            x.clone().bind::<T::Inner>(f).bind::<T::Inner>(g),
+            x.bind::<T::Inner>(move |a| f(a).bind::<T::Inner>(g)),
+            "monad associativity law"
+        );
+        Ok(())
+    });
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for Identity<T> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: T::Parameters) -> Self::Strategy {
+        T::arbitrary_with(args).prop_map(Identity).boxed()
+    }
+}
+
+impl<T: Arbitrary + 'static, E: Arbitrary + 'static> Arbitrary for ResultOk<T, E> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        any::<Result<T, E>>().prop_map(ResultOk).boxed()
+    }
+}
+
+impl<L: Arbitrary + 'static, R: Arbitrary + 'static> Arbitrary for Either<L, R> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        prop_oneof![
+            any::<L>().prop_map(Either::Left),
+            any::<R>().prop_map(Either::Right),
+        ]
+        .boxed()
+    }
+}
+
+impl<E: Arbitrary + 'static, A: Arbitrary + 'static> Arbitrary for Validation<E, A> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        prop_oneof![
+            any::<E>().prop_map(Validation::Failure),
+            any::<A>().prop_map(Validation::Success),
+        ]
+        .boxed()
+    }
+}
+
+/// Generates up to 8 elements.
+impl<T: Arbitrary + 'static> Arbitrary for NonEmptyVec<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (any::<T>(), vec(any::<T>(), 0..8))
+            .prop_map(|(head, tail)| NonEmptyVec::new(head, tail))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::fixtures::Truncating;
+
+    #[test]
+    fn instances_are_lawful() {
+        check_functor_laws(any::<Vec<u8>>(), |x| x / 2, |x| x.wrapping_add(1));
+        check_functor_laws(any::<Either<bool, i32>>(), |x| x ^ 1, |x| !x);
+        check_functor_laws(any::<NonEmptyVec<i8>>(), i8::wrapping_neg, |x| x >> 1);
+        check_monad_laws(
+            any::<ResultOk<i16, bool>>(),
+            any::<i16>(),
+            |x| ResultOk(x.checked_add(1).ok_or(true)),
+            |x| ResultOk(if x % 3 == 0 { Err(false) } else { Ok(x) }),
+        );
+        check_monad_laws(
+            any::<Vec<u8>>(),
+            any::<u8>(),
+            |x| vec![x; (x % 3) as usize],
+            |x| vec![x, x.wrapping_mul(3)],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "functor identity law")]
+    fn reports_counterexamples() {
+        let values = vec(any::<u8>(), 0..4).prop_map(Truncating);
+        check_functor_laws(values, |x| x, |x| x);
+    }
+}
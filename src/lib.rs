@@ -1,126 +1,136 @@
-use std::marker::PhantomData;
-
-trait K1 {
-    type Inner;
-    // The bound `With<I>: K1<Inner = I>` ensures that Inner matches the last applied type parameter.
-    // The bound `K1<With<Self::Inner> = Self>` ensures that the projection's projection points
-    // back to `Self`. This is to disallow impl's of the form:
-    // ```rust
-    // struct Id1<T>(T);
-    // struct Id2<T>(T);
-    // impl<T> K1 for Id1<T> {
-    //     type Inner = T;
-    //     type With<I> = Id2<I>;
-    // }
-    // impl<T> K1 for Id2<T> {
-    //     type Inner = T;
-    //     type With<I> = Id2<I>;
-    // }
-    // ```
-    //
-    // We need the third bound to ensure that the projection's projection is the same as the
-    // projection of `Self`. Note that if this bound was not present, then the following impl would
-    // be legal:
-    // ```rust
-    // struct Id1<T>(T);
-    // struct Id2<T>(T);
-    // impl<T> K1 for Id1<T> {
-    //     type Inner = T;
-    //     type With<I> = Id2<I>;
-    // }
-    // impl<T> K1 for Id2<T> {
-    //     type Inner = T;
-    //     type With<I> = Id1<I>;
-    // }
-    // ```
-    type With<I>: K1<Inner = I> + K1<With<Self::Inner> = Self> + K1<With<I> = Self::With<I>>;
-}
-
-trait Functor: K1 {
-    fn fmap<B>(self, f: impl FnOnce(Self::Inner) -> B) -> Self::With<B>;
-}
-
-trait Applicative: Functor {
-    fn pure(val: Self::Inner) -> Self;
-
-    fn zip_with<B, C>(self, b: Self::With<B>, f: impl FnOnce(Self::Inner, B) -> C)
-        -> Self::With<C>;
-}
-
-trait Monad: Applicative {
-    fn bind<B>(self, f: impl FnOnce(Self::Inner) -> Self::With<B>) -> Self::With<B>;
-
-    fn flatten(self) -> Self::Inner
-    where
-        Self::Inner: K1<With<Self::Inner> = Self>;
-}
-
-#[derive(Debug, PartialEq, Eq)]
-struct Identity<T>(T);
-
-impl<T> K1 for Identity<T> {
-    type Inner = T;
-
-    type With<I> = Identity<I>;
-}
-
-impl<A> Functor for Identity<A> {
-    fn fmap<B>(self, f: impl FnOnce(A) -> B) -> Identity<B> {
-        Identity(f(self.0))
-    }
-}
-
-impl<A> Applicative for Identity<A> {
-    fn pure(val: A) -> Identity<A> {
-        Identity(val)
-    }
-
-    fn zip_with<B, C>(self, b: Identity<B>, f: impl FnOnce(A, B) -> C) -> Identity<C> {
-        Identity(f(self.0, b.0))
-    }
-}
-
-impl<A> Monad for Identity<A> {
-    fn bind<B>(self, f: impl FnOnce(A) -> Identity<B>) -> Identity<B> {
-        f(self.0)
-    }
-
-    fn flatten(self) -> Self::Inner
-    where
-        Self::Inner: K1<With<A> = Self>,
-    {
-        self.0
-    }
-}
-
-struct Const<C, V> {
-    inner: C,
-    _marker: PhantomData<V>,
-}
-
-impl<C, V> K1 for Const<C, V> {
-    type Inner = V;
-
-    type With<I> = Const<C, I>;
-}
-
-impl<C, A> Functor for Const<C, A> {
-    fn fmap<B>(self, _: impl FnOnce(A) -> B) -> Const<C, B> {
-        // mfw no type-changing-struct-update
-        Const {
-            inner: self.inner,
-            _marker: PhantomData,
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_ident() {
-        let i = Identity(Identity(0));
-        assert_eq!(i.flatten(), Identity(0));
-    }
+//! Higher kinded types in Rust, emulated with generic associated types.
+//!
+//! The [`K1`](kind::K1) trait describes a type constructor applied to its last type parameter,
+//! and the rest of the hierarchy ([`Functor`](functor::Functor),
+//! [`Applicative`](applicative::Applicative), [`Monad`](monad::Monad)) is built on top of it.
+//!
+//! The crate is `no_std`. The core traits and the instances that don't allocate, like `Option`,
+//! [`Identity`](identity::Identity) and [`Const`](constant::Const), are always available. The
+//! `alloc` feature adds the instances for `Vec`, `Box`, `String` and `BTreeMap` along with
+//! everything built on boxed closures, such as [`State`](state::State) and [`Free`](free::Free).
+//! The default `std` feature implies `alloc` and adds the instances for `HashMap`.
+//!
+//! Other features, named after the crates they depend on, add instances for those crates'
+//! types: `arrayvec` for `ArrayVec`, `either` for `either::Either`, `im` for its `Vector` and
+//! `HashMap`, and `smallvec` for `SmallVec`. The `futures` feature adds `FutureK` and `StreamK`,
+//! which make futures and streams monads over their outputs, and the `AsyncMonad` traits for
+//! binding with `async` functions.
+
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod align;
+pub mod alternative;
+pub mod applicative;
+pub mod arrow;
+#[cfg(feature = "futures")]
+pub mod async_monad;
+pub mod bifunctor;
+#[cfg(feature = "alloc")]
+pub mod codensity;
+#[cfg(feature = "alloc")]
+pub mod cofree;
+pub mod comonad;
+#[cfg(feature = "alloc")]
+pub mod compose;
+pub mod constant;
+#[cfg(feature = "alloc")]
+pub mod cont;
+pub mod contravariant;
+pub mod coproduct;
+#[cfg(feature = "alloc")]
+pub mod coyoneda;
+#[cfg(feature = "alloc")]
+pub mod dist;
+pub mod distributive;
+pub mod either;
+pub mod env;
+#[cfg(feature = "alloc")]
+pub mod eval;
+pub mod filterable;
+pub mod foldable;
+#[cfg(feature = "alloc")]
+pub mod free;
+#[cfg(feature = "alloc")]
+pub mod free_ap;
+#[cfg(feature = "alloc")]
+pub mod free_c;
+pub mod functor;
+#[cfg(feature = "futures")]
+pub mod future;
+pub mod identity;
+pub mod indexed;
+mod instances;
+pub mod invariant;
+#[cfg(feature = "alloc")]
+pub mod io;
+pub mod kind;
+#[cfg(feature = "alloc")]
+pub mod kleisli;
+pub mod laws;
+#[cfg(feature = "alloc")]
+pub mod logic;
+pub mod monad;
+pub mod monad_bracket;
+pub mod monad_error;
+pub mod monad_plus;
+pub mod monad_reader;
+pub mod monad_rec;
+pub mod monad_state;
+pub mod monad_writer;
+pub mod mono;
+pub mod monoid;
+pub mod nat;
+#[cfg(feature = "alloc")]
+pub mod non_empty;
+#[cfg(feature = "alloc")]
+pub mod optics;
+pub mod pair;
+#[cfg(feature = "alloc")]
+pub mod parser;
+pub mod poll;
+pub mod prelude;
+#[cfg(feature = "alloc")]
+pub mod product;
+pub mod profunctor;
+#[cfg(feature = "alloc")]
+pub mod reader;
+#[cfg(feature = "alloc")]
+pub mod recursion;
+pub mod representable;
+pub mod result;
+#[cfg(feature = "alloc")]
+pub mod rws;
+pub mod selective;
+#[cfg(feature = "alloc")]
+pub mod state;
+#[cfg(feature = "alloc")]
+pub mod store;
+#[cfg(feature = "futures")]
+pub mod stream;
+pub mod these;
+pub mod trans;
+pub mod traversable;
+pub mod try_functor;
+#[cfg(feature = "alloc")]
+mod util;
+pub mod validation;
+pub mod writer;
+#[cfg(feature = "alloc")]
+pub mod zip_vec;
+
+#[cfg(feature = "derive")]
+pub use higher_kinded_derive::{Functor, K1};
+
+// Paths the derive macros expand to, so they work in crates without `extern crate alloc`.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::boxed::Box;
+    pub use alloc::rc::Rc;
+    pub use alloc::vec::Vec;
 }
@@ -0,0 +1,251 @@
+//! Backtracking search with fair choice.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::alternative::Alternative;
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_plus::MonadPlus;
+
+/// A lazy stream of the answers to a search, produced one at a time as they're asked for.
+///
+/// [`or`](Alternative::or) and [`bind`](Monad::bind) search depth first like `Vec`'s instances,
+/// so a branch with infinitely many answers hides every branch after it. The fair versions,
+/// [`interleave`](Logic::interleave) and [`fair_bind`](Logic::fair_bind), alternate between
+/// branches instead, so every answer of every branch is reached eventually.
+///
+/// [`zip_with`](Applicative::zip_with) doesn't search every pair of answers the way `bind`
+/// does, for the reasons given on [`ZipVec`](crate::zip_vec::ZipVec): it pairs the first
+/// answer of each side, then the second, and so on. So the two instances disagree whenever both
+/// sides have more than one answer. [`pure`](Applicative::pure) has exactly one answer, which a
+/// zip cuts the other side down to, so the identity laws only hold for searches with at most
+/// one answer.
+///
+/// Nothing is searched until the answers are asked for with [`msplit`](Logic::msplit), or by
+/// iterating. Like [`Eval`](crate::eval::Eval), a `Logic` can only be run once.
+pub struct Logic<A>(Box<dyn FnOnce() -> Option<(A, Logic<A>)>>);
+
+impl<A: 'static> Logic<A> {
+    fn new(f: impl FnOnce() -> Option<(A, Logic<A>)> + 'static) -> Self {
+        Logic(Box::new(f))
+    }
+
+    /// The answers of the search built by `f` when it's first needed, for searches that refer
+    /// to themselves.
+    pub fn defer(f: impl FnOnce() -> Logic<A> + 'static) -> Self {
+        Logic::new(move || f().msplit())
+    }
+
+    /// Each of the items of `iter` in turn, which can go on forever.
+    pub fn choose<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: 'static,
+    {
+        let mut iter = iter.into_iter();
+        Logic::new(move || iter.next().map(|a| (a, Logic::choose(iter))))
+    }
+
+    /// Splits off the first answer, returning it with the search for the rest, or `None` if
+    /// there are no answers.
+    pub fn msplit(self) -> Option<(A, Logic<A>)> {
+        (self.0)()
+    }
+
+    /// Takes answers from `self` and `other` in turn, so that neither can starve the other.
+    pub fn interleave(self, other: Logic<A>) -> Self {
+        Logic::new(move || match self.msplit() {
+            None => other.msplit(),
+            Some((a, rest)) => Some((a, other.interleave(rest))),
+        })
+    }
+
+    /// Like [`bind`](Monad::bind), but [`interleave`](Logic::interleave)s the searches started
+    /// from each answer rather than exhausting each one before starting the next.
+    pub fn fair_bind<B: 'static>(self, mut f: impl FnMut(A) -> Logic<B> + 'static) -> Logic<B> {
+        Logic::new(move || {
+            // Searches that have no answers are skipped in a loop rather than recursing, so a
+            // long run of them doesn't grow the stack.
+            let mut searching = self;
+            loop {
+                let (a, rest) = searching.msplit()?;
+                match f(a).msplit() {
+                    None => searching = rest,
+                    Some((b, more)) => return Some((b, rest.fair_bind(f).interleave(more))),
+                }
+            }
+        })
+    }
+
+    /// Keeps only the first answer, pruning the rest of the search.
+    pub fn once(self) -> Self {
+        Logic::new(move || self.msplit().map(|(a, _)| (a, Self::empty())))
+    }
+
+    /// Returns the first answer, if there is one.
+    pub fn observe(self) -> Option<A> {
+        self.msplit().map(|(a, _)| a)
+    }
+
+    /// Returns up to the first `n` answers.
+    pub fn observe_many(self, n: usize) -> Vec<A> {
+        self.into_iter().take(n).collect()
+    }
+}
+
+impl<A: 'static> IntoIterator for Logic<A> {
+    type Item = A;
+
+    type IntoIter = IntoIter<A>;
+
+    fn into_iter(self) -> IntoIter<A> {
+        IntoIter(Some(self))
+    }
+}
+
+/// The answers of a [`Logic`], searched for as they're iterated over.
+pub struct IntoIter<A>(Option<Logic<A>>);
+
+impl<A: 'static> Iterator for IntoIter<A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        let (a, rest) = self.0.take()?.msplit()?;
+        self.0 = Some(rest);
+        Some(a)
+    }
+}
+
+impl<A> K1 for Logic<A> {
+    type Inner = A;
+
+    type With<I> = Logic<I>;
+}
+
+impl<A: 'static> Functor for Logic<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Logic<B> {
+        Logic::new(move || {
+            let (a, rest) = self.msplit()?;
+            let b = f(a);
+            Some((b, rest.fmap(f)))
+        })
+    }
+}
+
+impl<A: 'static> FunctorWith for Logic<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Logic<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Logic<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A: 'static> Applicative for Logic<A> {
+    fn pure<X: 'static>(val: X) -> Logic<X> {
+        Logic::new(move || Some((val, Logic::<X>::empty())))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Logic<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Logic<C> {
+        Logic::new(move || {
+            let (a, rest_a) = self.msplit()?;
+            let (b, rest_b) = b.msplit()?;
+            let c = f(a, b);
+            Some((c, rest_a.zip_with(rest_b, f)))
+        })
+    }
+}
+
+impl<A: 'static> Monad for Logic<A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Logic<B> + 'static) -> Logic<B> {
+        Logic::new(move || {
+            let mut searching = self;
+            loop {
+                let (a, rest) = searching.msplit()?;
+                match f(a).msplit() {
+                    None => searching = rest,
+                    Some((b, more)) => return Some((b, more.or(rest.bind(f)))),
+                }
+            }
+        })
+    }
+}
+
+impl<A: 'static> Alternative for Logic<A> {
+    fn empty<X: 'static>() -> Logic<X> {
+        Logic::new(|| None)
+    }
+
+    fn or(self, other: Logic<A>) -> Logic<A> {
+        Logic::new(move || match self.msplit() {
+            None => other.msplit(),
+            Some((a, rest)) => Some((a, rest.or(other))),
+        })
+    }
+}
+
+impl<A: 'static> MonadPlus for Logic<A> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleave_reaches_both_infinite_branches() {
+        let evens = || Logic::choose((0..).step_by(2));
+        let odds = || Logic::choose((1..).step_by(2));
+        assert_eq!(evens().or(odds()).observe_many(4), [0, 2, 4, 6]);
+        assert_eq!(evens().interleave(odds()).observe_many(4), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn fair_bind_reaches_every_branch() {
+        let pairs = |bind_fairly: bool| {
+            let rows = Logic::choose([0u32, 1]);
+            let row = |x| Logic::choose((0u32..).map(move |i| (x, i)));
+            if bind_fairly {
+                rows.fair_bind(row)
+            } else {
+                rows.bind(row)
+            }
+        };
+        assert!(pairs(false).observe_many(100).iter().all(|&(x, _)| x == 0));
+        assert_eq!(
+            pairs(true).observe_many(4),
+            [(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn searches_for_pythagorean_triples() {
+        let triples = Logic::choose(1u32..).bind(|c| {
+            Logic::choose(1..c).bind(move |b| {
+                Logic::choose(1..b)
+                    .mfilter(move |a| a * a + b * b == c * c)
+                    .fmap(move |a| (a, b, c))
+            })
+        });
+        assert_eq!(
+            triples.observe_many(3),
+            [(3, 4, 5), (6, 8, 10), (5, 12, 13)]
+        );
+    }
+
+    #[test]
+    fn msplit_and_once_prune_the_search() {
+        let (first, rest) = Logic::choose(["a", "b", "c"]).msplit().unwrap();
+        assert_eq!(first, "a");
+        assert_eq!(rest.into_iter().collect::<Vec<_>>(), ["b", "c"]);
+        assert_eq!(Logic::choose(5..).once().observe_many(3), [5]);
+        assert_eq!(Logic::<u8>::empty::<u8>().observe(), None);
+        let long = Logic::choose(0..1_000_000).mfilter(|n| *n == 999_999);
+        assert_eq!(long.observe(), Some(999_999));
+    }
+}
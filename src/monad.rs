@@ -0,0 +1,96 @@
+//! Monads.
+
+use crate::applicative::Applicative;
+use crate::kind::K1;
+
+/// An applicative whose effects can depend on the result of previous ones.
+pub trait Monad: Applicative {
+    fn bind<B: 'static>(
+        self,
+        f: impl FnMut(Self::Inner) -> Self::With<B> + 'static,
+    ) -> Self::With<B>;
+
+    /// Collapses one layer of nesting, e.g. `Some(Some(x))` into `Some(x)`.
+    fn flatten<B: 'static>(self) -> Self::With<B>
+    where
+        Self: Sized + K1<With<B> = <Self as K1>::Inner>,
+    {
+        self.bind::<B>(|inner| inner)
+    }
+}
+
+/// Do-notation: sequences monadic computations without nesting closures.
+///
+/// Each statement is one of:
+/// - `let pat <- expr;` binds the result of `expr`, like `expr.bind(move |pat| ...)`. The pattern
+///   has to be a single token tree, such as `x`, `_` or `(a, b)`.
+/// - `let pat = expr;` is an ordinary `let`.
+/// - `expr;` runs `expr` and ignores its result.
+///
+/// The block ends with the final computation, whose result is the result of the whole block.
+///
+/// ```
+/// use higher_kinded::mdo;
+///
+/// let sum = mdo! {
+///     let a <- Some(1);
+///     let b = a * 10;
+///     let c <- Some(b + 1);
+///     Some(a + c)
+/// };
+/// assert_eq!(sum, Some(12));
+/// ```
+///
+/// The rest of the block after a `<-` becomes a `move` closure that [`Monad::bind`] may call more
+/// than once, so values bound earlier have to be `Copy` or cloned if later statements consume
+/// them.
+#[macro_export]
+macro_rules! mdo {
+    (let $pat:tt <- $ma:expr; $($rest:tt)*) => {
+        $crate::monad::Monad::bind($ma, move |$pat| $crate::mdo!($($rest)*))
+    };
+    (let $pat:pat = $val:expr; $($rest:tt)*) => {{
+        let $pat = $val;
+        $crate::mdo!($($rest)*)
+    }};
+    ($ma:expr; $($rest:tt)*) => {
+        $crate::monad::Monad::bind($ma, move |_| $crate::mdo!($($rest)*))
+    };
+    ($ma:expr) => {
+        $ma
+    };
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::applicative::Applicative;
+    use crate::state::State;
+
+    #[test]
+    fn desugars_to_binds() {
+        let pairs = mdo! {
+            let a <- vec![1, 2];
+            let (b, c) <- vec![(a, 10), (a, 20)];
+            vec![b + c]
+        };
+        assert_eq!(pairs, [11, 21, 12, 22]);
+
+        let none: Option<i32> = mdo! {
+            let _ <- Some(());
+            None::<i32>;
+            Some(1)
+        };
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn threads_state() {
+        let prog = mdo! {
+            let n <- State::get();
+            State::put(n * 2);
+            let m <- State::get();
+            State::<u32, ()>::pure(n + m)
+        };
+        assert_eq!(prog.run_state(3), (9, 6));
+    }
+}
@@ -0,0 +1,35 @@
+//! Monads that can guarantee a resource is released.
+
+use crate::monad::Monad;
+
+/// A [`Monad`] that can run a cleanup step however the computation before it failed.
+///
+/// [`Io`](crate::io::Io) provides one that releases on a panic, and
+/// [`ResultT`](crate::trans::ResultT) passes it through from its base monad, releasing on an
+/// `Err` as well.
+///
+/// The resource is handed to both `use_` and `release`, so it has to be `Clone`. One that isn't
+/// can be shared through an `Rc`.
+///
+/// Instances should satisfy:
+/// - `Self::bracket(Self::pure(r), f, |_| Self::pure(())) == f(r)`
+/// - `release` runs exactly once after `acquire` succeeds, whether or not `use_` does
+pub trait MonadBracket: Monad {
+    /// Acquires a resource, uses it, then releases it, even if using it failed.
+    ///
+    /// Nothing is released if `acquire` itself fails.
+    fn bracket<R: Clone + 'static>(
+        acquire: Self::With<R>,
+        use_: impl FnOnce(R) -> Self + 'static,
+        release: impl FnOnce(R) -> Self::With<()> + 'static,
+    ) -> Self;
+
+    /// Runs `finalizer` after `self`, even if `self` failed.
+    fn guarantee(self, finalizer: Self::With<()>) -> Self
+    where
+        Self: Sized + 'static,
+        Self::With<()>: 'static,
+    {
+        Self::bracket(Self::pure(()), move |()| self, move |()| finalizer)
+    }
+}
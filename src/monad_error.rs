@@ -0,0 +1,29 @@
+//! Monads with recoverable failure.
+
+use crate::monad::Monad;
+
+/// A [`Monad`] that can fail with an `E` and recover from it.
+///
+/// [`ResultOk`](crate::result::ResultOk), [`Either`](crate::either::Either) and
+/// [`ResultT`](crate::trans::ResultT) provide one, and the other transformers pass it through from
+/// their base monads, like [`MonadState`](crate::monad_state::MonadState). A `ResultT` over
+/// another failing monad only exposes its own error type.
+///
+/// Instances should satisfy:
+/// - `Self::throw_error(e).bind(f) == Self::throw_error(e)`
+/// - `Self::throw_error(e).catch_error(h) == h(e)`
+/// - `Self::pure(a).catch_error(h) == Self::pure(a)`
+pub trait MonadError<E>: Monad {
+    fn throw_error<X: 'static>(err: E) -> Self::With<X>;
+
+    /// Runs `handler` on the error if `self` failed, and passes successes through.
+    fn catch_error(self, handler: impl FnMut(E) -> Self + 'static) -> Self;
+
+    /// Lifts a plain `Result` into this monad.
+    fn lift_result<X: 'static>(res: Result<X, E>) -> Self::With<X> {
+        match res {
+            Ok(x) => Self::pure(x),
+            Err(e) => Self::throw_error(e),
+        }
+    }
+}
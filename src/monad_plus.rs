@@ -0,0 +1,30 @@
+//! Monads with failure and choice.
+
+use crate::alternative::Alternative;
+use crate::monad::Monad;
+
+/// A [`Monad`] that is also an [`Alternative`], where failing anywhere fails the whole
+/// computation.
+///
+/// Instances should satisfy, on top of the laws of both traits:
+/// - `Self::empty().bind(f) == Self::empty()`
+/// - `m.bind(|_| Self::empty()) == Self::empty()`
+pub trait MonadPlus: Monad + Alternative {
+    /// Keeps only the results that satisfy `pred`.
+    fn mfilter(
+        self,
+        mut pred: impl FnMut(&Self::Inner) -> bool + 'static,
+    ) -> Self::With<Self::Inner>
+    where
+        Self: Sized,
+        Self::Inner: 'static,
+    {
+        self.bind(move |a| {
+            if pred(&a) {
+                Self::pure(a)
+            } else {
+                Self::empty()
+            }
+        })
+    }
+}
@@ -0,0 +1,35 @@
+//! Monads that can read a shared environment.
+
+use crate::monad::Monad;
+
+/// A [`Monad`] with an environment of type `R` that its computations can read.
+///
+/// [`Reader`](crate::reader::Reader), [`RWS`](crate::rws::RWS) and
+/// [`ReaderT`](crate::trans::ReaderT) provide one, and the other transformers pass it through
+/// from their base monads, so code written against `M: MonadReader<R>` runs on any stack with a
+/// reader in it. A `ReaderT` over another reader only exposes its own environment, since the two
+/// instances would overlap.
+///
+/// Instances should satisfy:
+/// - `Self::reader(f).local(g) == Self::reader(|r| f(g(r)))`
+/// - `Self::pure(a).local(g) == Self::pure(a)`
+pub trait MonadReader<R>: Monad {
+    /// Computes a value from the environment.
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> Self::With<X>;
+
+    /// Runs `self` in an environment modified by `f`.
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self;
+
+    /// Returns the whole environment.
+    fn ask() -> Self::With<R>
+    where
+        R: 'static,
+    {
+        Self::reader(|r| r)
+    }
+
+    /// Returns a projection of the environment.
+    fn asks<X: 'static>(f: impl FnOnce(&R) -> X + 'static) -> Self::With<X> {
+        Self::reader(move |r| f(&r))
+    }
+}
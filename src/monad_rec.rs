@@ -0,0 +1,59 @@
+//! Monads with stack-safe loops.
+
+use crate::either::Either;
+use crate::monad::Monad;
+
+/// A [`Monad`] that can run a loop of binds in constant stack space.
+///
+/// Writing a monadic loop as a function that binds to a call of itself nests one `bind` per
+/// iteration, which for strict monads like `Option` grows the stack until it overflows.
+/// [`tail_rec_m`](MonadRec::tail_rec_m) takes the body of the loop instead, with `f` returning
+/// `Either::Left` with the next state to go round again, or `Either::Right` with the result to
+/// stop.
+///
+/// Instances should agree with the recursive definition:
+/// - `Self::tail_rec_m(a, f) == f(a).bind(|e| match e { Left(a) => Self::tail_rec_m(a, f), Right(b) => Self::pure(b) })`
+pub trait MonadRec: Monad {
+    fn tail_rec_m<A: 'static, B: 'static>(
+        init: A,
+        f: impl FnMut(A) -> Self::With<Either<A, B>> + 'static,
+    ) -> Self::With<B>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Collatz steps until reaching 1, failing if the count passes `limit`.
+    fn collatz(n: u64, limit: u32) -> Option<u32> {
+        Option::<()>::tail_rec_m((n, 0), move |(n, steps)| {
+            if steps > limit {
+                None
+            } else if n == 1 {
+                Some(Either::Right(steps))
+            } else if n % 2 == 1 {
+                Some(Either::Left((3 * n + 1, steps + 1)))
+            } else {
+                Some(Either::Left((n / 2, steps + 1)))
+            }
+        })
+    }
+
+    #[test]
+    fn loops_until_done() {
+        assert_eq!(collatz(27, 1000), Some(111));
+        assert_eq!(collatz(27, 100), None);
+    }
+
+    #[test]
+    fn many_iterations_in_constant_stack() {
+        let count = Option::<()>::tail_rec_m(0u32, |n| {
+            Some(if n == 1_000_000 {
+                Either::Right(n)
+            } else {
+                Either::Left(n + 1)
+            })
+        });
+        assert_eq!(count, Some(1_000_000));
+    }
+}
@@ -0,0 +1,45 @@
+//! Monads that thread a state through their computations.
+
+use crate::monad::Monad;
+
+/// A [`Monad`] with a state of type `S` that each computation can read and replace.
+///
+/// [`State`](crate::state::State), [`RWS`](crate::rws::RWS) and
+/// [`StateT`](crate::trans::StateT) provide one, and the other transformers pass it through from
+/// their base monads, so code written against `M: MonadState<S>` runs on any stack with a state
+/// in it. A `StateT` over another state only exposes its own, since the two instances would
+/// overlap.
+///
+/// Instances should satisfy:
+/// - `Self::state(|s| (a, s)) == Self::pure(a)`
+/// - `Self::state(f).bind(|a| Self::state(g(a))) == Self::state(|s| { let (a, s) = f(s); g(a)(s) })`
+pub trait MonadState<S>: Monad {
+    /// Runs `f` on the current state, which it replaces, returning the value `f` computed.
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> Self::With<X>;
+
+    /// Returns (a copy of) the current state.
+    fn get() -> Self::With<S>
+    where
+        S: Clone + 'static,
+    {
+        Self::state(|s: S| (s.clone(), s))
+    }
+
+    /// Replaces the state.
+    fn put(new: S) -> Self::With<()>
+    where
+        S: 'static,
+    {
+        Self::state(move |_| ((), new))
+    }
+
+    /// Updates the state with `f`.
+    fn modify(f: impl FnOnce(S) -> S + 'static) -> Self::With<()> {
+        Self::state(move |s| ((), f(s)))
+    }
+
+    /// Returns a projection of the state.
+    fn gets<X: 'static>(f: impl FnOnce(&S) -> X + 'static) -> Self::With<X> {
+        Self::state(move |s| (f(&s), s))
+    }
+}
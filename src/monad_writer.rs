@@ -0,0 +1,35 @@
+//! Monads that accumulate an output.
+
+use crate::monad::Monad;
+
+/// A [`Monad`] that accumulates an output of type `W` alongside its values.
+///
+/// [`Writer`](crate::writer::Writer), [`RWS`](crate::rws::RWS) and
+/// [`WriterT`](crate::trans::WriterT) provide one, and the other transformers pass it through
+/// from their base monads, so code written against `M: MonadWriter<W>` runs on any stack with a
+/// writer in it. A `WriterT` over another writer only exposes its own output, since the two
+/// instances would overlap.
+///
+/// `censor` takes an `FnMut` because a base monad like `Vec` has an output for each of its
+/// results.
+///
+/// Instances should satisfy:
+/// - `Self::writer(a, w).listen() == Self::writer((a, w.clone()), w)`
+/// - `Self::writer(a, w).censor(f) == Self::writer(a, f(w))`
+pub trait MonadWriter<W>: Monad {
+    /// Produces `val`, writing `output`.
+    fn writer<X: 'static>(val: X, output: W) -> Self::With<X>;
+
+    /// Returns the value alongside a copy of what `self` wrote.
+    fn listen(self) -> Self::With<(Self::Inner, W)>
+    where
+        W: Clone;
+
+    /// Rewrites what `self` wrote with `f`.
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self;
+
+    /// Writes `output` without producing a value.
+    fn tell(output: W) -> Self::With<()> {
+        Self::writer((), output)
+    }
+}
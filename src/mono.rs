@@ -0,0 +1,97 @@
+//! Mapping and folding containers whose element type is fixed.
+
+use crate::foldable::Foldable;
+use crate::functor::Functor;
+use crate::kind::K1;
+use crate::monoid::Monoid;
+
+/// A container of `Element`s that isn't generic over them, like a `String` of `char`s.
+///
+/// Every [`K1`] is one, of its `Inner` type, so code written against [`MonoFunctor`] and
+/// [`MonoFoldable`] covers `Vec<u8>` and `String` alike.
+pub trait MonoKind {
+    type Element;
+}
+
+impl<T: K1> MonoKind for T {
+    type Element = T::Inner;
+}
+
+/// A [`Functor`] that can only map its elements to elements of the same type.
+///
+/// Every `Functor` whose mapped-to-itself type is `Self` is one. Instances should satisfy the
+/// `Functor` laws, restricted to functions from `Element` to `Element`.
+pub trait MonoFunctor: MonoKind {
+    fn omap(self, f: impl FnMut(Self::Element) -> Self::Element + 'static) -> Self;
+}
+
+impl<T> MonoFunctor for T
+where
+    T: Functor<With<<T as K1>::Inner> = T>,
+    T::Inner: 'static,
+{
+    fn omap(self, f: impl FnMut(T::Inner) -> T::Inner + 'static) -> T {
+        self.fmap(f)
+    }
+}
+
+/// A [`Foldable`] with a fixed element type. Every `Foldable` is one.
+pub trait MonoFoldable: MonoKind {
+    fn ofold_left<B>(self, init: B, f: impl FnMut(B, Self::Element) -> B) -> B;
+
+    fn ofold_right<B>(self, init: B, f: impl FnMut(Self::Element, B) -> B) -> B;
+
+    fn ofold_map<M: Monoid>(self, mut f: impl FnMut(Self::Element) -> M) -> M
+    where
+        Self: Sized,
+    {
+        self.ofold_left(M::empty(), |acc, a| acc.combine(f(a)))
+    }
+
+    fn olength(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.ofold_left(0, |n, _| n + 1)
+    }
+}
+
+impl<T: Foldable> MonoFoldable for T {
+    fn ofold_left<B>(self, init: B, f: impl FnMut(B, T::Inner) -> B) -> B {
+        self.fold_left(init, f)
+    }
+
+    fn ofold_right<B>(self, init: B, f: impl FnMut(T::Inner, B) -> B) -> B {
+        self.fold_right(init, f)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    // The same function works for the parameterized containers and the fixed ones.
+    fn shout<T>(text: T) -> T
+    where
+        T: MonoFunctor<Element = char>,
+    {
+        text.omap(|c| c.to_ascii_uppercase())
+    }
+
+    #[test]
+    fn maps_either_kind_of_container() {
+        assert_eq!(shout(String::from("hey")), "HEY");
+        assert_eq!(shout(vec!['a', 'b']), ['A', 'B']);
+        assert_eq!(vec![1u8, 2].omap(|b| b * 2), [2, 4]);
+    }
+
+    #[test]
+    fn folds_either_kind_of_container() {
+        assert_eq!("héllo".olength(), 5);
+        assert_eq!(
+            String::from("abc").ofold_right(String::new(), |c, s| s + &c.to_string()),
+            "cba"
+        );
+        assert_eq!(vec![1u8, 2].ofold_map(|b| vec![b, b]), [1, 1, 2, 2]);
+    }
+}
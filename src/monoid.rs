@@ -0,0 +1,536 @@
+//! Types with an associative combining operation.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, Mul};
+
+// Just the module: with `Alternative` itself in scope, `Option::empty` would be ambiguous with
+// `Monoid::empty`.
+use crate::alternative;
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+#[cfg(feature = "alloc")]
+use crate::invariant::Invariant;
+use crate::kind::K1;
+
+/// A type with an associative binary operation:
+/// `a.combine(b).combine(c) == a.combine(b.combine(c))`.
+pub trait Semigroup {
+    fn combine(self, other: Self) -> Self;
+}
+
+/// A [`Semigroup`] with an identity element:
+/// `Self::empty().combine(a) == a == a.combine(Self::empty())`.
+pub trait Monoid: Semigroup {
+    fn empty() -> Self;
+
+    /// Combines every item from left to right, starting from `empty`.
+    fn concat(items: impl IntoIterator<Item = Self>) -> Self
+    where
+        Self: Sized,
+    {
+        items.into_iter().fold(Self::empty(), Self::combine)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Semigroup for String {
+    fn combine(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Monoid for String {
+    fn empty() -> Self {
+        String::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Semigroup for Vec<T> {
+    fn combine(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Monoid for Vec<T> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+}
+
+/// Combines the contents when both are `Some`, and otherwise keeps whichever one is. This turns
+/// any semigroup into a monoid with `None` as the identity.
+impl<S: Semigroup> Semigroup for Option<S> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+impl<S: Semigroup> Monoid for Option<S> {
+    fn empty() -> Self {
+        None
+    }
+}
+
+impl Semigroup for () {
+    fn combine(self, (): ()) {}
+}
+
+impl Monoid for () {
+    fn empty() {}
+}
+
+impl<A: Semigroup, B: Semigroup> Semigroup for (A, B) {
+    fn combine(self, other: Self) -> Self {
+        (self.0.combine(other.0), self.1.combine(other.1))
+    }
+}
+
+impl<A: Monoid, B: Monoid> Monoid for (A, B) {
+    fn empty() -> Self {
+        (A::empty(), B::empty())
+    }
+}
+
+/// Lexicographic: the first non-`Equal` ordering wins.
+impl Semigroup for Ordering {
+    fn combine(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl Monoid for Ordering {
+    fn empty() -> Self {
+        Ordering::Equal
+    }
+}
+
+/// Numbers combined with `+`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<T>(pub T);
+
+/// Numbers combined with `*`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Product<T>(pub T);
+
+impl<T: Add<Output = T>> Semigroup for Sum<T> {
+    fn combine(self, other: Self) -> Self {
+        Sum(self.0 + other.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Semigroup for Product<T> {
+    fn combine(self, other: Self) -> Self {
+        Product(self.0 * other.0)
+    }
+}
+
+// std has no `Zero`/`One` traits, so the identities are spelled out per primitive.
+macro_rules! numeric_monoids {
+    ($zero:literal, $one:literal => $($t:ty),*) => {
+        $(
+            impl Monoid for Sum<$t> {
+                fn empty() -> Self {
+                    Sum($zero)
+                }
+            }
+
+            impl Monoid for Product<$t> {
+                fn empty() -> Self {
+                    Product($one)
+                }
+            }
+        )*
+    };
+}
+
+numeric_monoids!(0, 1 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+numeric_monoids!(0.0, 1.0 => f32, f64);
+
+/// Values combined by keeping the smaller one, or the first of two equal ones.
+///
+/// The identity is the largest value, so the integers and `char` are monoids, but the floats,
+/// which aren't `Ord`, aren't even semigroups.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<T>(pub T);
+
+/// Values combined by keeping the larger one, or the last of two equal ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<T>(pub T);
+
+impl<T: Ord> Semigroup for Min<T> {
+    fn combine(self, other: Self) -> Self {
+        Min(self.0.min(other.0))
+    }
+}
+
+impl<T: Ord> Semigroup for Max<T> {
+    fn combine(self, other: Self) -> Self {
+        Max(self.0.max(other.0))
+    }
+}
+
+macro_rules! bounded_monoids {
+    ($($t:ty),*) => {
+        $(
+            impl Monoid for Min<$t> {
+                fn empty() -> Self {
+                    Min(<$t>::MAX)
+                }
+            }
+
+            impl Monoid for Max<$t> {
+                fn empty() -> Self {
+                    Max(<$t>::MIN)
+                }
+            }
+        )*
+    };
+}
+
+bounded_monoids!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char);
+
+/// The first `Some`, if there is one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct First<T>(pub Option<T>);
+
+/// The last `Some`, if there is one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Last<T>(pub Option<T>);
+
+impl<T> Semigroup for First<T> {
+    fn combine(self, other: Self) -> Self {
+        First(self.0.or(other.0))
+    }
+}
+
+impl<T> Monoid for First<T> {
+    fn empty() -> Self {
+        First(None)
+    }
+}
+
+impl<T> Semigroup for Last<T> {
+    fn combine(self, other: Self) -> Self {
+        Last(other.0.or(self.0))
+    }
+}
+
+impl<T> Monoid for Last<T> {
+    fn empty() -> Self {
+        Last(None)
+    }
+}
+
+/// A monoid combined the other way round: `Dual(a).combine(Dual(b)) == Dual(b.combine(a))`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dual<M>(pub M);
+
+impl<M: Semigroup> Semigroup for Dual<M> {
+    fn combine(self, other: Self) -> Self {
+        Dual(other.0.combine(self.0))
+    }
+}
+
+impl<M: Monoid> Monoid for Dual<M> {
+    fn empty() -> Self {
+        Dual(M::empty())
+    }
+}
+
+/// A function from `A` to `A`, combined by composition: `a.combine(b)` runs `b` and then `a`,
+/// like `a ∘ b`, and the identity function is `empty`.
+///
+/// Folding into `Endo`s is how a right fold is written with
+/// [`fold_map`](crate::foldable::Foldable::fold_map), by turning each item into the step that
+/// folds it in and then applying the whole chain to the initial value:
+/// `xs.fold_map(|x| Endo::new(move |acc| f(x, acc))).apply(init)`. Wrapping the steps in
+/// [`Dual`] gives the left fold instead.
+///
+/// Each function runs at most once.
+#[cfg(feature = "alloc")]
+pub struct Endo<A>(pub Box<dyn FnOnce(A) -> A>);
+
+#[cfg(feature = "alloc")]
+impl<A> Endo<A> {
+    pub fn new(f: impl FnOnce(A) -> A + 'static) -> Self {
+        Endo(Box::new(f))
+    }
+
+    pub fn apply(self, a: A) -> A {
+        (self.0)(a)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Semigroup for Endo<A> {
+    fn combine(self, other: Self) -> Self {
+        Endo::new(move |a| self.apply(other.apply(a)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Monoid for Endo<A> {
+    fn empty() -> Self {
+        Endo::new(|a| a)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A> K1 for Endo<A> {
+    type Inner = A;
+
+    type With<I> = Endo<I>;
+}
+
+/// An `Endo` takes an `A` as well as returning one, so it's neither a `Functor` nor
+/// `Contravariant`: changing it to `B`s converts the argument back to an `A` with `g`, and the
+/// result forward with `f`.
+#[cfg(feature = "alloc")]
+impl<A: 'static> Invariant for Endo<A> {
+    fn invmap<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> B + 'static,
+        mut g: impl FnMut(B) -> A + 'static,
+    ) -> Endo<B> {
+        Endo::new(move |b| f(self.apply(g(b))))
+    }
+}
+
+/// An applicative of a monoid, combined by running both effects and combining the results:
+/// `Ap(Some(a)).combine(Ap(Some(b))) == Ap(Some(a.combine(b)))`, with `empty` being
+/// [`pure`](Applicative::pure) of the inner monoid's `empty`.
+///
+/// This lets [`fold_map`](crate::foldable::Foldable::fold_map) aggregate effectful values, like
+/// summing `Option`s where any `None` makes the whole sum `None`.
+///
+/// It's only a lawful monoid when `F` is a lawful applicative: `empty` is only an identity if
+/// `pure` is one for `zip_with`. That rules out the zipping sequences, like `Vec`, whose `pure`
+/// gives a single element, so `Ap(vec![a, b]).combine(Ap::empty())` loses `b`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Ap<F>(pub F);
+
+impl<F> Semigroup for Ap<F>
+where
+    F: Applicative<With<<F as K1>::Inner> = F>,
+    F::Inner: Semigroup + 'static,
+{
+    fn combine(self, other: Self) -> Self {
+        Ap(self.0.zip_with(other.0, Semigroup::combine))
+    }
+}
+
+impl<F> Monoid for Ap<F>
+where
+    F: Applicative<With<<F as K1>::Inner> = F>,
+    F::Inner: Monoid + 'static,
+{
+    fn empty() -> Self {
+        Ap(F::pure(F::Inner::empty()))
+    }
+}
+
+/// An [`Alternative`](alternative::Alternative) combined by choice:
+/// `Alt(a).combine(Alt(b)) == Alt(a.or(b))`, with
+/// [`Alternative::empty`](alternative::Alternative::empty) as the identity.
+///
+/// Folding into `Alt` picks out the alternatives, like the first `Some`, whatever the inner type
+/// is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Alt<F>(pub F);
+
+impl<F: alternative::Alternative> Semigroup for Alt<F> {
+    fn combine(self, other: Self) -> Self {
+        Alt(self.0.or(other.0))
+    }
+}
+
+impl<F> Monoid for Alt<F>
+where
+    F: alternative::Alternative<With<<F as K1>::Inner> = F>,
+    F::Inner: 'static,
+{
+    fn empty() -> Self {
+        Alt(F::empty::<F::Inner>())
+    }
+}
+
+// Each wrapper is a `Functor` over the value it wraps, the `Option`s' values for `First` and
+// `Last`.
+macro_rules! wrapper_functors {
+    ($($wrapper:ident($map:expr)),*) => {
+        $(
+            impl<T> K1 for $wrapper<T> {
+                type Inner = T;
+
+                type With<I> = $wrapper<I>;
+            }
+
+            impl<A> Functor for $wrapper<A> {
+                fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> $wrapper<B> {
+                    $wrapper($map(self.0, f))
+                }
+            }
+
+            impl<A> FunctorWith for $wrapper<A> {
+                fn fmap_with<X: 'static, Y: 'static>(
+                    fx: $wrapper<X>,
+                    f: impl FnMut(X) -> Y + 'static,
+                ) -> $wrapper<Y> {
+                    fx.fmap(f)
+                }
+            }
+        )*
+    };
+}
+
+fn apply<A, B>(a: A, mut f: impl FnMut(A) -> B) -> B {
+    f(a)
+}
+
+wrapper_functors!(
+    Sum(apply),
+    Product(apply),
+    Min(apply),
+    Max(apply),
+    First(Option::map),
+    Last(Option::map),
+    Dual(apply)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_lifts_semigroup() {
+        assert_eq!(Some(Sum(1)).combine(Some(Sum(2))), Some(Sum(3)));
+        assert_eq!(Some(Sum(1)).combine(None), Some(Sum(1)));
+        assert_eq!(Option::<Sum<i32>>::empty(), None);
+    }
+
+    #[test]
+    fn numeric_wrappers() {
+        assert_eq!(Sum::concat([1, 2, 3].map(Sum)), Sum(6));
+        assert_eq!(Product::concat([2.0, 3.0].map(Product)), Product(6.0));
+        assert_eq!(Product::<u8>::concat([]), Product(1));
+    }
+
+    #[test]
+    fn ordered_wrappers() {
+        assert_eq!(Min::concat([3, 1, 2].map(Min)), Min(1));
+        assert_eq!(Max::concat([3, 1, 2].map(Max)), Max(3));
+        assert_eq!(Min::<u8>::empty(), Min(255));
+        assert_eq!(Max::concat("hello".chars().map(Max)), Max('o'));
+    }
+
+    #[test]
+    fn first_and_last() {
+        let found = [None, Some(1), None, Some(2), None].map(First);
+        assert_eq!(First::concat(found), First(Some(1)));
+        assert_eq!(Last::concat(found.map(|f| Last(f.0))), Last(Some(2)));
+        assert_eq!(First::<()>::concat([]), First(None));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fold_map_with_wrappers() {
+        use crate::foldable::Foldable;
+
+        let words = vec!["apple", "fig", "banana"];
+        assert_eq!(words.clone().fold_map(|w| Sum(w.len())), Sum(14));
+        assert_eq!(words.clone().fold_map(|w| Min(w.len())), Min(3));
+        assert_eq!(
+            words.fold_map(|w| First(w.starts_with('b').then_some(w))),
+            First(Some("banana"))
+        );
+        assert_eq!(Max(2).fmap(|n| n * 10), Max(20));
+        assert_eq!(Last(Some(2)).fmap(|n| n + 1), Last(Some(3)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dual_flips_the_order() {
+        let joined = Dual(String::from("a")).combine(Dual(String::from("b")));
+        assert_eq!(joined, Dual(String::from("ba")));
+        assert_eq!(
+            Dual::concat([vec![1], vec![2, 3]].map(Dual)),
+            Dual(vec![2, 3, 1])
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn endo_composes() {
+        let f = Endo::new(|n: i32| n + 1).combine(Endo::new(|n| n * 10));
+        assert_eq!(f.apply(2), 21);
+        assert_eq!(Endo::<i32>::empty().apply(7), 7);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn folds_through_endo() {
+        use crate::foldable::Foldable;
+
+        let digits = vec![1, 2, 3];
+        let right = digits
+            .clone()
+            .fold_map(|d| Endo::new(move |acc: Vec<i32>| [vec![d], acc].concat()));
+        assert_eq!(right.apply(vec![0]), [1, 2, 3, 0]);
+        let left = digits.fold_map(|d| Dual(Endo::new(move |acc: i32| acc * 10 + d)));
+        assert_eq!(left.0.apply(0), 123);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ap_combines_inside_the_effect() {
+        use crate::foldable::Foldable;
+
+        let parse = |s: &str| s.parse::<i32>().ok().map(Sum);
+        let sum = vec!["1", "2", "3"].fold_map(|s| Ap(parse(s)));
+        assert_eq!(sum, Ap(Some(Sum(6))));
+        assert_eq!(vec!["1", "x"].fold_map(|s| Ap(parse(s))), Ap(None));
+        assert_eq!(Ap::<Option<Sum<i32>>>::empty(), Ap(Some(Sum(0))));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn alt_picks_an_alternative() {
+        use crate::foldable::Foldable;
+
+        let found = vec!["x", "2", "3"].fold_map(|s| Alt(s.parse::<i32>().ok()));
+        assert_eq!(found, Alt(Some(2)));
+        assert_eq!(Alt::<Option<i32>>::empty(), Alt(None));
+        assert_eq!(Alt(vec![1]).combine(Alt(vec![2, 3])), Alt(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn ordering_is_lexicographic() {
+        let cmp = |a: (i32, &str), b: (i32, &str)| a.0.cmp(&b.0).combine(a.1.cmp(b.1));
+        assert_eq!(cmp((1, "b"), (1, "a")), Ordering::Greater);
+        assert_eq!(cmp((0, "b"), (1, "a")), Ordering::Less);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn tuples_combine_pointwise() {
+        let a = (String::from("a"), vec![1]);
+        let b = (String::from("b"), vec![2]);
+        assert_eq!(a.combine(b), (String::from("ab"), vec![1, 2]));
+        assert_eq!(<((), String)>::empty(), ((), String::new()));
+    }
+}
@@ -0,0 +1,144 @@
+//! Natural transformations between type constructors.
+
+use core::marker::PhantomData;
+
+use crate::kind::K1;
+
+/// A natural transformation from `F` to `G`: a way of turning an `F<A>` into a `G<A>` for every
+/// `A`, without looking at the `A`s.
+///
+/// `F` and `G` are witnesses, conventionally the constructors applied to `()`. Closures can't be
+/// generic over `A`, so transformations are written as types:
+///
+/// ```
+/// use higher_kinded::nat::NatTrans;
+/// use higher_kinded::result::ResultOk;
+///
+/// struct Required;
+///
+/// impl NatTrans<Option<()>, ResultOk<(), &'static str>> for Required {
+///     fn apply<A: 'static>(&mut self, fa: Option<A>) -> ResultOk<A, &'static str> {
+///         ResultOk(fa.ok_or("missing"))
+///     }
+/// }
+///
+/// assert_eq!(Required.apply(Some(1)), ResultOk(Ok(1)));
+/// assert_eq!(Required.apply(None::<&str>), ResultOk(Err("missing")));
+/// ```
+pub trait NatTrans<F: K1, G: K1> {
+    fn apply<A: 'static>(&mut self, fa: F::With<A>) -> G::With<A>;
+
+    /// Runs `self`, then `next` on the result.
+    fn and_then<H: K1, N: NatTrans<G, H>>(self, next: N) -> AndThen<Self, N, G>
+    where
+        Self: Sized,
+    {
+        AndThen {
+            first: self,
+            second: next,
+            via: PhantomData,
+        }
+    }
+}
+
+impl<F: K1, G: K1, N: NatTrans<F, G> + ?Sized> NatTrans<F, G> for &mut N {
+    fn apply<A: 'static>(&mut self, fa: F::With<A>) -> G::With<A> {
+        (**self).apply(fa)
+    }
+}
+
+/// The natural transformation that leaves every `F<A>` alone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NatId;
+
+impl<F: K1> NatTrans<F, F> for NatId {
+    fn apply<A: 'static>(&mut self, fa: F::With<A>) -> F::With<A> {
+        fa
+    }
+}
+
+/// Two natural transformations run one after the other, going through `G`. Made by
+/// [`NatTrans::and_then`].
+pub struct AndThen<N, M, G> {
+    first: N,
+    second: M,
+    via: PhantomData<fn() -> G>,
+}
+
+impl<N: Clone, M: Clone, G> Clone for AndThen<N, M, G> {
+    fn clone(&self) -> Self {
+        AndThen {
+            first: self.first.clone(),
+            second: self.second.clone(),
+            via: PhantomData,
+        }
+    }
+}
+
+impl<F: K1, G: K1, H: K1, N, M> NatTrans<F, H> for AndThen<N, M, G>
+where
+    N: NatTrans<F, G>,
+    M: NatTrans<G, H>,
+{
+    fn apply<A: 'static>(&mut self, fa: F::With<A>) -> H::With<A> {
+        let ga = self.first.apply::<A>(fa);
+        self.second.apply::<A>(ga)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::either::Either;
+
+    struct Head;
+
+    impl NatTrans<Vec<()>, Option<()>> for Head {
+        fn apply<A: 'static>(&mut self, fa: Vec<A>) -> Option<A> {
+            fa.into_iter().next()
+        }
+    }
+
+    struct OrElse<E>(E);
+
+    impl<E: Clone> NatTrans<Option<()>, Either<E, ()>> for OrElse<E> {
+        fn apply<A: 'static>(&mut self, fa: Option<A>) -> Either<E, A> {
+            fa.map_or_else(|| Either::Left(self.0.clone()), Either::Right)
+        }
+    }
+
+    struct Counted(usize);
+
+    impl NatTrans<Option<()>, Either<(), ()>> for Counted {
+        fn apply<A: 'static>(&mut self, fa: Option<A>) -> Either<(), A> {
+            self.0 += 1;
+            fa.map_or(Either::Left(()), Either::Right)
+        }
+    }
+
+    #[test]
+    fn composes_left_to_right() {
+        let mut nt = Head.and_then(OrElse("empty"));
+        assert_eq!(nt.apply(vec![1, 2]), Either::Right(1));
+        assert_eq!(nt.apply(Vec::<char>::new()), Either::Left("empty"));
+    }
+
+    #[test]
+    fn identity_is_a_unit() {
+        let mut nt = NatId.and_then(Head).and_then(NatId);
+        assert_eq!(nt.apply(vec!["a"]), Some("a"));
+        assert_eq!(
+            NatTrans::<Vec<()>, Vec<()>>::apply(&mut NatId, vec![1]),
+            [1]
+        );
+    }
+
+    #[test]
+    fn keeps_state_across_calls() {
+        let mut counted = Counted(0);
+        let mut nt = Head.and_then(&mut counted);
+        assert_eq!(nt.apply(vec![1]), Either::Right(1));
+        assert_eq!(nt.apply(Vec::<()>::new()), Either::Left(()));
+        assert_eq!(counted.0, 2);
+    }
+}
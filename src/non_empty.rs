@@ -0,0 +1,245 @@
+//! Vectors with at least one element.
+
+use alloc::vec::{self, Vec};
+use core::iter;
+
+use crate::applicative::Applicative;
+use crate::comonad::Comonad;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monoid::Semigroup;
+use crate::traversable::Traversable;
+
+/// A `Vec` that is never empty: a `head` plus any number of further elements.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyVec<T> {
+    head: T,
+    tail: Vec<T>,
+}
+
+impl<T> NonEmptyVec<T> {
+    pub fn new(head: T, tail: Vec<T>) -> Self {
+        NonEmptyVec { head, tail }
+    }
+
+    pub fn singleton(head: T) -> Self {
+        NonEmptyVec::new(head, Vec::new())
+    }
+
+    /// Returns `None` if `vec` is empty.
+    pub fn from_vec(mut vec: Vec<T>) -> Option<Self> {
+        if vec.is_empty() {
+            return None;
+        }
+        let tail = vec.split_off(1);
+        Some(NonEmptyVec::new(vec.pop()?, tail))
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len());
+        vec.push(self.head);
+        vec.extend(self.tail);
+        vec
+    }
+
+    pub fn head(&self) -> &T {
+        &self.head
+    }
+
+    /// Everything after the head, which may be empty.
+    pub fn tail(&self) -> &[T] {
+        &self.tail
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        iter::once(&self.head).chain(&self.tail)
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    pub fn push(&mut self, val: T) {
+        self.tail.push(val);
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.into_vec()
+    }
+}
+
+/// Hands the vector back if it's empty.
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = Vec<T>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        if vec.is_empty() {
+            Err(vec)
+        } else {
+            Ok(NonEmptyVec::from_vec(vec).expect("vector is not empty"))
+        }
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = iter::Chain<iter::Once<T>, vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.head).chain(self.tail)
+    }
+}
+
+/// Concatenation, like `Vec`'s instance. There's no `Monoid`, since the empty vector is exactly
+/// what this type rules out.
+impl<T> Semigroup for NonEmptyVec<T> {
+    fn combine(mut self, other: Self) -> Self {
+        self.tail.push(other.head);
+        self.tail.extend(other.tail);
+        self
+    }
+}
+
+impl<T> K1 for NonEmptyVec<T> {
+    type Inner = T;
+
+    type With<I> = NonEmptyVec<I>;
+}
+
+impl<A> Functor for NonEmptyVec<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> NonEmptyVec<B> {
+        let head = f(self.head);
+        NonEmptyVec::new(head, self.tail.into_iter().map(f).collect())
+    }
+}
+
+impl<A> FunctorWith for NonEmptyVec<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: NonEmptyVec<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> NonEmptyVec<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorRef for NonEmptyVec<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> NonEmptyVec<B> {
+        let head = f(&self.head);
+        NonEmptyVec::new(head, self.tail.iter().map(f).collect())
+    }
+}
+
+/// Zips, for the reasons given on [`ZipVec`](crate::zip_vec::ZipVec). The heads are always
+/// paired, so the result is never empty.
+impl<A> Applicative for NonEmptyVec<A> {
+    fn pure<X: 'static>(val: X) -> NonEmptyVec<X> {
+        NonEmptyVec::singleton(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: NonEmptyVec<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> NonEmptyVec<C> {
+        let head = f(self.head, b.head);
+        let tail = self.tail.into_iter().zip(b.tail);
+        NonEmptyVec::new(head, tail.map(|(a, b)| f(a, b)).collect())
+    }
+}
+
+impl<A> Foldable for NonEmptyVec<A> {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, A) -> B) -> B {
+        self.into_iter().fold(init, f)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        let init = self.tail.into_iter().rev().fold(init, |acc, a| f(a, acc));
+        f(self.head, init)
+    }
+}
+
+impl<A> Traversable for NonEmptyVec<A> {
+    fn traverse<F, B: 'static>(self, mut f: impl FnMut(A) -> F) -> F::With<NonEmptyVec<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        let head = f(self.head);
+        head.zip_with(self.tail.traverse(f), NonEmptyVec::new)
+    }
+}
+
+/// The focus is the head, and `extend` runs `f` on every suffix, longest first. Each suffix
+/// after the first is a copy, hence `A: Clone`.
+impl<A: Clone> Comonad for NonEmptyVec<A> {
+    fn extract(self) -> A {
+        self.head
+    }
+
+    fn extend<B: 'static>(
+        self,
+        mut f: impl FnMut(NonEmptyVec<A>) -> B + 'static,
+    ) -> NonEmptyVec<B> {
+        let mut rest = NonEmptyVec::from_vec(self.tail.clone());
+        let head = f(self);
+        let mut tail = Vec::new();
+        while let Some(suffix) = rest {
+            rest = NonEmptyVec::from_vec(suffix.tail.clone());
+            tail.push(f(suffix));
+        }
+        NonEmptyVec::new(head, tail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_round_trip() {
+        assert_eq!(NonEmptyVec::<i32>::from_vec(vec![]), None);
+        let v = NonEmptyVec::from_vec(vec![1, 2, 3]).unwrap();
+        assert_eq!((*v.head(), v.len()), (1, 3));
+        assert_eq!(
+            v.combine(NonEmptyVec::singleton(4)).into_vec(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(NonEmptyVec::try_from(Vec::<i32>::new()), Err(vec![]));
+        let v = NonEmptyVec::try_from(vec![5, 6]).unwrap();
+        assert_eq!(v.tail(), [6]);
+        assert_eq!(v.iter().sum::<i32>(), 11);
+    }
+
+    #[test]
+    fn functor_applicative_foldable() {
+        let v = NonEmptyVec::new(1, vec![2, 3]);
+        assert_eq!(v.fmap_ref(|x| x * 2).into_vec(), [2, 4, 6]);
+        let zipped = v
+            .clone()
+            .zip_with(NonEmptyVec::new(10, vec![20]), |a, b| a + b);
+        assert_eq!(zipped.into_vec(), [11, 22]);
+        assert_eq!(v.clone().fold_right(0, |a, acc| acc * 10 + a), 321);
+        assert_eq!(v.fold_left(0, |acc, a| acc * 10 + a), 123);
+    }
+
+    #[test]
+    fn traverse_in_order() {
+        let v = NonEmptyVec::new("1", vec!["2", "3"]);
+        let parsed = v.clone().traverse(|s| s.parse::<i32>().ok());
+        assert_eq!(parsed, Some(NonEmptyVec::new(1, vec![2, 3])));
+        let v = NonEmptyVec::new("1", vec!["x"]);
+        assert_eq!(v.traverse(|s| s.parse::<i32>().ok()), None);
+    }
+
+    #[test]
+    fn extend_sees_suffixes() {
+        let v = NonEmptyVec::new(1, vec![2, 3]);
+        assert_eq!(v.clone().extract(), 1);
+        let sums = v.clone().extend(|w| w.into_iter().sum::<i32>());
+        assert_eq!(sums.into_vec(), [6, 5, 3]);
+        assert_eq!(v.duplicate().fmap(|w| w.len()).into_vec(), [3, 2, 1]);
+    }
+}
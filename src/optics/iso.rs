@@ -0,0 +1,133 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+
+use crate::constant::Const;
+use crate::identity::Identity;
+use crate::optics::{Lens, Prism};
+
+/// A lossless conversion between `S` and `A`.
+///
+/// An iso is both a [`Lens`] whose focus is the whole value and a [`Prism`] that always matches,
+/// and converts into either with `From` to compose with them.
+pub struct Iso<S, A> {
+    to: Rc<dyn Fn(S) -> A>,
+    from: Rc<dyn Fn(A) -> S>,
+}
+
+impl<S, A> Clone for Iso<S, A> {
+    fn clone(&self) -> Self {
+        Iso {
+            to: self.to.clone(),
+            from: self.from.clone(),
+        }
+    }
+}
+
+impl<S: 'static, A: 'static> Iso<S, A> {
+    /// `from` should undo `to`, and the other way around.
+    pub fn new(to: impl Fn(S) -> A + 'static, from: impl Fn(A) -> S + 'static) -> Self {
+        Iso {
+            to: Rc::new(to),
+            from: Rc::new(from),
+        }
+    }
+
+    /// The iso given by a pair of `From` impls.
+    pub fn from_into() -> Self
+    where
+        S: Into<A>,
+        A: Into<S>,
+    {
+        Iso::new(S::into, A::into)
+    }
+
+    pub fn to(&self, s: S) -> A {
+        (self.to)(s)
+    }
+
+    pub fn from(&self, a: A) -> S {
+        (self.from)(a)
+    }
+
+    /// Updates `s` by converting it, running `f`, and converting back.
+    pub fn over(&self, s: S, f: impl FnOnce(A) -> A) -> S {
+        self.from(f(self.to(s)))
+    }
+
+    /// The same conversion in the other direction.
+    pub fn reverse(&self) -> Iso<A, S> {
+        Iso {
+            to: self.from.clone(),
+            from: self.to.clone(),
+        }
+    }
+
+    pub fn compose<B: 'static>(&self, inner: &Iso<A, B>) -> Iso<S, B> {
+        let (outer, inner) = (self.clone(), inner.clone());
+        let (outer_from, inner_from) = (outer.clone(), inner.clone());
+        Iso::new(
+            move |s| inner.to(outer.to(s)),
+            move |b| outer_from.from(inner_from.from(b)),
+        )
+    }
+}
+
+impl<S: 'static, A: 'static> From<Iso<S, A>> for Lens<S, A> {
+    fn from(iso: Iso<S, A>) -> Self {
+        Lens::from_split(move |s| {
+            let iso = iso.clone();
+            (iso.to(s), Box::new(move |a| iso.from(a)))
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> From<Iso<S, A>> for Prism<S, A> {
+    fn from(iso: Iso<S, A>) -> Self {
+        let (to, from) = (iso.to, iso.from);
+        Prism::new(move |s| Ok(to(s)), move |a| from(a))
+    }
+}
+
+/// Unwraps an [`Identity`].
+pub fn identity<T: 'static>() -> Iso<Identity<T>, T> {
+    Iso::new(|Identity(t)| t, Identity)
+}
+
+/// Unwraps a [`Const`], forgetting its phantom parameter.
+pub fn constant<C: 'static, V: 'static>() -> Iso<Const<C, V>, C> {
+    Iso::new(Const::into_inner, Const::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::{fst, ok};
+    use crate::result::ResultOk;
+
+    #[test]
+    fn converts_both_ways() {
+        let iso = identity::<i32>();
+        assert_eq!(iso.to(Identity(1)), 1);
+        assert_eq!(iso.reverse().to(2), Identity(2));
+        assert_eq!(iso.over(Identity(3), |n| n * 2), Identity(6));
+        assert_eq!(constant::<&str, ()>().from("c").into_inner(), "c");
+    }
+
+    #[test]
+    fn from_into_for_newtypes() {
+        let iso = Iso::<ResultOk<i32, ()>, Result<i32, ()>>::from_into();
+        let nested = iso.compose(&Iso::new(|r: Result<i32, ()>| r.ok(), |o| o.ok_or(())));
+        assert_eq!(nested.to(ResultOk(Ok(1))), Some(1));
+        assert_eq!(nested.from(None), ResultOk(Err(())));
+    }
+
+    #[test]
+    fn composes_with_lenses_and_prisms() {
+        let wrapped = Lens::from(identity::<(i32, char)>()).compose(&fst());
+        assert_eq!(wrapped.set(Identity((1, 'a')), 2), Identity((2, 'a')));
+
+        let wrapped_ok = Prism::from(Iso::<ResultOk<i32, ()>, _>::from_into()).compose(&ok());
+        assert_eq!(wrapped_ok.preview(ResultOk(Ok(4))), Some(4));
+        assert_eq!(wrapped_ok.review(5), ResultOk(Ok(5)));
+    }
+}
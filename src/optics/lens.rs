@@ -0,0 +1,148 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+
+use crate::constant::Const;
+use crate::functor::{Functor, FunctorOnce};
+use crate::identity::Identity;
+use crate::util::once;
+
+// The focus, and a function that puts a new focus back into the whole.
+pub(crate) type Focus<S, A> = (A, Box<dyn FnOnce(A) -> S>);
+
+type Split<S, A> = Rc<dyn Fn(S) -> Focus<S, A>>;
+
+/// Focuses on exactly one `A` inside an `S`, like a struct field.
+///
+/// Lenses are in the van Laarhoven style: [`modify_f`](Lens::modify_f) runs a function on the
+/// focus and rebuilds the whole inside whatever functor it returns. [`view`](Lens::view) is the
+/// same with [`Const`], which never rebuilds, and [`over`](Lens::over) is the same with
+/// [`Identity`]. Those two rebuild at most once, so unlike `modify_f` they don't need to clone
+/// the whole.
+pub struct Lens<S, A> {
+    split: Split<S, A>,
+}
+
+impl<S, A> Clone for Lens<S, A> {
+    fn clone(&self) -> Self {
+        Lens {
+            split: self.split.clone(),
+        }
+    }
+}
+
+impl<S: 'static, A: 'static> Lens<S, A> {
+    pub fn new(get: impl Fn(&S) -> A + 'static, set: impl Fn(S, A) -> S + 'static) -> Self {
+        let set = Rc::new(set);
+        Lens::from_split(move |s| {
+            let set = set.clone();
+            (get(&s), Box::new(move |a| set(s, a)))
+        })
+    }
+
+    pub(crate) fn from_split(split: impl Fn(S) -> Focus<S, A> + 'static) -> Self {
+        Lens {
+            split: Rc::new(split),
+        }
+    }
+
+    pub(crate) fn split(&self, s: S) -> Focus<S, A> {
+        (self.split)(s)
+    }
+
+    /// Runs `f` on the focus and puts each result back into a copy of `s` inside `F`.
+    pub fn modify_f<F: Functor<Inner = A>>(&self, s: S, f: impl FnOnce(A) -> F) -> F::With<S>
+    where
+        S: Clone,
+    {
+        let lens = self.clone();
+        let (a, _) = self.split(s.clone());
+        f(a).fmap(move |a| lens.set(s.clone(), a))
+    }
+
+    // Without a copy of `s` to rebuild from each time, `F` can rebuild at most once.
+    fn modify_once<F>(&self, s: S, f: impl FnOnce(A) -> F) -> F::With<S>
+    where
+        F: FunctorOnce<Inner = A>,
+    {
+        let (a, rebuild) = self.split(s);
+        f(a).fmap(once(rebuild))
+    }
+
+    pub fn view(&self, s: S) -> A {
+        self.modify_once(s, Const::<A, A>::new).into_inner()
+    }
+
+    pub fn over(&self, s: S, f: impl FnOnce(A) -> A) -> S {
+        self.modify_once(s, |a| Identity(f(a))).0
+    }
+
+    pub fn set(&self, s: S, a: A) -> S {
+        self.over(s, |_| a)
+    }
+
+    /// Focuses further, on the `B` that `inner` sees inside this lens's `A`.
+    pub fn compose<B: 'static>(&self, inner: &Lens<A, B>) -> Lens<S, B> {
+        let (outer, inner) = (self.clone(), inner.clone());
+        Lens::from_split(move |s| {
+            let (a, rebuild_s) = outer.split(s);
+            let (b, rebuild_a) = inner.split(a);
+            (b, Box::new(move |b| rebuild_s(rebuild_a(b))))
+        })
+    }
+}
+
+/// The first half of a pair.
+pub fn fst<A: 'static, B: 'static>() -> Lens<(A, B), A> {
+    Lens::from_split(|(a, b)| (a, Box::new(move |a| (a, b))))
+}
+
+/// The second half of a pair.
+pub fn snd<A: 'static, B: 'static>() -> Lens<(A, B), B> {
+    Lens::from_split(|(a, b)| (b, Box::new(move |b| (a, b))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    fn x() -> Lens<Point, i32> {
+        Lens::new(|p: &Point| p.x, |p, x| Point { x, ..p })
+    }
+
+    #[test]
+    fn view_over_set() {
+        let p = Point { x: 1, y: 2 };
+        assert_eq!(x().view(Point { x: 1, y: 2 }), 1);
+        let p = x().over(p, |x| x + 10);
+        assert_eq!(p, Point { x: 11, y: 2 });
+        assert_eq!(x().set(p, 0), Point { x: 0, y: 2 });
+    }
+
+    #[test]
+    fn composes_through_tuples() {
+        let inner_x = snd::<&str, Point>().compose(&x());
+        let pair = ("p", Point { x: 3, y: 4 });
+        assert_eq!(inner_x.over(pair, |x| -x), ("p", Point { x: -3, y: 4 }));
+
+        let nested = fst().compose(&snd());
+        assert_eq!(
+            nested.set(((1, String::from("a")), 2), "b".into()),
+            ((1, "b".into()), 2)
+        );
+    }
+
+    #[test]
+    fn modify_f_rebuilds_in_the_functor() {
+        let checked = x().modify_f(Point { x: 4, y: 0 }, |x| (x > 5).then_some(x - 5));
+        assert_eq!(checked, None);
+        let checked = x().modify_f(Point { x: 4, y: 0 }, |x| (x > 3).then_some(x + 1));
+        assert_eq!(checked, Some(Point { x: 5, y: 0 }));
+        assert_eq!(fst().modify_f((1, 2), |a| vec![a, a + 1]), [(1, 2), (2, 2)]);
+    }
+}
@@ -0,0 +1,16 @@
+//! Optics: first-class getters and setters for parts of a larger value.
+//!
+//! The optics here take the whole value by value and hand it back rebuilt, so they work for
+//! types that aren't `Clone`. Viewing consumes the whole, so clone it first to keep it around.
+
+mod iso;
+mod lens;
+mod optional;
+mod prism;
+mod traversal;
+
+pub use iso::{constant, identity, Iso};
+pub use lens::{fst, snd, Lens};
+pub use optional::Optional;
+pub use prism::{err, left, ok, right, some, Prism};
+pub use traversal::{traversed, Traversal};
@@ -0,0 +1,116 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+
+use crate::applicative::Applicative;
+use crate::optics::lens::Focus;
+use crate::optics::{Lens, Prism};
+
+// Like a lens's `Split`, but hands the whole back if there is no focus.
+type FocusFn<S, A> = Rc<dyn Fn(S) -> Result<Focus<S, A>, S>>;
+
+/// Focuses on at most one `A` inside an `S`.
+///
+/// This is what composing a [`Lens`] with a [`Prism`] gives: the focus might be missing, like a
+/// prism's, but it can't be built from an `A` alone, like a lens's. Both convert into one with
+/// `From`.
+pub struct Optional<S, A> {
+    focus: FocusFn<S, A>,
+}
+
+impl<S, A> Clone for Optional<S, A> {
+    fn clone(&self) -> Self {
+        Optional {
+            focus: self.focus.clone(),
+        }
+    }
+}
+
+impl<S: 'static, A: 'static> Optional<S, A> {
+    pub(crate) fn from_focus(focus: impl Fn(S) -> Result<Focus<S, A>, S> + 'static) -> Self {
+        Optional {
+            focus: Rc::new(focus),
+        }
+    }
+
+    pub(crate) fn focus(&self, s: S) -> Result<Focus<S, A>, S> {
+        (self.focus)(s)
+    }
+
+    /// Runs `f` on the focus if there is one and puts each result back into a copy of `s`
+    /// inside `F`, or returns `s` in [`pure`](Applicative::pure) if not.
+    pub fn modify_f<F: Applicative<Inner = A>>(&self, s: S, f: impl FnOnce(A) -> F) -> F::With<S>
+    where
+        S: Clone,
+    {
+        match self.focus(s.clone()) {
+            Ok((a, _)) => {
+                let optional = self.clone();
+                f(a).fmap(move |a| optional.set(s.clone(), a))
+            }
+            Err(s) => F::pure(s),
+        }
+    }
+
+    pub fn preview(&self, s: S) -> Option<A> {
+        self.focus(s).ok().map(|(a, _)| a)
+    }
+
+    /// Updates the focus if there is one, leaving `s` alone otherwise.
+    pub fn over(&self, s: S, f: impl FnOnce(A) -> A) -> S {
+        match self.focus(s) {
+            Ok((a, rebuild)) => rebuild(f(a)),
+            Err(s) => s,
+        }
+    }
+
+    /// Replaces the focus if there is one, leaving `s` alone otherwise.
+    pub fn set(&self, s: S, a: A) -> S {
+        self.over(s, |_| a)
+    }
+
+    pub fn compose<B: 'static>(&self, inner: &Optional<A, B>) -> Optional<S, B> {
+        let (outer, inner) = (self.clone(), inner.clone());
+        Optional::from_focus(move |s| {
+            let (a, rebuild_s) = outer.focus(s)?;
+            match inner.focus(a) {
+                Ok((b, rebuild_a)) => Ok((b, Box::new(move |b| rebuild_s(rebuild_a(b))))),
+                Err(a) => Err(rebuild_s(a)),
+            }
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> From<Lens<S, A>> for Optional<S, A> {
+    fn from(lens: Lens<S, A>) -> Self {
+        Optional::from_focus(move |s| Ok(lens.split(s)))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Prism<S, A>> for Optional<S, A> {
+    fn from(prism: Prism<S, A>) -> Self {
+        Optional::from_focus(move |s| {
+            let a = prism.matching(s)?;
+            let prism = prism.clone();
+            Ok((a, Box::new(move |a| prism.review(a))))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::{fst, some};
+
+    #[test]
+    fn modify_f_rebuilds_once_per_result() {
+        let first_some = Optional::from(fst()).compose(&some().into());
+        assert_eq!(
+            first_some.modify_f((Some(1), 'a'), |a| vec![a, a + 1]),
+            [(Some(1), 'a'), (Some(2), 'a')]
+        );
+        assert_eq!(
+            first_some.modify_f((None, 'a'), |a: i32| vec![a, a + 1]),
+            [(None, 'a')]
+        );
+    }
+}
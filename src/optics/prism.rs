@@ -0,0 +1,187 @@
+use alloc::rc::Rc;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::optics::{Lens, Optional};
+
+/// Focuses on one case of a sum type, like `Some` in an `Option`.
+///
+/// [`preview`](Prism::preview) gets the `A` out if `s` is in that case, and
+/// [`review`](Prism::review) builds an `S` from an `A`.
+pub struct Prism<S, A> {
+    matching: Rc<dyn Fn(S) -> Result<A, S>>,
+    build: Rc<dyn Fn(A) -> S>,
+}
+
+impl<S, A> Clone for Prism<S, A> {
+    fn clone(&self) -> Self {
+        Prism {
+            matching: self.matching.clone(),
+            build: self.build.clone(),
+        }
+    }
+}
+
+impl<S: 'static, A: 'static> Prism<S, A> {
+    /// `matching` returns the focus if `s` is in the right case, and hands `s` back otherwise.
+    pub fn new(
+        matching: impl Fn(S) -> Result<A, S> + 'static,
+        build: impl Fn(A) -> S + 'static,
+    ) -> Self {
+        Prism {
+            matching: Rc::new(matching),
+            build: Rc::new(build),
+        }
+    }
+
+    pub fn matching(&self, s: S) -> Result<A, S> {
+        (self.matching)(s)
+    }
+
+    /// Runs `f` on the focus if there is one and rebuilds `s` inside `F`, or returns `s` in
+    /// [`pure`](Applicative::pure) if not.
+    pub fn modify_f<F: Applicative<Inner = A>>(&self, s: S, f: impl FnOnce(A) -> F) -> F::With<S> {
+        match self.matching(s) {
+            Ok(a) => {
+                let build = self.build.clone();
+                f(a).fmap(move |a| build(a))
+            }
+            Err(s) => F::pure(s),
+        }
+    }
+
+    pub fn preview(&self, s: S) -> Option<A> {
+        self.matching(s).ok()
+    }
+
+    pub fn review(&self, a: A) -> S {
+        (self.build)(a)
+    }
+
+    /// Updates the focus if there is one, leaving `s` alone otherwise.
+    pub fn over(&self, s: S, f: impl FnOnce(A) -> A) -> S {
+        match self.matching(s) {
+            Ok(a) => self.review(f(a)),
+            Err(s) => s,
+        }
+    }
+
+    /// Focuses on a case of this prism's focus.
+    pub fn compose<B: 'static>(&self, inner: &Prism<A, B>) -> Prism<S, B> {
+        let (outer, inner) = (self.clone(), inner.clone());
+        let build = {
+            let (outer, inner) = (outer.clone(), inner.clone());
+            move |b| outer.review(inner.review(b))
+        };
+        Prism::new(
+            move |s| {
+                let a = outer.matching(s)?;
+                inner.matching(a).map_err(|a| outer.review(a))
+            },
+            build,
+        )
+    }
+
+    /// Focuses on a part of this prism's focus. There is no `review` for the result, since `B`
+    /// alone isn't enough to rebuild an `A`.
+    pub fn compose_lens<B: 'static>(&self, inner: &Lens<A, B>) -> Optional<S, B> {
+        Optional::from(self.clone()).compose(&inner.clone().into())
+    }
+}
+
+impl<S: 'static, A: 'static> Lens<S, A> {
+    /// Focuses on a case of this lens's focus, which might not be there.
+    pub fn compose_prism<B: 'static>(&self, inner: &Prism<A, B>) -> Optional<S, B> {
+        Optional::from(self.clone()).compose(&inner.clone().into())
+    }
+}
+
+/// `Some` in an `Option`.
+pub fn some<A: 'static>() -> Prism<Option<A>, A> {
+    Prism::new(|s: Option<A>| s.ok_or(None), Some)
+}
+
+/// `Ok` in a `Result`.
+pub fn ok<T: 'static, E: 'static>() -> Prism<Result<T, E>, T> {
+    Prism::new(|s: Result<T, E>| s.map_err(Err), Ok)
+}
+
+/// `Err` in a `Result`.
+pub fn err<T: 'static, E: 'static>() -> Prism<Result<T, E>, E> {
+    Prism::new(
+        |s: Result<T, E>| match s {
+            Ok(t) => Err(Ok(t)),
+            Err(e) => Ok(e),
+        },
+        Err,
+    )
+}
+
+/// `Left` in an `Either`.
+pub fn left<L: 'static, R: 'static>() -> Prism<Either<L, R>, L> {
+    Prism::new(
+        |s| match s {
+            Either::Left(l) => Ok(l),
+            right => Err(right),
+        },
+        Either::Left,
+    )
+}
+
+/// `Right` in an `Either`.
+pub fn right<L: 'static, R: 'static>() -> Prism<Either<L, R>, R> {
+    Prism::new(
+        |s| match s {
+            Either::Right(r) => Ok(r),
+            left => Err(left),
+        },
+        Either::Right,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::{fst, snd};
+
+    #[test]
+    fn preview_and_review() {
+        assert_eq!(some().preview(Some(1)), Some(1));
+        assert_eq!(some::<i32>().preview(None), None);
+        assert_eq!(err::<(), _>().review("bad"), Err("bad"));
+        assert_eq!(
+            left::<_, ()>().over(Either::Left(2), |n| n * 2),
+            Either::Left(4)
+        );
+        assert_eq!(
+            right().over(Either::<i32, i32>::Left(2), |n| n * 2),
+            Either::Left(2)
+        );
+    }
+
+    #[test]
+    fn composes_prisms() {
+        let ok_some = ok::<Option<i32>, ()>().compose(&some());
+        assert_eq!(ok_some.preview(Ok(Some(1))), Some(1));
+        assert_eq!(ok_some.matching(Ok(None)), Err(Ok(None)));
+        assert_eq!(ok_some.review(2), Ok(Some(2)));
+    }
+
+    #[test]
+    fn composes_with_lenses() {
+        let fst_some = fst::<Option<i32>, &str>().compose_prism(&some());
+        assert_eq!(fst_some.preview((Some(1), "a")), Some(1));
+        assert_eq!(fst_some.set((None, "a"), 5), (None, "a"));
+
+        let some_snd = some::<(i32, i32)>().compose_lens(&snd());
+        assert_eq!(some_snd.over(Some((1, 2)), |n| n + 1), Some((1, 3)));
+    }
+
+    #[test]
+    fn modify_f_skips_other_cases() {
+        let halve = |n: i32| (n % 2 == 0).then_some(n / 2);
+        assert_eq!(some().modify_f(Some(4), halve), Some(Some(2)));
+        assert_eq!(some().modify_f(Some(3), halve), None);
+        assert_eq!(some().modify_f(None, halve), Some(None));
+    }
+}
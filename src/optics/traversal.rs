@@ -0,0 +1,197 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::applicative::Applicative;
+use crate::functor::FunctorWith;
+use crate::monoid::Monoid;
+use crate::optics::{Lens, Optional, Prism};
+use crate::state::State;
+use crate::traversable::Traversable;
+use crate::writer::Writer;
+
+// All the targets in order, and a function that puts the same number of new ones back.
+type Parts<S, A> = (Vec<A>, Box<dyn FnOnce(Vec<A>) -> S>);
+
+/// Focuses on zero or more `A`s inside an `S`, like the elements of a `Vec`.
+///
+/// [`traversed`] builds one for any [`Traversable`], and lenses, prisms and optionals all convert
+/// into traversals with `From`, so they can be composed with them.
+pub struct Traversal<S, A> {
+    parts: Rc<dyn Fn(S) -> Parts<S, A>>,
+}
+
+impl<S, A> Clone for Traversal<S, A> {
+    fn clone(&self) -> Self {
+        Traversal {
+            parts: self.parts.clone(),
+        }
+    }
+}
+
+impl<S: 'static, A: 'static> Traversal<S, A> {
+    pub(crate) fn from_parts(parts: impl Fn(S) -> Parts<S, A> + 'static) -> Self {
+        Traversal {
+            parts: Rc::new(parts),
+        }
+    }
+
+    fn parts(&self, s: S) -> Parts<S, A> {
+        (self.parts)(s)
+    }
+
+    /// Runs `f` on every target in order, combining the effects, and puts each list of results
+    /// back into a copy of `s` inside `F`.
+    pub fn modify_f<F>(&self, s: S, f: impl FnMut(A) -> F) -> F::With<S>
+    where
+        F: Applicative<Inner = A> + FunctorWith,
+        S: Clone,
+    {
+        let traversal = self.clone();
+        let targets = self.to_vec(s.clone());
+        F::fmap_with(targets.traverse(f), move |targets| {
+            (traversal.parts(s.clone()).1)(targets)
+        })
+    }
+
+    pub fn over(&self, s: S, f: impl FnMut(A) -> A) -> S {
+        let (targets, rebuild) = self.parts(s);
+        rebuild(targets.into_iter().map(f).collect())
+    }
+
+    pub fn to_vec(&self, s: S) -> Vec<A> {
+        self.parts(s).0
+    }
+
+    /// Maps every target into a monoid and combines the results in order.
+    pub fn fold_map_of<M: Monoid>(&self, s: S, f: impl FnMut(A) -> M) -> M {
+        M::concat(self.to_vec(s).into_iter().map(f))
+    }
+
+    /// Focuses on the targets of `inner` in each of this traversal's targets.
+    pub fn compose<B: 'static>(&self, inner: &Traversal<A, B>) -> Traversal<S, B> {
+        let (outer, inner) = (self.clone(), inner.clone());
+        Traversal::from_parts(move |s| {
+            let (targets, rebuild_s) = outer.parts(s);
+            let mut all = Vec::new();
+            let mut rebuilds = Vec::with_capacity(targets.len());
+            for a in targets {
+                let (bs, rebuild_a) = inner.parts(a);
+                rebuilds.push((bs.len(), rebuild_a));
+                all.extend(bs);
+            }
+            (
+                all,
+                Box::new(move |bs: Vec<B>| {
+                    let mut bs = bs.into_iter();
+                    let targets = rebuilds
+                        .into_iter()
+                        .map(|(len, rebuild_a)| rebuild_a(bs.by_ref().take(len).collect()))
+                        .collect();
+                    rebuild_s(targets)
+                }),
+            )
+        })
+    }
+}
+
+/// Focuses on every element of a [`Traversable`].
+///
+/// The elements are collected by traversing with [`Writer`], and put back by traversing the
+/// remaining shape with [`State`].
+pub fn traversed<T>() -> Traversal<T, T::Inner>
+where
+    T: Traversable + 'static,
+    T::Inner: 'static,
+    T::With<()>: Traversable<Inner = (), With<T::Inner> = T> + 'static,
+{
+    Traversal::from_parts(|t: T| {
+        let (shape, targets) = t.traverse(|a| Writer::tell(vec![a])).run_writer();
+        (
+            targets,
+            Box::new(move |targets: Vec<T::Inner>| {
+                shape
+                    .traverse(|()| {
+                        State::new(|mut rest: vec::IntoIter<T::Inner>| {
+                            let next = rest.next().expect("traversal rebuilt with too few targets");
+                            (next, rest)
+                        })
+                    })
+                    .eval_state(targets.into_iter())
+            }),
+        )
+    })
+}
+
+impl<S: 'static, A: 'static> From<Optional<S, A>> for Traversal<S, A> {
+    fn from(optional: Optional<S, A>) -> Self {
+        Traversal::from_parts(move |s| match optional.focus(s) {
+            Ok((a, rebuild)) => (
+                vec![a],
+                Box::new(move |mut targets: Vec<A>| {
+                    rebuild(
+                        targets
+                            .pop()
+                            .expect("traversal rebuilt with too few targets"),
+                    )
+                }),
+            ),
+            Err(s) => (Vec::new(), Box::new(move |_| s)),
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> From<Lens<S, A>> for Traversal<S, A> {
+    fn from(lens: Lens<S, A>) -> Self {
+        Optional::from(lens).into()
+    }
+}
+
+impl<S: 'static, A: 'static> From<Prism<S, A>> for Traversal<S, A> {
+    fn from(prism: Prism<S, A>) -> Self {
+        Optional::from(prism).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optics::{fst, some};
+
+    #[test]
+    fn over_and_to_vec() {
+        let each = traversed::<Vec<i32>>();
+        assert_eq!(each.over(vec![1, 2, 3], |n| n * 2), [2, 4, 6]);
+        assert_eq!(each.to_vec(vec![1, 2]), [1, 2]);
+        assert_eq!(
+            each.fold_map_of(vec![1, 2, 3], |n| vec![n; n as usize])
+                .len(),
+            6
+        );
+        assert_eq!(traversed::<Option<i32>>().to_vec(None), []);
+    }
+
+    #[test]
+    fn composes_with_other_optics() {
+        let firsts = traversed::<Vec<(i32, &str)>>().compose(&fst().into());
+        let pairs = vec![(1, "a"), (2, "b")];
+        assert_eq!(firsts.over(pairs, |n| -n), [(-1, "a"), (-2, "b")]);
+
+        let somes = traversed::<Vec<Option<i32>>>().compose(&some().into());
+        assert_eq!(somes.to_vec(vec![Some(1), None, Some(3)]), [1, 3]);
+        let nested = traversed::<Vec<Vec<i32>>>().compose(&traversed());
+        assert_eq!(
+            nested.over(vec![vec![1], vec![], vec![2, 3]], |n| n + 1),
+            [vec![2], vec![], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn modify_f_combines_effects() {
+        let each = traversed::<Vec<i32>>();
+        let halve = |n: i32| (n % 2 == 0).then_some(n / 2);
+        assert_eq!(each.modify_f(vec![2, 4], halve), Some(vec![1, 2]));
+        assert_eq!(each.modify_f(vec![2, 3], halve), None);
+    }
+}
@@ -0,0 +1,159 @@
+//! Two values of the same type.
+
+use core::ops::{Index, IndexMut};
+
+use crate::applicative::Applicative;
+use crate::distributive::Distributive;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::representable::Representable;
+use crate::traversable::Traversable;
+
+/// Two `A`s, indexed by `false` for the first and `true` for the second.
+///
+/// It's the smallest [`Representable`] functor with more than one element, so it's what a "left
+/// and right" or "before and after" looks like when the two sides should be mapped together.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pair<A>(pub A, pub A);
+
+impl<A> Pair<A> {
+    pub fn swap(self) -> Self {
+        Pair(self.1, self.0)
+    }
+}
+
+impl<A> From<(A, A)> for Pair<A> {
+    fn from((a, b): (A, A)) -> Self {
+        Pair(a, b)
+    }
+}
+
+impl<A> From<Pair<A>> for (A, A) {
+    fn from(pair: Pair<A>) -> Self {
+        (pair.0, pair.1)
+    }
+}
+
+impl<A> Index<bool> for Pair<A> {
+    type Output = A;
+
+    fn index(&self, second: bool) -> &A {
+        if second {
+            &self.1
+        } else {
+            &self.0
+        }
+    }
+}
+
+impl<A> IndexMut<bool> for Pair<A> {
+    fn index_mut(&mut self, second: bool) -> &mut A {
+        if second {
+            &mut self.1
+        } else {
+            &mut self.0
+        }
+    }
+}
+
+impl<T> K1 for Pair<T> {
+    type Inner = T;
+
+    type With<I> = Pair<I>;
+}
+
+impl<A> Functor for Pair<A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Pair<B> {
+        Pair(f(self.0), f(self.1))
+    }
+}
+
+impl<A> FunctorWith for Pair<A> {
+    fn fmap_with<X: 'static, Y: 'static>(fx: Pair<X>, f: impl FnMut(X) -> Y + 'static) -> Pair<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorRef for Pair<A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Pair<B> {
+        Pair(f(&self.0), f(&self.1))
+    }
+}
+
+impl<A> Foldable for Pair<A> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, A) -> B) -> B {
+        let acc = f(init, self.0);
+        f(acc, self.1)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        let acc = f(self.1, init);
+        f(self.0, acc)
+    }
+}
+
+impl<A> Traversable for Pair<A> {
+    fn traverse<F, B: 'static>(self, mut f: impl FnMut(A) -> F) -> F::With<Pair<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        let first = f(self.0);
+        // An `F` is an `F::With<B>`, but only `fmap` can show it.
+        let second = f(self.1).fmap(|b| b);
+        first.zip_with(second, Pair)
+    }
+}
+
+impl<A: Clone + 'static> Distributive for Pair<A> {
+    fn distribute<W>(w: W) -> Pair<W::With<A>>
+    where
+        W: Functor<Inner = Pair<A>> + FunctorRef + 'static,
+    {
+        Pair(w.fmap_ref(|p| p.0.clone()), w.fmap(|p| p.1))
+    }
+}
+
+impl<A> Representable for Pair<A> {
+    type Rep = bool;
+
+    fn tabulate<X: 'static>(mut f: impl FnMut(bool) -> X + 'static) -> Pair<X> {
+        let first = f(false);
+        Pair(first, f(true))
+    }
+
+    fn index(self, second: bool) -> A {
+        if second {
+            self.1
+        } else {
+            self.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_both_sides() {
+        assert_eq!(Pair(1, 2).fmap(|n| n * 10), Pair(10, 20));
+        assert_eq!(Pair("a", "b").fold_left(String::new(), |s, x| s + x), "ab");
+        assert_eq!(Pair("1", "x").traverse(|s| s.parse::<u8>().ok()), None);
+        assert_eq!(
+            Pair("1", "2").traverse(|s| s.parse::<u8>().ok()),
+            Some(Pair(1, 2))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn distributes_and_indexes() {
+        let pairs = vec![Pair(1, 2), Pair(3, 4)];
+        assert_eq!(Pair::distribute(pairs), Pair(vec![1, 3], vec![2, 4]));
+        let mut p = Pair(1, 2).swap();
+        p[false] += 10;
+        assert_eq!(p, Pair(12, 1));
+        assert_eq!(<(i32, i32)>::from(p), (12, 1));
+    }
+}
@@ -0,0 +1,381 @@
+//! Parser combinators over string slices.
+//!
+//! Apart from a handful of primitives that look at the input directly, the combinators are all
+//! written with [`Functor`], [`Applicative`], [`Alternative`] and [`Monad`], so they double as a
+//! test that those traits are enough to build a real parser on.
+//!
+//! A [`Parser`] can only be run once, like [`State`](crate::state::State), so combinators that
+//! run a parser repeatedly, like [`many`], take a function that builds a fresh one each time.
+//! Parsers are usually written as functions anyway, which can be passed as they are.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::alternative::Alternative;
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_plus::MonadPlus;
+
+// On failure, the input that was left where the parser gave up.
+type Step<'a, A> = Result<(A, &'a str), &'a str>;
+
+/// A parser of an `A` from the start of a `&'a str`.
+///
+/// Choice with [`or`](Alternative::or) always backtracks, trying the other side from where the
+/// first started however much input it consumed before failing. When both sides fail, the error
+/// is the one that got further.
+pub struct Parser<'a, A>(Box<dyn FnOnce(&'a str) -> Step<'a, A> + 'a>);
+
+/// Where parsing failed, as a byte offset into the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub offset: usize,
+}
+
+impl ParseError {
+    fn at(input: &str, rest: &str) -> Self {
+        ParseError {
+            offset: input.len() - rest.len(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unexpected input at byte {}", self.offset)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl<'a, A> Parser<'a, A> {
+    /// Makes a parser from a function returning the result and the rest of the input, or the
+    /// input left where it failed.
+    pub fn new(f: impl FnOnce(&'a str) -> Result<(A, &'a str), &'a str> + 'a) -> Self {
+        Parser(Box::new(f))
+    }
+
+    /// A parser built by `f` when it runs, for grammars that refer to themselves.
+    pub fn defer(f: impl FnOnce() -> Parser<'a, A> + 'a) -> Self {
+        Parser::new(move |input| (f().0)(input))
+    }
+
+    /// Parses a prefix of `input`, returning the result and the rest.
+    pub fn run(self, input: &'a str) -> Result<(A, &'a str), ParseError> {
+        (self.0)(input).map_err(|rest| ParseError::at(input, rest))
+    }
+
+    /// Parses the whole of `input`, failing if anything is left over.
+    pub fn parse(self, input: &'a str) -> Result<A, ParseError> {
+        match (self.0)(input) {
+            Ok((a, "")) => Ok(a),
+            Ok((_, rest)) | Err(rest) => Err(ParseError::at(input, rest)),
+        }
+    }
+}
+
+impl<'a, A: 'static> Parser<'a, A> {
+    /// Runs `next` after `self`, keeping `self`'s result.
+    pub fn skip<B: 'static>(self, next: Parser<'a, B>) -> Self {
+        self.zip_with(next, |a, _| a)
+    }
+
+    /// Runs `next` after `self`, keeping `next`'s result.
+    pub fn then<B: 'static>(self, next: Parser<'a, B>) -> Parser<'a, B> {
+        self.zip_with(next, |_, b| b)
+    }
+
+    /// Skips any whitespace after `self`.
+    pub fn lexeme(self) -> Self {
+        self.skip(spaces())
+    }
+}
+
+/// Parses a `char` that satisfies `pred`.
+pub fn satisfy<'a>(mut pred: impl FnMut(char) -> bool + 'a) -> Parser<'a, char> {
+    Parser::new(move |input: &'a str| match input.chars().next() {
+        Some(c) if pred(c) => Ok((c, &input[c.len_utf8()..])),
+        _ => Err(input),
+    })
+}
+
+/// Parses any `char`.
+pub fn any_char<'a>() -> Parser<'a, char> {
+    satisfy(|_| true)
+}
+
+/// Parses the `char` `expected`.
+pub fn char<'a>(expected: char) -> Parser<'a, char> {
+    satisfy(move |c| c == expected)
+}
+
+/// Parses `expected` exactly.
+pub fn string<'a>(expected: &'static str) -> Parser<'a, &'static str> {
+    Parser::new(move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((expected, rest)),
+        None => Err(input),
+    })
+}
+
+/// Succeeds only at the end of the input.
+pub fn eof<'a>() -> Parser<'a, ()> {
+    Parser::new(|input: &'a str| {
+        if input.is_empty() {
+            Ok(((), input))
+        } else {
+            Err(input)
+        }
+    })
+}
+
+/// Runs parsers from `p` until one fails, collecting their results.
+///
+/// This loops rather than recursing, so it doesn't grow the stack however many items there are.
+/// It also stops after a parser that succeeds without consuming anything, which would otherwise
+/// repeat forever.
+///
+/// The parser that failed is forgotten, so if what follows fails too, the error is reported after
+/// the last item rather than wherever that parser gave up.
+pub fn many<'a, A: 'a>(mut p: impl FnMut() -> Parser<'a, A> + 'a) -> Parser<'a, Vec<A>> {
+    Parser::new(move |mut input: &'a str| {
+        let mut items = Vec::new();
+        while let Ok((a, rest)) = (p().0)(input) {
+            items.push(a);
+            if rest.len() == input.len() {
+                break;
+            }
+            input = rest;
+        }
+        Ok((items, input))
+    })
+}
+
+/// Like [`many`], but fails unless there is at least one item.
+pub fn many1<'a, A: 'static>(mut p: impl FnMut() -> Parser<'a, A> + 'static) -> Parser<'a, Vec<A>> {
+    let first = p();
+    first.zip_with(many(p), |a, mut rest| {
+        rest.insert(0, a);
+        rest
+    })
+}
+
+/// Parses one or more items from `p` separated by `sep`.
+pub fn sep_by1<'a, A: 'static, S: 'static>(
+    mut p: impl FnMut() -> Parser<'a, A> + 'static,
+    mut sep: impl FnMut() -> Parser<'a, S> + 'static,
+) -> Parser<'a, Vec<A>> {
+    let first = p();
+    first.zip_with(many(move || sep().then(p())), |a, mut rest| {
+        rest.insert(0, a);
+        rest
+    })
+}
+
+/// Parses zero or more items from `p` separated by `sep`.
+pub fn sep_by<'a, A: 'static, S: 'static>(
+    p: impl FnMut() -> Parser<'a, A> + 'static,
+    sep: impl FnMut() -> Parser<'a, S> + 'static,
+) -> Parser<'a, Vec<A>> {
+    sep_by1(p, sep).or(Parser::<Vec<A>>::pure(Vec::new()))
+}
+
+/// Parses `p` between `open` and `close`, keeping only `p`'s result.
+pub fn between<'a, O: 'static, A: 'static, C: 'static>(
+    open: Parser<'a, O>,
+    p: Parser<'a, A>,
+    close: Parser<'a, C>,
+) -> Parser<'a, A> {
+    open.then(p).skip(close)
+}
+
+/// Parses one or more items from `p` separated by operators from `op`, combining them from the
+/// left with the functions the operators return, like `1 - 2 - 3` as `(1 - 2) - 3`.
+pub fn chainl1<'a, A: 'static, F: FnOnce(A, A) -> A + 'static>(
+    mut p: impl FnMut() -> Parser<'a, A> + 'static,
+    mut op: impl FnMut() -> Parser<'a, F> + 'static,
+) -> Parser<'a, A> {
+    let first = p();
+    let rest = many(move || op().zip_with(p(), |f, b| (f, b)));
+    first.zip_with(rest, |a, rest: Vec<(F, A)>| {
+        rest.into_iter().fold(a, |acc, (f, b)| f(acc, b))
+    })
+}
+
+/// Skips any whitespace.
+pub fn spaces<'a>() -> Parser<'a, ()> {
+    many(|| satisfy(char::is_whitespace)).fmap(|_| ())
+}
+
+/// Parses a decimal digit.
+pub fn digit<'a>() -> Parser<'a, u32> {
+    satisfy(|c| c.is_ascii_digit()).fmap(|c| c.to_digit(10).expect("an ASCII digit"))
+}
+
+/// Parses a non-negative decimal number, failing if it doesn't fit in a `u64`.
+pub fn number<'a>() -> Parser<'a, u64> {
+    many1(digit).bind(|digits: Vec<u32>| {
+        let n = digits.into_iter().try_fold(0u64, |acc, d| {
+            acc.checked_mul(10)?.checked_add(u64::from(d))
+        });
+        match n {
+            Some(n) => Parser::<u64>::pure(n),
+            None => Parser::<u64>::empty(),
+        }
+    })
+}
+
+impl<'a, A> K1 for Parser<'a, A> {
+    type Inner = A;
+
+    type With<I> = Parser<'a, I>;
+}
+
+impl<'a, A: 'a> Functor for Parser<'a, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Parser<'a, B> {
+        Parser::new(move |input| {
+            let (a, rest) = (self.0)(input)?;
+            Ok((f(a), rest))
+        })
+    }
+}
+
+impl<'a, A: 'a> FunctorWith for Parser<'a, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Parser<'a, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Parser<'a, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<'a, A: 'a> Applicative for Parser<'a, A> {
+    fn pure<X: 'static>(val: X) -> Parser<'a, X> {
+        Parser::new(move |input| Ok((val, input)))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Parser<'a, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Parser<'a, C> {
+        Parser::new(move |input| {
+            let (a, rest) = (self.0)(input)?;
+            let (b, rest) = (b.0)(rest)?;
+            Ok((f(a, b), rest))
+        })
+    }
+}
+
+impl<'a, A: 'a> Monad for Parser<'a, A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Parser<'a, B> + 'static) -> Parser<'a, B> {
+        Parser::new(move |input| {
+            let (a, rest) = (self.0)(input)?;
+            (f(a).0)(rest)
+        })
+    }
+}
+
+impl<'a, A: 'a> Alternative for Parser<'a, A> {
+    fn empty<X: 'static>() -> Parser<'a, X> {
+        Parser::new(Err)
+    }
+
+    fn or(self, other: Self) -> Self {
+        Parser::new(move |input| {
+            let here = match (self.0)(input) {
+                Ok(done) => return Ok(done),
+                Err(here) => here,
+            };
+            (other.0)(input).map_err(|there| {
+                if there.len() < here.len() {
+                    there
+                } else {
+                    here
+                }
+            })
+        })
+    }
+}
+
+impl<'a, A: 'a> MonadPlus for Parser<'a, A> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token<'a>(c: char) -> Parser<'a, char> {
+        char(c).lexeme()
+    }
+
+    // expr := term (('+' | '-') term)*
+    // term := factor (('*' | '/') factor)*
+    // factor := number | '(' expr ')'
+    fn expr<'a>() -> Parser<'a, i64> {
+        chainl1(term, || {
+            token('+')
+                .fmap(|_| (|a, b| a + b) as fn(i64, i64) -> i64)
+                .or(token('-').fmap(|_| (|a, b| a - b) as fn(i64, i64) -> i64))
+        })
+    }
+
+    fn term<'a>() -> Parser<'a, i64> {
+        chainl1(factor, || {
+            token('*')
+                .fmap(|_| (|a, b| a * b) as fn(i64, i64) -> i64)
+                .or(token('/').fmap(|_| (|a, b| a / b) as fn(i64, i64) -> i64))
+        })
+    }
+
+    fn factor<'a>() -> Parser<'a, i64> {
+        number().lexeme().fmap(|n| n as i64).or(between(
+            token('('),
+            Parser::defer(expr),
+            token(')'),
+        ))
+    }
+
+    #[test]
+    fn parses_arithmetic() {
+        let eval = |src| spaces().then(expr()).parse(src);
+        assert_eq!(eval("1 + 2 * 3"), Ok(7));
+        assert_eq!(eval(" (1 + 2) * 3 "), Ok(9));
+        assert_eq!(eval("10 - 4 - 3"), Ok(3));
+        assert_eq!(eval("2 * (3 + (4 - 1)) / 3"), Ok(4));
+        assert_eq!(eval("1 + * 2"), Err(ParseError { offset: 2 }));
+        assert_eq!(eval("(1 + 2"), Err(ParseError { offset: 6 }));
+    }
+
+    #[test]
+    fn lists_and_choice() {
+        let list = || between(char('['), sep_by(number, || token(',')), char(']'));
+        assert_eq!(list().parse("[1,2, 3]"), Ok(vec![1, 2, 3]));
+        assert_eq!(list().parse("[]"), Ok(vec![]));
+        assert_eq!(list().parse("[1,]"), Err(ParseError { offset: 2 }));
+
+        let keyword = string("let").or(string("letter"));
+        assert_eq!(keyword.run("letter"), Ok(("let", "ter")));
+        let optional = char('-')
+            .optional()
+            .zip_with(number(), |sign, n| (sign.is_some(), n));
+        assert_eq!(optional.parse("-12"), Ok((true, 12)));
+        assert_eq!(
+            number().parse("99999999999999999999"),
+            Err(ParseError { offset: 20 })
+        );
+    }
+
+    #[test]
+    fn many_is_stack_safe_and_borrows_the_input() {
+        let input = "a".repeat(100_000);
+        let (items, rest) = many(|| char('a')).run(&input).unwrap();
+        assert_eq!((items.len(), rest), (100_000, ""));
+        assert_eq!(many(eof).run(""), Ok((vec![()], "")));
+        assert_eq!(
+            many1(digit).run("x").unwrap_err().to_string(),
+            "unexpected input at byte 0"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! `Poll<Result<T, E>>` as a functor over the `Ok` value.
+
+use core::task::Poll;
+
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::try_functor::TryFunctor;
+
+/// A `Poll<Result<T, E>>`, the output of a fallible future's `poll`, seen as a type constructor
+/// in `T`.
+///
+/// `Poll<T>` itself maps over everything that's ready, errors included. Like
+/// [`ResultOk`](crate::result::ResultOk), this wrapper fixes `E` and only maps successes, the way
+/// `Poll::map_ok` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PollOk<T, E>(pub Poll<Result<T, E>>);
+
+impl<T, E> PollOk<T, E> {
+    pub fn into_poll(self) -> Poll<Result<T, E>> {
+        self.0
+    }
+}
+
+impl<T, E> From<Poll<Result<T, E>>> for PollOk<T, E> {
+    fn from(poll: Poll<Result<T, E>>) -> Self {
+        PollOk(poll)
+    }
+}
+
+impl<T, E> From<PollOk<T, E>> for Poll<Result<T, E>> {
+    fn from(poll: PollOk<T, E>) -> Self {
+        poll.0
+    }
+}
+
+impl<T, E> K1 for PollOk<T, E> {
+    type Inner = T;
+
+    type With<I> = PollOk<I, E>;
+}
+
+impl<A, E> Functor for PollOk<A, E> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> PollOk<B, E> {
+        PollOk(self.0.map_ok(f))
+    }
+}
+
+impl<A, E> FunctorWith for PollOk<A, E> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: PollOk<X, E>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> PollOk<Y, E> {
+        fx.fmap(f)
+    }
+}
+
+impl<A, E: Clone> FunctorRef for PollOk<A, E> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> PollOk<B, E> {
+        PollOk(match &self.0 {
+            Poll::Ready(res) => Poll::Ready(res.as_ref().map(f).map_err(E::clone)),
+            Poll::Pending => Poll::Pending,
+        })
+    }
+}
+
+impl<A, E> TryFunctor for PollOk<A, E> {
+    fn try_fmap<B: 'static, X>(
+        self,
+        mut f: impl FnMut(A) -> Result<B, X>,
+    ) -> Result<PollOk<B, E>, X> {
+        Ok(PollOk(match self.0 {
+            Poll::Ready(Ok(a)) => Poll::Ready(Ok(f(a)?)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Generic code that only knows it has a functor.
+    fn describe<F: Functor<Inner = u32>>(fa: F) -> F::With<String> {
+        fa.fmap(|n| format!("{n} bytes"))
+    }
+
+    #[test]
+    fn maps_only_successes() {
+        let ready: PollOk<u32, &str> = Poll::Ready(Ok(4)).into();
+        assert_eq!(describe(ready).0, Poll::Ready(Ok("4 bytes".to_owned())));
+        let failed: PollOk<u32, &str> = Poll::Ready(Err("closed")).into();
+        assert_eq!(describe(failed).into_poll(), Poll::Ready(Err("closed")));
+        let pending = PollOk::<u32, &str>(Poll::Pending);
+        assert_eq!(describe(pending).0, Poll::Pending);
+        assert_eq!(describe(Poll::Ready(7)), Poll::Ready("7 bytes".to_owned()));
+    }
+}
@@ -0,0 +1,102 @@
+//! Glob-import this to bring the core traits and data types into scope.
+
+pub use crate::align::{Align, Semialign, Zip};
+pub use crate::alternative::Alternative;
+pub use crate::applicative::Applicative;
+pub use crate::arrow::{Arrow, Category};
+#[cfg(feature = "futures")]
+pub use crate::async_monad::{AsyncFunctor, AsyncMonad};
+pub use crate::bifunctor::Bifunctor;
+#[cfg(feature = "alloc")]
+pub use crate::codensity::Codensity;
+#[cfg(feature = "alloc")]
+pub use crate::cofree::Cofree;
+pub use crate::comonad::Comonad;
+#[cfg(feature = "alloc")]
+pub use crate::compose::Compose;
+pub use crate::constant::Const;
+#[cfg(feature = "alloc")]
+pub use crate::cont::Cont;
+pub use crate::contravariant::Contravariant;
+pub use crate::coproduct::{Coproduct, Inject, Injection, Project};
+#[cfg(feature = "alloc")]
+pub use crate::coyoneda::Coyoneda;
+#[cfg(feature = "alloc")]
+pub use crate::dist::Dist;
+pub use crate::distributive::Distributive;
+pub use crate::either::Either;
+pub use crate::env::Env;
+#[cfg(feature = "alloc")]
+pub use crate::eval::Eval;
+pub use crate::filterable::{Filterable, Witherable};
+pub use crate::foldable::Foldable;
+#[cfg(feature = "alloc")]
+pub use crate::free::Free;
+#[cfg(feature = "alloc")]
+pub use crate::free_ap::FreeAp;
+#[cfg(feature = "alloc")]
+pub use crate::free_c::FreeC;
+pub use crate::functor::{Functor, FunctorC, FunctorRef, FunctorWith};
+#[cfg(feature = "futures")]
+pub use crate::future::FutureK;
+pub use crate::identity::Identity;
+pub use crate::indexed::{FoldableWithIndex, FunctorWithIndex, Indexed, TraversableWithIndex};
+pub use crate::invariant::Invariant;
+#[cfg(feature = "alloc")]
+pub use crate::io::Io;
+pub use crate::kind::{K1, K2};
+#[cfg(feature = "alloc")]
+pub use crate::kleisli::Kleisli;
+#[cfg(feature = "alloc")]
+pub use crate::logic::Logic;
+pub use crate::monad::Monad;
+pub use crate::monad_bracket::MonadBracket;
+pub use crate::monad_error::MonadError;
+pub use crate::monad_plus::MonadPlus;
+pub use crate::monad_reader::MonadReader;
+pub use crate::monad_rec::MonadRec;
+pub use crate::monad_state::MonadState;
+pub use crate::monad_writer::MonadWriter;
+pub use crate::mono::{MonoFoldable, MonoFunctor, MonoKind};
+pub use crate::monoid::{Monoid, Semigroup};
+pub use crate::nat::NatTrans;
+#[cfg(feature = "alloc")]
+pub use crate::non_empty::NonEmptyVec;
+#[cfg(feature = "alloc")]
+pub use crate::optics::{Iso, Lens, Optional, Prism, Traversal};
+pub use crate::pair::Pair;
+#[cfg(feature = "alloc")]
+pub use crate::parser::Parser;
+pub use crate::poll::PollOk;
+#[cfg(feature = "alloc")]
+pub use crate::product::FunctorProduct;
+pub use crate::profunctor::{Choice, Profunctor, Strong};
+#[cfg(feature = "alloc")]
+pub use crate::reader::Reader;
+#[cfg(feature = "alloc")]
+pub use crate::recursion::Fix;
+pub use crate::representable::Representable;
+pub use crate::result::ResultOk;
+#[cfg(feature = "alloc")]
+pub use crate::rws::RWS;
+pub use crate::selective::Selective;
+#[cfg(feature = "alloc")]
+pub use crate::state::State;
+#[cfg(feature = "alloc")]
+pub use crate::store::Store;
+#[cfg(feature = "futures")]
+pub use crate::stream::StreamK;
+pub use crate::these::These;
+pub use crate::trans::{MonadTrans, OptionT, ResultT, WriterT};
+#[cfg(feature = "alloc")]
+pub use crate::trans::{ReaderT, StateT};
+pub use crate::traversable::Traversable;
+pub use crate::try_functor::TryFunctor;
+#[cfg(feature = "alloc")]
+pub use crate::validation::Validated;
+pub use crate::validation::Validation;
+pub use crate::writer::Writer;
+#[cfg(feature = "alloc")]
+pub use crate::zip_vec::ZipVec;
+#[cfg(feature = "derive")]
+pub use higher_kinded_derive::{Functor, K1};
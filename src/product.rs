@@ -0,0 +1,123 @@
+//! Products of two functors.
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+
+/// An `F<A>` and a `G<A>` side by side, mapped and combined in lockstep.
+///
+/// Both halves are applied types with the same `Inner`, so `FunctorProduct<Vec<A>, Option<A>>`
+/// maps to `FunctorProduct<Vec<B>, Option<B>>`.
+///
+/// There is no [`Applicative`] instance, only an inherent [`zip_with`](FunctorProduct::zip_with):
+/// `pure` would have to put the same value in both halves, and the hierarchy doesn't require it
+/// to be `Clone`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctorProduct<F, G>(pub F, pub G);
+
+impl<F, G> FunctorProduct<F, G> {
+    pub fn into_inner(self) -> (F, G) {
+        (self.0, self.1)
+    }
+}
+
+impl<F: Applicative, G: Applicative<Inner = F::Inner>> FunctorProduct<F, G> {
+    /// Zips each half with the matching half of `b`, which is all of `Applicative` that doesn't
+    /// need `pure`.
+    pub fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: FunctorProduct<F::With<B>, G::With<B>>,
+        f: impl FnMut(F::Inner, B) -> C + 'static,
+    ) -> FunctorProduct<F::With<C>, G::With<C>> {
+        let f = Rc::new(RefCell::new(f));
+        let g = f.clone();
+        FunctorProduct(
+            self.0.zip_with(b.0, move |a, b| (f.borrow_mut())(a, b)),
+            self.1.zip_with(b.1, move |a, b| (g.borrow_mut())(a, b)),
+        )
+    }
+}
+
+impl<F: K1, G: K1<Inner = F::Inner>> K1 for FunctorProduct<F, G> {
+    type Inner = F::Inner;
+
+    type With<I> = FunctorProduct<F::With<I>, G::With<I>>;
+}
+
+impl<F: Functor, G: Functor<Inner = F::Inner>> Functor for FunctorProduct<F, G> {
+    fn fmap<B: 'static>(
+        self,
+        f: impl FnMut(F::Inner) -> B + 'static,
+    ) -> FunctorProduct<F::With<B>, G::With<B>> {
+        // Both halves need the same `f`.
+        let f = Rc::new(RefCell::new(f));
+        let g = f.clone();
+        FunctorProduct(
+            self.0.fmap(move |a| (f.borrow_mut())(a)),
+            self.1.fmap(move |a| (g.borrow_mut())(a)),
+        )
+    }
+}
+
+impl<F: FunctorWith, G: FunctorWith<Inner = F::Inner>> FunctorWith for FunctorProduct<F, G> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: FunctorProduct<F::With<X>, G::With<X>>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> FunctorProduct<F::With<Y>, G::With<Y>> {
+        let f = Rc::new(RefCell::new(f));
+        let g = f.clone();
+        FunctorProduct(
+            F::fmap_with(fx.0, move |x| (f.borrow_mut())(x)),
+            G::fmap_with(fx.1, move |x| (g.borrow_mut())(x)),
+        )
+    }
+}
+
+impl<F: Foldable, G: Foldable<Inner = F::Inner>> Foldable for FunctorProduct<F, G> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, F::Inner) -> B) -> B {
+        let acc = self.0.fold_left(init, &mut f);
+        self.1.fold_left(acc, f)
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(F::Inner, B) -> B) -> B {
+        let acc = self.1.fold_right(init, &mut f);
+        self.0.fold_right(acc, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_both_halves() {
+        let both = FunctorProduct(vec![1, 2], Some(3)).fmap(|n| n * 2);
+        assert_eq!(both.into_inner(), (vec![2, 4], Some(6)));
+    }
+
+    #[test]
+    fn zips_in_lockstep() {
+        let a = FunctorProduct(vec![1, 2], Some(3));
+        let b = FunctorProduct(vec![10, 20], Some(30));
+        assert_eq!(
+            a.zip_with(b, |x, y| x + y).into_inner(),
+            (vec![11, 22], Some(33))
+        );
+    }
+
+    #[test]
+    fn folds_first_then_second() {
+        let both = FunctorProduct(vec![1, 2], Some(3));
+        assert_eq!(
+            both.fold_right(Vec::new(), |n, mut acc| {
+                acc.push(n);
+                acc
+            }),
+            [3, 2, 1]
+        );
+    }
+}
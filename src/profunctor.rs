@@ -0,0 +1,186 @@
+//! Profunctors: things that consume their first parameter and produce their second.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::either::Either;
+use crate::kind::K2;
+
+/// A [`K2`] that is contravariant in its first parameter and covariant in its second, like a
+/// function from `Inner1` to `Inner2`.
+///
+/// The closures, and the types they take and return, are `'static` because instances typically
+/// store them.
+///
+/// Instances should satisfy `x.dimap(|a| a, |b| b) == x`, and `dimap` should agree with `lmap`
+/// followed by `rmap`.
+pub trait Profunctor: K2 {
+    fn dimap<C: 'static, D: 'static>(
+        self,
+        f: impl FnMut(C) -> Self::Inner1 + 'static,
+        g: impl FnMut(Self::Inner2) -> D + 'static,
+    ) -> Self::With<C, D>;
+
+    /// Pre-composes `f` onto the input.
+    fn lmap<C: 'static>(
+        self,
+        f: impl FnMut(C) -> Self::Inner1 + 'static,
+    ) -> Self::With<C, Self::Inner2>
+    where
+        Self: Sized,
+        Self::Inner2: 'static,
+    {
+        self.dimap(f, |b| b)
+    }
+
+    /// Post-composes `g` onto the output.
+    fn rmap<D: 'static>(
+        self,
+        g: impl FnMut(Self::Inner2) -> D + 'static,
+    ) -> Self::With<Self::Inner1, D>
+    where
+        Self: Sized,
+        Self::Inner1: 'static,
+    {
+        self.dimap(|a| a, g)
+    }
+}
+
+/// A [`Profunctor`] that can pass extra data alongside its input, untouched, to its output.
+///
+/// This is what a lens needs: focus on part of a structure with `first_p`, and carry the rest
+/// of it through. The names follow Haskell's `first'` and `second'`.
+// The two signatures differ only in which side of the pairs `C` is on, which is easier to see
+// spelled out than behind an alias for each.
+#[allow(clippy::type_complexity)]
+pub trait Strong: Profunctor {
+    /// Acts on the first half of a pair, passing the second half through.
+    fn first_p<C: Clone + 'static>(self) -> Self::With<(Self::Inner1, C), (Self::Inner2, C)>;
+
+    /// Acts on the second half of a pair, passing the first half through.
+    fn second_p<C: Clone + 'static>(self) -> Self::With<(C, Self::Inner1), (C, Self::Inner2)>;
+}
+
+/// A [`Profunctor`] that can act on one side of an [`Either`] and pass the other side through.
+///
+/// This is what a prism needs: act on the case it matches, and hand back anything else as is.
+/// The names follow Haskell's `left'` and `right'`.
+#[allow(clippy::type_complexity)]
+pub trait Choice: Profunctor {
+    /// Acts on `Left` values, passing `Right` ones through.
+    fn left_p<C: 'static>(self) -> Self::With<Either<Self::Inner1, C>, Either<Self::Inner2, C>>;
+
+    /// Acts on `Right` values, passing `Left` ones through.
+    fn right_p<C: 'static>(self) -> Self::With<Either<C, Self::Inner1>, Either<C, Self::Inner2>>;
+}
+
+/// A boxed `FnMut(A) -> B`, so that functions can be used where a [`K2`] is expected.
+#[cfg(feature = "alloc")]
+pub struct FnWrap<A, B>(Box<dyn FnMut(A) -> B>);
+
+#[cfg(feature = "alloc")]
+impl<A, B> FnWrap<A, B> {
+    pub fn new(f: impl FnMut(A) -> B + 'static) -> Self {
+        FnWrap(Box::new(f))
+    }
+
+    pub fn call(&mut self, a: A) -> B {
+        (self.0)(a)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B> K2 for FnWrap<A, B> {
+    type Inner1 = A;
+    type Inner2 = B;
+
+    type With<C, D> = FnWrap<C, D>;
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, B: 'static> Profunctor for FnWrap<A, B> {
+    fn dimap<C: 'static, D: 'static>(
+        mut self,
+        mut f: impl FnMut(C) -> A + 'static,
+        mut g: impl FnMut(B) -> D + 'static,
+    ) -> FnWrap<C, D> {
+        FnWrap::new(move |c| g(self.call(f(c))))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, B: 'static> Strong for FnWrap<A, B> {
+    fn first_p<C: Clone + 'static>(mut self) -> FnWrap<(A, C), (B, C)> {
+        FnWrap::new(move |(a, c)| (self.call(a), c))
+    }
+
+    fn second_p<C: Clone + 'static>(mut self) -> FnWrap<(C, A), (C, B)> {
+        FnWrap::new(move |(c, a)| (c, self.call(a)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, B: 'static> Choice for FnWrap<A, B> {
+    fn left_p<C: 'static>(mut self) -> FnWrap<Either<A, C>, Either<B, C>> {
+        FnWrap::new(move |ac| match ac {
+            Either::Left(a) => Either::Left(self.call(a)),
+            Either::Right(c) => Either::Right(c),
+        })
+    }
+
+    fn right_p<C: 'static>(mut self) -> FnWrap<Either<C, A>, Either<C, B>> {
+        FnWrap::new(move |ca| match ca {
+            Either::Left(c) => Either::Left(c),
+            Either::Right(a) => Either::Right(self.call(a)),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dimap_wraps_both_ends() {
+        let mut f = FnWrap::new(|x: i32| x * 2).dimap(|s: &str| s.len() as i32, |x| x.to_string());
+        assert_eq!(f.call("abc"), "6");
+    }
+
+    #[test]
+    fn lmap_and_rmap() {
+        let mut f = FnWrap::new(|x: i32| x + 1)
+            .lmap(|(a, b)| a * b)
+            .rmap(|x| x > 10);
+        assert!(f.call((3, 4)));
+        assert!(!f.call((1, 2)));
+    }
+
+    // A lens and a prism in the profunctor encoding, specialised to plain functions: each one
+    // turns a function on the focus into one on the whole structure.
+    fn age_lens(f: FnWrap<u32, u32>) -> FnWrap<(String, u32), (String, u32)> {
+        f.second_p::<String>()
+    }
+
+    fn ok_prism(f: FnWrap<i32, i32>) -> FnWrap<Result<i32, String>, Result<i32, String>> {
+        f.right_p::<String>()
+            .dimap(Either::from_result, Either::into_result)
+    }
+
+    #[test]
+    fn strong_and_choice_make_optics() {
+        let mut birthday = age_lens(FnWrap::new(|n| n + 1));
+        assert_eq!(
+            birthday.call(("ann".to_owned(), 30)),
+            ("ann".to_owned(), 31)
+        );
+
+        let mut bump = ok_prism(FnWrap::new(|n| n * 10));
+        assert_eq!(bump.call(Ok(4)), Ok(40));
+        assert_eq!(bump.call(Err("no".to_owned())), Err("no".to_owned()));
+
+        let mut pair = FnWrap::new(|s: &str| s.len()).first_p::<bool>();
+        assert_eq!(pair.call(("abc", true)), (3, true));
+        let mut left = FnWrap::new(|n: i32| -n).left_p::<()>();
+        assert_eq!(left.call(Either::Left(1)), Either::Left(-1));
+    }
+}
@@ -0,0 +1,190 @@
+//! The reader monad.
+
+use alloc::boxed::Box;
+
+use crate::applicative::Applicative;
+use crate::distributive::Distributive;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_reader::MonadReader;
+use crate::representable::Representable;
+
+/// A computation that reads from a shared environment of type `R`.
+///
+/// Combining two readers hands each of them its own copy of the environment, so the instances
+/// need `R: Clone`. Wrap a large environment in an `Rc` to make that cheap.
+pub struct Reader<R, A>(Box<dyn FnOnce(R) -> A>);
+
+impl<R, A> Reader<R, A> {
+    pub fn new(f: impl FnOnce(R) -> A + 'static) -> Self {
+        Reader(Box::new(f))
+    }
+
+    /// Runs the computation against `env`.
+    pub fn run_reader(self, env: R) -> A {
+        (self.0)(env)
+    }
+}
+
+impl<R: 'static> Reader<R, R> {
+    /// Returns the whole environment.
+    pub fn ask() -> Self {
+        Reader::new(|r| r)
+    }
+}
+
+impl<R: 'static, A> Reader<R, A> {
+    /// Returns a projection of the environment.
+    pub fn asks(f: impl FnOnce(&R) -> A + 'static) -> Self {
+        Reader::new(move |r| f(&r))
+    }
+}
+
+impl<R: 'static, A: 'static> Reader<R, A> {
+    /// Runs `self` in an environment modified by `f`.
+    pub fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        Reader::new(move |r| self.run_reader(f(r)))
+    }
+}
+
+impl<R, A> K1 for Reader<R, A> {
+    type Inner = A;
+
+    type With<I> = Reader<R, I>;
+}
+
+impl<R: 'static, A: 'static> Functor for Reader<R, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Reader<R, B> {
+        Reader::new(move |r| f(self.run_reader(r)))
+    }
+}
+
+impl<R: 'static, A: 'static> FunctorWith for Reader<R, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Reader<R, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Reader<R, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<R: 'static, A: 'static> FunctorOnce for Reader<R, A> {}
+
+impl<R: Clone + 'static, A: 'static> Applicative for Reader<R, A> {
+    fn pure<X: 'static>(val: X) -> Reader<R, X> {
+        Reader::new(move |_| val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Reader<R, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Reader<R, C> {
+        Reader::new(move |r: R| {
+            let a = self.run_reader(r.clone());
+            f(a, b.run_reader(r))
+        })
+    }
+}
+
+impl<R: Clone + 'static, A: 'static> Monad for Reader<R, A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Reader<R, B> + 'static) -> Reader<R, B> {
+        Reader::new(move |r: R| {
+            let a = self.run_reader(r.clone());
+            f(a).run_reader(r)
+        })
+    }
+}
+
+impl<R: Clone + 'static, A: 'static> MonadReader<R> for Reader<R, A> {
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> Reader<R, X> {
+        Reader::new(f)
+    }
+
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        Reader::local(self, f)
+    }
+}
+
+/// Runs every reader inside `w` against the same environment, cloned for each of them.
+impl<R: Clone + 'static, A: 'static> Distributive for Reader<R, A> {
+    fn distribute<W>(w: W) -> Reader<R, W::With<A>>
+    where
+        W: Functor<Inner = Reader<R, A>> + FunctorRef + 'static,
+    {
+        Reader::new(move |r: R| w.fmap(move |reader: Reader<R, A>| reader.run_reader(r.clone())))
+    }
+}
+
+impl<R: 'static, A: 'static> Representable for Reader<R, A> {
+    type Rep = R;
+
+    fn tabulate<X: 'static>(f: impl FnMut(R) -> X + 'static) -> Reader<R, X> {
+        Reader::new(f)
+    }
+
+    fn index(self, env: R) -> A {
+        self.run_reader(env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Config {
+        name: &'static str,
+        verbose: bool,
+    }
+
+    fn greeting() -> Reader<Config, String> {
+        Reader::asks(|c: &Config| c.name).bind(|name| {
+            Reader::asks(move |c: &Config| {
+                if c.verbose {
+                    format!("hello there, {name}!")
+                } else {
+                    format!("hi {name}")
+                }
+            })
+        })
+    }
+
+    #[test]
+    fn reads_environment() {
+        let cfg = Config {
+            name: "ferris",
+            verbose: false,
+        };
+        assert_eq!(greeting().run_reader(cfg.clone()), "hi ferris");
+        let loud = greeting().local(|c| Config { verbose: true, ..c });
+        assert_eq!(loud.run_reader(cfg), "hello there, ferris!");
+    }
+
+    #[test]
+    fn distribute_shares_the_environment() {
+        let readers = vec![
+            Reader::asks(|n: &i32| n + 1),
+            Reader::asks(|n: &i32| n * 10),
+        ];
+        assert_eq!(Reader::distribute(readers).run_reader(3), [4, 30]);
+        let counts = vec![
+            Reader::asks(|s: &String| s.len()),
+            Reader::asks(|s: &String| s.matches('a').count()),
+        ];
+        let total = Reader::cotraverse(counts, |counts| counts.into_iter().sum::<usize>());
+        assert_eq!(total.run_reader(String::from("banana")), 9);
+    }
+
+    #[test]
+    fn tabulate_is_new() {
+        assert_eq!(Reader::<i32, ()>::tabulate(|n| n + 1).index(1), 2);
+    }
+
+    #[test]
+    fn ask_and_zip() {
+        let both = Reader::ask().zip_with(Reader::asks(|n: &i32| n * 2), |a, b| a + b);
+        assert_eq!(both.run_reader(5), 15);
+    }
+}
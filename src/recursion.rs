@@ -0,0 +1,307 @@
+//! Recursion schemes over the fixed point of a functor.
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::either::Either;
+use crate::functor::FunctorWith;
+use crate::kind::K1;
+
+/// A recursive type built from a base functor `F` by putting a `Fix<F>` in each of its holes.
+///
+/// The base functor describes a single layer, with its type parameter standing for the
+/// recursive positions, so `Fix<ListF<T, ()>>` is a list if `ListF<T, R>` is `Nil` or
+/// `Cons(T, R)`. Writing the functor once, e.g. with the `Functor` derive, gets folds
+/// ([`cata`](Fix::cata), [`para`](Fix::para)) and unfolds ([`ana`](Fix::ana),
+/// [`apo`](Fix::apo)) over the recursive type for free, and [`hylo`] fuses an unfold with a
+/// fold. As with [`Free`](crate::free::Free), `F` only stands in for its constructor and is
+/// conventionally applied to `()`.
+///
+/// Like [`Cofree`](crate::cofree::Cofree), each layer is behind an `Rc`, so subtrees can be
+/// shared, with `clone` or by [`para`](Fix::para) handing them out while still folding them.
+/// Taking a layer apart moves it out if it isn't shared and copies it if it is, which only
+/// clones the `Rc`s inside, so [`unfix`](Fix::unfix) and the folds need the layer to be
+/// `Clone`. Building a structure doesn't.
+///
+/// The schemes call their function on one layer after `F::fmap_with` has returned, so they
+/// need `fmap_with` to map the holes straight away. Functors that defer it, like ones holding a
+/// continuation, aren't supported.
+pub struct Fix<F: K1>(Rc<F::With<Fix<F>>>);
+
+// Not bounded on the layer being `Clone`: a derived `Clone` for the layer needs `Fix<F>: Clone`
+// for its holes, which would make the bound its own requirement.
+impl<F: K1> Clone for Fix<F> {
+    fn clone(&self) -> Self {
+        Fix(self.0.clone())
+    }
+}
+
+// `H` is what the holes of a layer hold on the way in or out, which differs from the seed or
+// result for the variants.
+type Algebra<F, A, H = A> = Rc<RefCell<dyn FnMut(<F as K1>::With<H>) -> A>>;
+
+type Coalgebra<F, S, H = S> = Rc<RefCell<dyn FnMut(S) -> <F as K1>::With<H>>>;
+
+impl<F: FunctorWith + 'static> Fix<F> {
+    pub fn new(layer: F::With<Fix<F>>) -> Self {
+        Fix(Rc::new(layer))
+    }
+
+    /// Returns a reference to the outermost layer.
+    pub fn layer(&self) -> &F::With<Fix<F>> {
+        &self.0
+    }
+
+    /// Grows a structure from `seed` from the top down: `coalg` gives each layer, with seeds for
+    /// its subtrees in the holes.
+    ///
+    /// The whole structure is built up front, so `coalg` has to eventually produce layers with
+    /// no holes.
+    pub fn ana<S: 'static>(seed: S, coalg: impl FnMut(S) -> F::With<S> + 'static) -> Self {
+        Fix::ana_shared(seed, Rc::new(RefCell::new(coalg)))
+    }
+
+    fn ana_shared<S: 'static>(seed: S, coalg: Coalgebra<F, S>) -> Self {
+        let layer = (coalg.borrow_mut())(seed);
+        Fix::new(F::fmap_with(layer, move |s| {
+            Fix::ana_shared(s, coalg.clone())
+        }))
+    }
+
+    /// Like [`ana`](Fix::ana), but `coalg` can also finish a hole early by putting an existing
+    /// subtree in it, as `Either::Left`, instead of a seed to keep unfolding.
+    pub fn apo<S: 'static>(
+        seed: S,
+        coalg: impl FnMut(S) -> F::With<Either<Fix<F>, S>> + 'static,
+    ) -> Self {
+        Fix::apo_shared(seed, Rc::new(RefCell::new(coalg)))
+    }
+
+    fn apo_shared<S: 'static>(seed: S, coalg: Coalgebra<F, S, Either<Fix<F>, S>>) -> Self {
+        let layer = (coalg.borrow_mut())(seed);
+        Fix::new(F::fmap_with(layer, move |hole| match hole {
+            Either::Left(done) => done,
+            Either::Right(s) => Fix::apo_shared(s, coalg.clone()),
+        }))
+    }
+}
+
+impl<F: FunctorWith + 'static> Fix<F>
+where
+    F::With<Fix<F>>: Clone,
+{
+    /// Returns the outermost layer, copying it if the structure is shared.
+    pub fn unfix(self) -> F::With<Fix<F>> {
+        Rc::try_unwrap(self.0).unwrap_or_else(|layer| (*layer).clone())
+    }
+
+    /// Folds the structure from the bottom up: `alg` is given each layer with its holes already
+    /// replaced by the results for the subtrees.
+    pub fn cata<A: 'static>(self, alg: impl FnMut(F::With<A>) -> A + 'static) -> A {
+        self.cata_shared(Rc::new(RefCell::new(alg)))
+    }
+
+    // Shares `alg` between every level, like `Free`'s `bind_shared`.
+    fn cata_shared<A: 'static>(self, alg: Algebra<F, A>) -> A {
+        let next = alg.clone();
+        let layer = F::fmap_with(self.unfix(), move |child: Self| {
+            child.cata_shared(next.clone())
+        });
+        let result = (alg.borrow_mut())(layer);
+        result
+    }
+
+    /// Like [`cata`](Fix::cata), but `alg` also gets each subtree alongside the result of
+    /// folding it.
+    pub fn para<A: 'static>(self, alg: impl FnMut(F::With<(Fix<F>, A)>) -> A + 'static) -> A {
+        self.para_shared(Rc::new(RefCell::new(alg)))
+    }
+
+    fn para_shared<A: 'static>(self, alg: Algebra<F, A, (Fix<F>, A)>) -> A {
+        let next = alg.clone();
+        let layer = F::fmap_with(self.unfix(), move |child: Self| {
+            (child.clone(), child.para_shared(next.clone()))
+        });
+        let result = (alg.borrow_mut())(layer);
+        result
+    }
+}
+
+/// Unfolds `seed` with `coalg` and folds the result with `alg`, without building the
+/// intermediate [`Fix`].
+///
+/// Each layer is folded as soon as its holes are, so only the layers on the path from the root
+/// to the current one are alive at a time, rather than the whole structure.
+pub fn hylo<F, S, A>(
+    seed: S,
+    coalg: impl FnMut(S) -> F::With<S> + 'static,
+    alg: impl FnMut(F::With<A>) -> A + 'static,
+) -> A
+where
+    F: FunctorWith + 'static,
+    S: 'static,
+    A: 'static,
+{
+    hylo_shared::<F, S, A>(
+        seed,
+        Rc::new(RefCell::new(coalg)),
+        Rc::new(RefCell::new(alg)),
+    )
+}
+
+fn hylo_shared<F, S, A>(seed: S, coalg: Coalgebra<F, S>, alg: Algebra<F, A>) -> A
+where
+    F: FunctorWith + 'static,
+    S: 'static,
+    A: 'static,
+{
+    let layer = (coalg.borrow_mut())(seed);
+    let next = alg.clone();
+    let layer = F::fmap_with(layer, move |s| {
+        hylo_shared::<F, S, A>(s, coalg.clone(), next.clone())
+    });
+    let result = (alg.borrow_mut())(layer);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functor::Functor;
+
+    #[derive(Clone)]
+    enum ListF<T, R> {
+        Nil,
+        Cons(T, R),
+    }
+
+    impl<T, R> K1 for ListF<T, R> {
+        type Inner = R;
+
+        type With<I> = ListF<T, I>;
+    }
+
+    impl<T, R> Functor for ListF<T, R> {
+        fn fmap<B: 'static>(self, mut f: impl FnMut(R) -> B + 'static) -> ListF<T, B> {
+            match self {
+                ListF::Nil => ListF::Nil,
+                ListF::Cons(t, r) => ListF::Cons(t, f(r)),
+            }
+        }
+    }
+
+    impl<T, R> FunctorWith for ListF<T, R> {
+        fn fmap_with<X: 'static, Y: 'static>(
+            fx: ListF<T, X>,
+            f: impl FnMut(X) -> Y + 'static,
+        ) -> ListF<T, Y> {
+            fx.fmap(f)
+        }
+    }
+
+    type List<T> = Fix<ListF<T, ()>>;
+
+    fn range(n: u32) -> List<u32> {
+        Fix::ana(n, |n| {
+            if n == 0 {
+                ListF::Nil
+            } else {
+                ListF::Cons(n, n - 1)
+            }
+        })
+    }
+
+    #[test]
+    fn ana_then_cata() {
+        let sum = range(4).cata(|layer| match layer {
+            ListF::Nil => 0,
+            ListF::Cons(n, rest) => n + rest,
+        });
+        assert_eq!(sum, 10);
+
+        let items = to_vec(range(3));
+        assert_eq!(items, [3, 2, 1]);
+    }
+
+    fn to_vec(list: List<u32>) -> Vec<u32> {
+        list.cata(|layer: ListF<u32, Vec<u32>>| match layer {
+            ListF::Nil => Vec::new(),
+            ListF::Cons(n, mut rest) => {
+                rest.insert(0, n);
+                rest
+            }
+        })
+    }
+
+    #[test]
+    fn folds_cloned_structures() {
+        let list = range(3);
+        let copy = list.clone();
+        let len = copy.clone().cata(|layer| match layer {
+            ListF::Nil => 0,
+            ListF::Cons(_, n) => n + 1,
+        });
+        assert_eq!(len, 3);
+        assert_eq!(to_vec(list), [3, 2, 1]);
+        assert_eq!(to_vec(copy), [3, 2, 1]);
+    }
+
+    #[test]
+    fn para_sees_each_tail() {
+        // Each element weighted by how many come after it.
+        let weighted = range(3).para(|layer| match layer {
+            ListF::Nil => 0,
+            ListF::Cons(n, (rest, acc)) => n * to_vec(rest).len() as u32 + acc,
+        });
+        assert_eq!(weighted, 3 * 2 + 2);
+    }
+
+    #[test]
+    fn apo_reuses_the_rest() {
+        // Inserting into a descending list stops unfolding once the new element is placed.
+        let insert = |x: u32, list: List<u32>| {
+            Fix::apo((x, list), |(x, list): (u32, List<u32>)| {
+                match list.unfix() {
+                    ListF::Nil => ListF::Cons(x, Either::Left(Fix::new(ListF::Nil))),
+                    ListF::Cons(y, rest) if x >= y => {
+                        ListF::Cons(x, Either::Left(Fix::new(ListF::Cons(y, rest))))
+                    }
+                    ListF::Cons(y, rest) => ListF::Cons(y, Either::Right((x, rest))),
+                }
+            })
+        };
+        assert_eq!(to_vec(insert(2, range(3))), [3, 2, 2, 1]);
+        assert_eq!(to_vec(insert(0, range(2))), [2, 1, 0]);
+        assert_eq!(to_vec(insert(5, range(0))), [5]);
+    }
+
+    #[test]
+    fn hylo_fuses_unfold_and_fold() {
+        let factorial = |n: u64| {
+            hylo::<ListF<u64, ()>, _, _>(
+                n,
+                |n| {
+                    if n == 0 {
+                        ListF::Nil
+                    } else {
+                        ListF::Cons(n, n - 1)
+                    }
+                },
+                |layer| match layer {
+                    ListF::Nil => 1,
+                    ListF::Cons(n, acc) => n * acc,
+                },
+            )
+        };
+        assert_eq!(factorial(0), 1);
+        assert_eq!(factorial(10), 3_628_800);
+    }
+
+    #[test]
+    fn unfix_peels_one_layer() {
+        match range(2).unfix() {
+            ListF::Cons(2, rest) => assert!(matches!(rest.unfix(), ListF::Cons(1, _))),
+            _ => panic!("expected a cons cell"),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Functors that are lookup tables over a fixed index type.
+
+use core::ops::Index;
+
+use crate::functor::Functor;
+
+/// A [`Functor`] that's isomorphic to a function from its [`Rep`](Representable::Rep) type: it
+/// holds exactly one element for every `Rep`.
+///
+/// A `[A; N]` is a function from the indices below `N`, a [`Pair`](crate::pair::Pair) one from
+/// `bool`, and a [`Reader<R, A>`](crate::reader::Reader) is literally a function from `R`.
+/// [`tabulate`](Representable::tabulate) builds one from a function and
+/// [`index`](Representable::index) looks an element up, and the two are inverses:
+/// - `Self::tabulate(f).index(r) == f(r)`
+/// - `Self::tabulate(|r| x.clone().index(r)) == x`
+///
+/// Tabulating into a type that stores its elements computes each one up front, which
+/// [`memoize`] builds on.
+pub trait Representable: Functor {
+    type Rep;
+
+    fn tabulate<X: 'static>(f: impl FnMut(Self::Rep) -> X + 'static) -> Self::With<X>;
+
+    fn index(self, rep: Self::Rep) -> Self::Inner;
+}
+
+/// Computes `f` at every position of a `T`, and returns a function that looks the results up
+/// rather than running `f` again.
+///
+/// Only `T`'s shape matters, so its element type can be anything, as in
+/// `memoize::<[(); 64], _>(slow)`.
+pub fn memoize<T, X>(f: impl FnMut(T::Rep) -> X + 'static) -> impl Fn(T::Rep) -> X
+where
+    T: Representable,
+    T::With<X>: Index<T::Rep, Output = X>,
+    X: Clone + 'static,
+{
+    let table = T::tabulate(f);
+    move |rep| table[rep].clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::pair::Pair;
+
+    #[test]
+    fn tabulate_and_index_agree() {
+        let squares = <[(); 4]>::tabulate(|i| i * i);
+        assert_eq!(squares, [0, 1, 4, 9]);
+        assert_eq!(squares.index(3), 9);
+        assert_eq!(Pair::<()>::tabulate(|b: bool| b as u8), Pair(0, 1));
+        assert_eq!(Pair("no", "yes").index(true), "yes");
+    }
+
+    #[test]
+    fn memoize_computes_once() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let slow_square = memoize::<[(); 16], _>(move |i| {
+            counter.set(counter.get() + 1);
+            i * i
+        });
+        assert_eq!(calls.get(), 16);
+        assert_eq!(slow_square(5) + slow_square(5) + slow_square(15), 275);
+        assert_eq!(calls.get(), 16);
+    }
+}
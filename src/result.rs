@@ -0,0 +1,236 @@
+//! `Result` with its error type fixed, so it can be a [`K1`] over the `Ok` side.
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::monad_rec::MonadRec;
+use crate::selective::Selective;
+use crate::traversable::Traversable;
+
+/// A `Result<T, E>` seen as a type constructor in `T`.
+///
+/// `Result` itself has two parameters, and `K1` always abstracts over the last one, which is the
+/// error type. This wrapper fixes `E` instead and maps over the `Ok` values, short-circuiting on
+/// the first `Err` like `?` does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultOk<T, E>(pub Result<T, E>);
+
+impl<T, E> ResultOk<T, E> {
+    pub fn into_result(self) -> Result<T, E> {
+        self.0
+    }
+}
+
+impl<T, E> From<Result<T, E>> for ResultOk<T, E> {
+    fn from(res: Result<T, E>) -> Self {
+        ResultOk(res)
+    }
+}
+
+impl<T, E> From<ResultOk<T, E>> for Result<T, E> {
+    fn from(res: ResultOk<T, E>) -> Self {
+        res.0
+    }
+}
+
+impl<T, E> K1 for ResultOk<T, E> {
+    type Inner = T;
+
+    type With<I> = ResultOk<I, E>;
+}
+
+impl<A, E> Functor for ResultOk<A, E> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> ResultOk<B, E> {
+        ResultOk(self.0.map(f))
+    }
+}
+
+impl<A, E> FunctorWith for ResultOk<A, E> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: ResultOk<X, E>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> ResultOk<Y, E> {
+        fx.fmap(f)
+    }
+}
+
+impl<A, E> FunctorOnce for ResultOk<A, E> {}
+
+impl<A, E: Clone> FunctorRef for ResultOk<A, E> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> ResultOk<B, E> {
+        ResultOk(self.0.as_ref().map(f).map_err(E::clone))
+    }
+}
+
+impl<A, E> Applicative for ResultOk<A, E> {
+    fn pure<X: 'static>(val: X) -> ResultOk<X, E> {
+        ResultOk(Ok(val))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: ResultOk<B, E>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> ResultOk<C, E> {
+        ResultOk(self.0.and_then(|a| b.0.map(|b| f(a, b))))
+    }
+}
+
+impl<A, E> Selective for ResultOk<A, E> {
+    fn select<X: 'static, Y: 'static, F: FnOnce(X) -> Y + 'static>(
+        fxy: ResultOk<Either<X, Y>, E>,
+        f: ResultOk<F, E>,
+    ) -> ResultOk<Y, E> {
+        ResultOk(match fxy.0 {
+            Ok(Either::Left(x)) => f.0.map(|f| f(x)),
+            Ok(Either::Right(y)) => Ok(y),
+            Err(e) => Err(e),
+        })
+    }
+}
+
+impl<A, E> Monad for ResultOk<A, E> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> ResultOk<B, E> + 'static) -> ResultOk<B, E> {
+        ResultOk(self.0.and_then(|a| f(a).0))
+    }
+}
+
+impl<A, E> MonadError<E> for ResultOk<A, E> {
+    fn throw_error<X: 'static>(err: E) -> ResultOk<X, E> {
+        ResultOk(Err(err))
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(E) -> ResultOk<A, E> + 'static) -> ResultOk<A, E> {
+        ResultOk(self.0.or_else(|e| handler(e).0))
+    }
+}
+
+impl<A, E> MonadRec for ResultOk<A, E> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        mut f: impl FnMut(X) -> ResultOk<Either<X, Y>, E> + 'static,
+    ) -> ResultOk<Y, E> {
+        let mut x = init;
+        loop {
+            match f(x).0 {
+                Ok(Either::Left(next)) => x = next,
+                Ok(Either::Right(y)) => return ResultOk(Ok(y)),
+                Err(e) => return ResultOk(Err(e)),
+            }
+        }
+    }
+}
+
+impl<A, E> Foldable for ResultOk<A, E> {
+    fn fold_left<B>(self, init: B, mut f: impl FnMut(B, A) -> B) -> B {
+        match self.0 {
+            Ok(a) => f(init, a),
+            Err(_) => init,
+        }
+    }
+
+    fn fold_right<B>(self, init: B, mut f: impl FnMut(A, B) -> B) -> B {
+        match self.0 {
+            Ok(a) => f(a, init),
+            Err(_) => init,
+        }
+    }
+}
+
+impl<A, E: 'static> Traversable for ResultOk<A, E> {
+    fn traverse<F, B: 'static>(self, mut f: impl FnMut(A) -> F) -> F::With<ResultOk<B, E>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        match self.0 {
+            Ok(a) => f(a).fmap(|b| ResultOk(Ok(b))),
+            Err(e) => F::pure(ResultOk(Err(e))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> ResultOk<i32, String> {
+        s.parse().map_err(|_| format!("bad number: {s}")).into()
+    }
+
+    #[test]
+    fn maps_ok_side() {
+        assert_eq!(parse("4").fmap(|x| x + 1).into_result(), Ok(5));
+        assert_eq!(
+            parse("x").fmap(|x| x + 1).into_result(),
+            Err("bad number: x".to_owned())
+        );
+    }
+
+    #[test]
+    fn first_error_wins() {
+        let res = parse("a").zip_with(parse("b"), |a, b| a + b);
+        assert_eq!(Result::from(res), Err("bad number: a".to_owned()));
+        let res = parse("1").bind(|a| parse("2").fmap(move |b| a + b));
+        assert_eq!(Result::from(res), Ok(3));
+    }
+
+    #[test]
+    fn catch_recovers() {
+        let recovered = parse("x").catch_error(|_| ResultOk(Ok(0)));
+        assert_eq!(recovered.into_result(), Ok(0));
+        let thrown = ResultOk::<(), &str>::throw_error::<usize>("boom");
+        assert_eq!(thrown.catch_error(|e| ResultOk(Ok(e.len()))).0, Ok(4));
+    }
+
+    // Generic code only needs to know it can fail with a `String`.
+    fn checked_div<M>(a: i32, b: i32) -> M::With<i32>
+    where
+        M: MonadError<String>,
+    {
+        if b == 0 {
+            M::throw_error("division by zero".to_owned())
+        } else {
+            M::pure(a / b)
+        }
+    }
+
+    #[test]
+    fn generic_over_error_monad() {
+        let res = checked_div::<ResultOk<(), String>>(1, 0);
+        assert_eq!(res.into_result(), Err("division by zero".to_owned()));
+        assert_eq!(checked_div::<ResultOk<(), String>>(6, 3).0, Ok(2));
+    }
+
+    #[test]
+    fn tail_rec_m_stops_at_the_first_error() {
+        let sum_until = |items: Vec<&'static str>| {
+            ResultOk::<(), String>::tail_rec_m((items.into_iter(), 0), |(mut rest, acc)| {
+                let Some(s) = rest.next() else {
+                    return ResultOk(Ok(Either::Right(acc)));
+                };
+                ResultOk(parse(s).0.map(|n| Either::Left((rest, acc + n))))
+            })
+        };
+        assert_eq!(sum_until(vec!["1", "2", "3"]).0, Ok(6));
+        assert_eq!(
+            sum_until(vec!["1", "x", "y"]).0,
+            Err("bad number: x".to_owned())
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn traverse_runs_effects_in_order() {
+        let items = vec!["1", "x", "y"];
+        let res = items.traverse(parse);
+        assert_eq!(res.into_result(), Err("bad number: x".to_owned()));
+        assert_eq!(
+            ResultOk::<_, String>(Ok(Some(1))).sequence(),
+            Some(ResultOk(Ok(1)))
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! The reader-writer-state monad.
+
+use alloc::boxed::Box;
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_reader::MonadReader;
+use crate::monad_state::MonadState;
+use crate::monad_writer::MonadWriter;
+use crate::monoid::Monoid;
+
+/// A computation that reads an environment `R`, writes an output `W` and threads a state `S`,
+/// producing an `A`.
+///
+/// This is what stacking [`ReaderT`](crate::trans::ReaderT),
+/// [`WriterT`](crate::trans::WriterT) and [`StateT`](crate::trans::StateT)
+/// would give, but each step is a single boxed closure rather than one per layer. The output
+/// written so far is threaded through alongside the state, so [`tell`](RWS::tell) appends to it
+/// directly instead of every `bind` combining the outputs of both sides.
+///
+/// As with [`Reader`](crate::reader::Reader), sequencing hands each step its own copy of the
+/// environment, so the instances need `R: Clone`.
+pub struct RWS<R, W, S, A>(Step<R, W, S, A>);
+
+// Takes the output written so far alongside the state, and returns it with this step's appended.
+type Step<R, W, S, A> = Box<dyn FnOnce(R, S, W) -> (A, S, W)>;
+
+impl<R, W: Monoid, S, A> RWS<R, W, S, A> {
+    /// Makes a computation from a function of the environment and the initial state, returning
+    /// the result, the final state and what it wrote.
+    pub fn new(f: impl FnOnce(R, S) -> (A, S, W) + 'static) -> Self {
+        RWS(Box::new(move |r, s, written: W| {
+            let (a, s, w) = f(r, s);
+            (a, s, written.combine(w))
+        }))
+    }
+
+    /// Runs the computation, returning the result, the final state and everything written.
+    pub fn run_rws(self, env: R, init: S) -> (A, S, W) {
+        (self.0)(env, init, W::empty())
+    }
+
+    /// Runs the computation, returning only the result and the output.
+    pub fn eval_rws(self, env: R, init: S) -> (A, W) {
+        let (a, _, w) = self.run_rws(env, init);
+        (a, w)
+    }
+
+    /// Runs the computation, returning only the final state and the output.
+    pub fn exec_rws(self, env: R, init: S) -> (S, W) {
+        let (_, s, w) = self.run_rws(env, init);
+        (s, w)
+    }
+}
+
+impl<R: 'static, W: 'static, S: 'static, A: 'static> RWS<R, W, S, A> {
+    // Runs `self` into a fresh output, so `f` sees only what `self` wrote, then appends whatever
+    // `f` turns that into.
+    fn scoped<B>(self, f: impl FnOnce(A, W) -> (B, W) + 'static) -> RWS<R, W, S, B>
+    where
+        W: Monoid,
+    {
+        RWS(Box::new(move |r, s, written: W| {
+            let (a, s, w) = (self.0)(r, s, W::empty());
+            let (b, w) = f(a, w);
+            (b, s, written.combine(w))
+        }))
+    }
+
+    /// Runs `self` in an environment modified by `f`.
+    pub fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        RWS(Box::new(move |r, s, w| (self.0)(f(r), s, w)))
+    }
+
+    /// Returns the value alongside a copy of what `self` wrote.
+    pub fn listen(self) -> RWS<R, W, S, (A, W)>
+    where
+        W: Monoid + Clone,
+    {
+        self.scoped(|a, w| ((a, w.clone()), w))
+    }
+
+    /// Rewrites what `self` wrote with `f`.
+    pub fn censor(self, f: impl FnOnce(W) -> W + 'static) -> Self
+    where
+        W: Monoid,
+    {
+        self.scoped(move |a, w| (a, f(w)))
+    }
+}
+
+impl<R: 'static, W: Monoid + 'static, S: 'static, A: 'static, F> RWS<R, W, S, (A, F)>
+where
+    F: FnOnce(W) -> W + 'static,
+{
+    /// Rewrites what `self` wrote with the function it returned.
+    pub fn pass(self) -> RWS<R, W, S, A> {
+        self.scoped(|(a, f), w| (a, f(w)))
+    }
+}
+
+impl<R: 'static, W: 'static, S: 'static> RWS<R, W, S, R> {
+    /// Returns the whole environment.
+    pub fn ask() -> Self {
+        RWS(Box::new(|r, s, w| (r, s, w)))
+    }
+}
+
+impl<R: 'static, W: 'static, S: Clone + 'static> RWS<R, W, S, S> {
+    /// Returns (a copy of) the current state.
+    pub fn get() -> Self {
+        RWS(Box::new(|_, s: S, w| (s.clone(), s, w)))
+    }
+}
+
+impl<R: 'static, W: 'static, S: 'static> RWS<R, W, S, ()> {
+    /// Replaces the state.
+    pub fn put(new: S) -> Self {
+        RWS(Box::new(move |_, _, w| ((), new, w)))
+    }
+
+    /// Updates the state with `f`.
+    pub fn modify(f: impl FnOnce(S) -> S + 'static) -> Self {
+        RWS(Box::new(move |_, s, w| ((), f(s), w)))
+    }
+
+    /// Writes `output` without producing a value.
+    pub fn tell(output: W) -> Self
+    where
+        W: Monoid,
+    {
+        RWS(Box::new(move |_, s, written: W| {
+            ((), s, written.combine(output))
+        }))
+    }
+}
+
+impl<R: 'static, W: 'static, S: 'static, A> RWS<R, W, S, A> {
+    /// Returns a projection of the environment.
+    pub fn asks(f: impl FnOnce(&R) -> A + 'static) -> Self {
+        RWS(Box::new(move |r, s, w| (f(&r), s, w)))
+    }
+
+    /// Returns a projection of the current state.
+    pub fn gets(f: impl FnOnce(&S) -> A + 'static) -> Self {
+        RWS(Box::new(move |_, s, w| (f(&s), s, w)))
+    }
+}
+
+impl<R, W, S, A> K1 for RWS<R, W, S, A> {
+    type Inner = A;
+
+    type With<I> = RWS<R, W, S, I>;
+}
+
+impl<R: 'static, W: 'static, S: 'static, A: 'static> Functor for RWS<R, W, S, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> RWS<R, W, S, B> {
+        RWS(Box::new(move |r, s, w| {
+            let (a, s, w) = (self.0)(r, s, w);
+            (f(a), s, w)
+        }))
+    }
+}
+
+impl<R: 'static, W: 'static, S: 'static, A: 'static> FunctorWith for RWS<R, W, S, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: RWS<R, W, S, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> RWS<R, W, S, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<R: Clone + 'static, W: Monoid + 'static, S: 'static, A: 'static> Applicative
+    for RWS<R, W, S, A>
+{
+    fn pure<X: 'static>(val: X) -> RWS<R, W, S, X> {
+        RWS(Box::new(move |_, s, w| (val, s, w)))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: RWS<R, W, S, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> RWS<R, W, S, C> {
+        RWS(Box::new(move |r: R, s, w| {
+            let (a, s, w) = (self.0)(r.clone(), s, w);
+            let (b, s, w) = (b.0)(r, s, w);
+            (f(a, b), s, w)
+        }))
+    }
+}
+
+impl<R: Clone + 'static, W: Monoid + 'static, S: 'static, A: 'static> Monad for RWS<R, W, S, A> {
+    fn bind<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> RWS<R, W, S, B> + 'static,
+    ) -> RWS<R, W, S, B> {
+        RWS(Box::new(move |r: R, s, w| {
+            let (a, s, w) = (self.0)(r.clone(), s, w);
+            (f(a).0)(r, s, w)
+        }))
+    }
+}
+
+impl<R: Clone + 'static, W: Monoid + 'static, S: 'static, A: 'static> MonadReader<R>
+    for RWS<R, W, S, A>
+{
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> RWS<R, W, S, X> {
+        RWS(Box::new(move |r, s, w| (f(r), s, w)))
+    }
+
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        RWS::local(self, f)
+    }
+}
+
+impl<R: Clone + 'static, W: Monoid + 'static, S: 'static, A: 'static> MonadWriter<W>
+    for RWS<R, W, S, A>
+{
+    fn writer<X: 'static>(val: X, output: W) -> RWS<R, W, S, X> {
+        RWS::new(move |_, s| (val, s, output))
+    }
+
+    fn listen(self) -> RWS<R, W, S, (A, W)>
+    where
+        W: Clone,
+    {
+        RWS::listen(self)
+    }
+
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self {
+        RWS::censor(self, f)
+    }
+}
+
+impl<R: Clone + 'static, W: Monoid + 'static, S: 'static, A: 'static> MonadState<S>
+    for RWS<R, W, S, A>
+{
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> RWS<R, W, S, X> {
+        RWS(Box::new(move |_, s, w| {
+            let (x, s) = f(s);
+            (x, s, w)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traversable::Traversable;
+
+    type Machine<A> = RWS<u32, Vec<String>, u32, A>;
+
+    // Adds the step size from the environment to the counter, logging the old value.
+    fn step() -> Machine<u32> {
+        RWS::ask().bind(|by| {
+            RWS::get().bind(move |n| {
+                RWS::put(n + by)
+                    .bind(move |()| RWS::tell(vec![format!("at {n}")]))
+                    .fmap(move |()| n)
+            })
+        })
+    }
+
+    #[test]
+    fn reads_writes_and_threads_state() {
+        let prog = step().zip_with(step().local(|by| by * 10), |a, b| (a, b));
+        assert_eq!(
+            prog.run_rws(2, 1),
+            ((1, 3), 23, vec!["at 1".to_owned(), "at 3".to_owned()])
+        );
+        assert_eq!(
+            RWS::<u32, Vec<String>, u32, u32>::gets(|s| s * 2).eval_rws(0, 4),
+            (8, vec![])
+        );
+        assert_eq!(
+            RWS::<u32, Vec<String>, _, ()>::modify(|s| s + 1).exec_rws(0, 4),
+            (5, vec![])
+        );
+    }
+
+    #[test]
+    fn listen_and_censor_see_only_their_own_output() {
+        let prog = step().bind(|_| step().listen()).fmap(|(n, w)| (n, w.len()));
+        let (value, state, out) = prog.run_rws(1, 0);
+        assert_eq!((value, state), ((1, 1), 2));
+        assert_eq!(out, ["at 0", "at 1"]);
+
+        let quiet = step().bind(|_| step().censor(|_| Vec::new()));
+        assert_eq!(quiet.eval_rws(1, 0), (1, vec!["at 0".to_owned()]));
+
+        let reversed = step()
+            .zip_with(step(), |a, b| {
+                (a + b, |w: Vec<String>| w.into_iter().rev().collect())
+            })
+            .pass();
+        assert_eq!(
+            reversed.eval_rws(1, 5),
+            (11, vec!["at 6".to_owned(), "at 5".to_owned()])
+        );
+    }
+
+    #[test]
+    fn new_appends_to_the_output() {
+        let prog = RWS::tell(vec![1]).bind(|()| RWS::new(|r: i32, s: i32| (r + s, s, vec![2])));
+        assert_eq!(prog.run_rws(1, 2), (3, 2, vec![1, 2]));
+    }
+
+    #[test]
+    fn traverse_runs_in_order() {
+        let steps = vec![(); 3].traverse(|()| step().bind(|n| RWS::asks(move |by| n + by)));
+        let log = ["at 0", "at 2", "at 4"].map(String::from).to_vec();
+        assert_eq!(steps.run_rws(2, 0), (vec![2, 4, 6], 6, log));
+    }
+
+    // Counts down from the state, logging each step, by the amount in the environment.
+    fn countdown<M>() -> M
+    where
+        M: MonadReader<u32>
+            + MonadState<u32>
+            + MonadWriter<Vec<String>>
+            + K1<Inner = u32, With<u32> = M>,
+    {
+        M::ask().bind::<u32>(|by| {
+            M::state::<u32>(move |n: u32| (n, n.saturating_sub(by)))
+                .bind::<u32>(|n| M::writer(n, vec![format!("from {n}")]))
+        })
+    }
+
+    #[test]
+    fn provides_every_capability() {
+        let prog = countdown::<Machine<u32>>().bind(|_| countdown());
+        assert_eq!(
+            prog.run_rws(3, 10),
+            (7, 4, vec!["from 10".to_owned(), "from 7".to_owned()])
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Selective applicative functors.
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::FunctorWith;
+
+/// An [`Applicative`] that can skip an effect depending on the result of an earlier one.
+///
+/// `select` only runs the effects of `f` when `fab` produces a `Left`. Unlike
+/// [`Monad::bind`](crate::monad::Monad::bind), the effects that might run are all given up
+/// front, rather than computed from a value, so they can still be inspected without running
+/// anything.
+///
+/// Instances should satisfy:
+/// - `Self::select(x.fmap(Either::Right), f) == x`
+/// - `Self::select(Self::pure(Either::Left(a)), f) == f.fmap(|f| f(a))`
+pub trait Selective: Applicative + FunctorWith {
+    /// Applies the function from `f` to a `Left`, and passes a `Right` through without running
+    /// the effects of `f`.
+    fn select<A: 'static, B: 'static, F: FnOnce(A) -> B + 'static>(
+        fab: Self::With<Either<A, B>>,
+        f: Self::With<F>,
+    ) -> Self::With<B>;
+
+    /// Handles a `Left` with the function from `l` and a `Right` with the one from `r`, only
+    /// running the effects of the side that's needed.
+    fn branch<A, B, C, L, R>(
+        fab: Self::With<Either<A, B>>,
+        l: Self::With<L>,
+        r: Self::With<R>,
+    ) -> Self::With<C>
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        L: FnOnce(A) -> C + 'static,
+        R: FnOnce(B) -> C + 'static,
+    {
+        let fab = Self::fmap_with(fab, |ab: Either<A, B>| match ab {
+            Either::Left(a) => Either::Left(a),
+            Either::Right(b) => Either::Right(Either::Left(b)),
+        });
+        let l = Self::fmap_with(l, |l: L| move |a| Either::Right(l(a)));
+        Self::select(Self::select(fab, l), r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::ResultOk;
+    #[cfg(feature = "alloc")]
+    use crate::validation::{Validated, Validation};
+
+    #[test]
+    fn select_skips_on_right() {
+        let double = |n: i32| n * 2;
+        assert_eq!(
+            Option::<()>::select(Some(Either::Left(2)), Some(double)),
+            Some(4)
+        );
+        assert_eq!(
+            Option::<()>::select(Some(Either::Right(3)), None::<fn(i32) -> i32>),
+            Some(3)
+        );
+        assert_eq!(
+            Option::<()>::select(Some(Either::Left(2)), None::<fn(i32) -> i32>),
+            None
+        );
+
+        let failed: ResultOk<fn(i32) -> i32, &str> = ResultOk(Err("skipped"));
+        let right = ResultOk::<_, &str>(Ok(Either::Right(1)));
+        assert_eq!(ResultOk::<(), _>::select(right, failed), ResultOk(Ok(1)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn branch_runs_one_side() {
+        type V<A> = Validated<&'static str, A>;
+        let len = |s: &str| s.len();
+        let parse = |n: u32| n as usize;
+        let left: V<Either<&str, u32>> = Validation::Success(Either::Left("abc"));
+        let right: V<Either<&str, u32>> = Validation::Success(Either::Right(7));
+
+        let fail = || V::<fn(u32) -> usize>::fail("unused");
+        assert_eq!(
+            V::<()>::branch(left, Validation::Success(len), fail()),
+            Validation::Success(3)
+        );
+        assert_eq!(
+            V::<()>::branch(
+                right.clone(),
+                V::<fn(&str) -> usize>::fail("unused"),
+                Validation::Success(parse)
+            ),
+            Validation::Success(7)
+        );
+        assert_eq!(
+            V::<()>::branch(right, Validation::Success(len), fail()),
+            V::fail("unused")
+        );
+    }
+}
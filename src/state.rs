@@ -0,0 +1,197 @@
+//! The state monad.
+
+use alloc::boxed::Box;
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_rec::MonadRec;
+use crate::monad_state::MonadState;
+
+/// A computation that threads a value of type `S` through, producing an `A` along the way.
+///
+/// A `State` is just a boxed `FnOnce(S) -> (A, S)`, so nothing happens until it is run with an
+/// initial state, and it can only be run once.
+pub struct State<S, A>(Box<dyn FnOnce(S) -> (A, S)>);
+
+impl<S, A> State<S, A> {
+    pub fn new(f: impl FnOnce(S) -> (A, S) + 'static) -> Self {
+        State(Box::new(f))
+    }
+
+    /// Runs the computation, returning the result and the final state.
+    pub fn run_state(self, init: S) -> (A, S) {
+        (self.0)(init)
+    }
+
+    /// Runs the computation, returning only the result.
+    pub fn eval_state(self, init: S) -> A {
+        self.run_state(init).0
+    }
+
+    /// Runs the computation, returning only the final state.
+    pub fn exec_state(self, init: S) -> S {
+        self.run_state(init).1
+    }
+}
+
+impl<S: Clone + 'static> State<S, S> {
+    /// Returns (a copy of) the current state.
+    pub fn get() -> Self {
+        State::new(|s: S| (s.clone(), s))
+    }
+}
+
+impl<S: 'static> State<S, ()> {
+    /// Replaces the state.
+    pub fn put(new: S) -> Self {
+        State::new(move |_| ((), new))
+    }
+
+    /// Updates the state with `f`.
+    pub fn modify(f: impl FnOnce(S) -> S + 'static) -> Self {
+        State::new(move |s| ((), f(s)))
+    }
+}
+
+impl<S: 'static, A> State<S, A> {
+    /// Returns a projection of the current state.
+    pub fn gets(f: impl FnOnce(&S) -> A + 'static) -> Self {
+        State::new(move |s| (f(&s), s))
+    }
+}
+
+impl<S, A> K1 for State<S, A> {
+    type Inner = A;
+
+    type With<I> = State<S, I>;
+}
+
+impl<S: 'static, A: 'static> Functor for State<S, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> State<S, B> {
+        State::new(move |s| {
+            let (a, s) = self.run_state(s);
+            (f(a), s)
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> FunctorWith for State<S, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: State<S, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> State<S, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<S: 'static, A: 'static> FunctorOnce for State<S, A> {}
+
+impl<S: 'static, A: 'static> Applicative for State<S, A> {
+    fn pure<X: 'static>(val: X) -> State<S, X> {
+        State::new(move |s| (val, s))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: State<S, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> State<S, C> {
+        State::new(move |s| {
+            let (a, s) = self.run_state(s);
+            let (b, s) = b.run_state(s);
+            (f(a, b), s)
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> Monad for State<S, A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> State<S, B> + 'static) -> State<S, B> {
+        State::new(move |s| {
+            let (a, s) = self.run_state(s);
+            f(a).run_state(s)
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> MonadRec for State<S, A> {
+    fn tail_rec_m<X: 'static, Y: 'static>(
+        init: X,
+        mut f: impl FnMut(X) -> State<S, Either<X, Y>> + 'static,
+    ) -> State<S, Y> {
+        State::new(move |mut s| {
+            let mut x = init;
+            loop {
+                let (step, next) = f(x).run_state(s);
+                s = next;
+                match step {
+                    Either::Left(next) => x = next,
+                    Either::Right(y) => return (y, s),
+                }
+            }
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> MonadState<S> for State<S, A> {
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> State<S, X> {
+        State::new(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traversable::Traversable;
+
+    fn fresh() -> State<u32, u32> {
+        State::get().bind(|n| State::put(n + 1).fmap(move |()| n))
+    }
+
+    #[test]
+    fn counter() {
+        let pair = fresh().zip_with(fresh(), |a, b| (a, b));
+        assert_eq!(pair.run_state(10), ((10, 11), 12));
+    }
+
+    #[test]
+    fn stack_ops() {
+        let push = |x: i32| {
+            State::modify(move |mut v: Vec<i32>| {
+                v.push(x);
+                v
+            })
+        };
+        let pop = || State::new(|mut v: Vec<i32>| (v.pop(), v));
+        let prog = push(1).bind(move |()| push(2)).bind(move |()| pop());
+        assert_eq!(prog.run_state(vec![]), (Some(2), vec![1]));
+        assert_eq!(State::gets(Vec::len).eval_state(vec![1, 2, 3]), 3);
+        assert_eq!(State::put(vec![4]).exec_state(vec![1]), [4]);
+    }
+
+    #[test]
+    fn tail_rec_m_threads_state_through_iterations() {
+        // Pops and sums until the stack is empty.
+        let drain = State::<Vec<u64>, ()>::tail_rec_m(0, |acc| {
+            State::new(move |mut stack: Vec<u64>| match stack.pop() {
+                Some(n) => (Either::Left(acc + n), stack),
+                None => (Either::Right(acc), stack),
+            })
+        });
+        assert_eq!(
+            drain.run_state((1..=100_000).collect()),
+            (5_000_050_000, vec![])
+        );
+    }
+
+    #[test]
+    fn traverse_threads_state_in_order() {
+        let labelled = vec!["a", "b", "c"].traverse(|s| fresh().fmap(move |n| format!("{s}{n}")));
+        assert_eq!(
+            labelled.run_state(0),
+            (vec!["a0".into(), "b1".into(), "c2".into()], 3)
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! The store comonad.
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::comonad::Comonad;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+
+/// A lookup function from positions `S` to values `A`, focused on one position.
+///
+/// `extend` replaces the lookup with one that runs `f` on the store refocused at each
+/// position, which is how cellular automata and spreadsheets compute every cell from its
+/// neighbours. Lookups are lazy and aren't cached, so every [`peek`](Store::peek) through a
+/// chain of `extend`s recomputes everything it depends on.
+pub struct Store<S, A> {
+    lookup: Rc<dyn Fn(S) -> A>,
+    pos: S,
+}
+
+impl<S: Clone, A> Clone for Store<S, A> {
+    fn clone(&self) -> Self {
+        Store {
+            lookup: self.lookup.clone(),
+            pos: self.pos.clone(),
+        }
+    }
+}
+
+impl<S, A> Store<S, A> {
+    pub fn new(lookup: impl Fn(S) -> A + 'static, pos: S) -> Self {
+        Store {
+            lookup: Rc::new(lookup),
+            pos,
+        }
+    }
+
+    pub fn pos(&self) -> &S {
+        &self.pos
+    }
+
+    /// Looks up the value at any position, not just the focus.
+    pub fn peek(&self, pos: S) -> A {
+        (self.lookup)(pos)
+    }
+
+    /// Moves the focus to `pos`.
+    pub fn seek(self, pos: S) -> Self {
+        Store { pos, ..self }
+    }
+
+    /// Moves the focus relative to where it is now.
+    pub fn seeks(self, f: impl FnOnce(S) -> S) -> Self {
+        Store {
+            pos: f(self.pos),
+            ..self
+        }
+    }
+
+    /// Looks up every position `f` produces from the focus, e.g. all of its neighbours.
+    pub fn experiment<F>(self, f: impl FnOnce(S) -> F) -> F::With<A>
+    where
+        F: Functor<Inner = S>,
+        S: 'static,
+        A: 'static,
+    {
+        let lookup = self.lookup;
+        f(self.pos).fmap(move |pos| lookup(pos))
+    }
+}
+
+impl<S, A> K1 for Store<S, A> {
+    type Inner = A;
+
+    type With<I> = Store<S, I>;
+}
+
+impl<S: 'static, A: 'static> Functor for Store<S, A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Store<S, B> {
+        let (lookup, f) = (self.lookup, RefCell::new(f));
+        Store::new(move |pos| (f.borrow_mut())(lookup(pos)), self.pos)
+    }
+}
+
+impl<S: 'static, A: 'static> FunctorWith for Store<S, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Store<S, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Store<S, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<S: 'static, A: 'static> Comonad for Store<S, A> {
+    fn extract(self) -> A {
+        (self.lookup)(self.pos)
+    }
+
+    fn extend<B: 'static>(self, f: impl FnMut(Store<S, A>) -> B + 'static) -> Store<S, B> {
+        let (lookup, f) = (self.lookup, RefCell::new(f));
+        Store::new(
+            move |pos| {
+                (f.borrow_mut())(Store {
+                    lookup: lookup.clone(),
+                    pos,
+                })
+            },
+            self.pos,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Rule 90 on a ring of 8 cells: each cell becomes the xor of its neighbours.
+    fn step(cells: Store<usize, bool>) -> bool {
+        let [left, right] = cells
+            .experiment(|i| Neighbours([(i + 7) % 8, (i + 1) % 8]))
+            .0;
+        left ^ right
+    }
+
+    struct Neighbours<T>([T; 2]);
+
+    impl<T> K1 for Neighbours<T> {
+        type Inner = T;
+
+        type With<I> = Neighbours<I>;
+    }
+
+    impl<A> Functor for Neighbours<A> {
+        fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> Neighbours<B> {
+            Neighbours(self.0.map(f))
+        }
+    }
+
+    fn render(cells: &Store<usize, bool>) -> String {
+        (0..8)
+            .map(|i| if cells.peek(i) { '#' } else { '.' })
+            .collect()
+    }
+
+    #[test]
+    fn cellular_automaton() {
+        let start = Store::new(|i| i == 4, 0);
+        assert_eq!(render(&start), "....#...");
+        let next = start.extend(step);
+        assert_eq!(render(&next), "...#.#..");
+        assert_eq!(render(&next.extend(step)), "..#...#.");
+    }
+
+    #[test]
+    fn seek_and_extract() {
+        let squares = Store::new(|i: i32| i * i, 3);
+        assert_eq!(squares.clone().extract(), 9);
+        assert_eq!(*squares.clone().seeks(|i| i + 1).pos(), 4);
+        assert_eq!(squares.seek(5).fmap(|x| x + 1).extract(), 26);
+    }
+}
@@ -0,0 +1,294 @@
+//! Streams as a monad over their items.
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use futures::future;
+use futures::stream::{self, Fuse, LocalBoxStream, Stream, StreamExt};
+
+use crate::alternative::Alternative;
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::filterable::Filterable;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_plus::MonadPlus;
+
+/// An asynchronous sequence of `A`s, seen as a type constructor in `A`.
+///
+/// Like [`FutureK`](crate::future::FutureK), this boxes the stream so that every combinator
+/// gives back a `StreamK`, and isn't `Send`. The instances treat it like an asynchronous `Vec`:
+/// [`bind`](Monad::bind) runs the stream from each item in turn, one after the other, and
+/// [`or`](Alternative::or) chains two streams.
+///
+/// That includes [`zip_with`](Applicative::zip_with), which zips like `Vec`'s, for the reasons
+/// given on [`ZipVec`](crate::zip_vec::ZipVec). It waits for an item from each stream and ends
+/// with the shorter one, where `bind` would pull all of `b` for every item of `self`. A
+/// [`pure`](Applicative::pure) stream is a single item, so zipping with one keeps only the
+/// first item of the other.
+///
+/// A `StreamK` is a stream too, so `StreamExt` works on it directly. Both it and
+/// [`Filterable`] have a `filter_map`, so with both in scope, call the one you want by path.
+pub struct StreamK<A>(LocalBoxStream<'static, A>);
+
+impl<A> StreamK<A> {
+    pub fn new(stream: impl Stream<Item = A> + 'static) -> Self {
+        StreamK(stream.boxed_local())
+    }
+
+    /// The items of `iter`, each ready straight away.
+    pub fn iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: 'static,
+    {
+        StreamK::new(stream::iter(iter))
+    }
+
+    pub fn into_inner(self) -> LocalBoxStream<'static, A> {
+        self.0
+    }
+}
+
+impl<A> Stream for StreamK<A> {
+    type Item = A;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<A>> {
+        self.0.as_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<A> K1 for StreamK<A> {
+    type Inner = A;
+
+    type With<I> = StreamK<I>;
+}
+
+impl<A: 'static> Functor for StreamK<A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> StreamK<B> {
+        StreamK::new(self.0.map(f))
+    }
+}
+
+impl<A: 'static> FunctorWith for StreamK<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: StreamK<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> StreamK<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A: 'static> Applicative for StreamK<A> {
+    fn pure<X: 'static>(val: X) -> StreamK<X> {
+        StreamK::new(stream::once(future::ready(val)))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: StreamK<B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> StreamK<C> {
+        StreamK::new(self.0.zip(b.0).map(move |(a, b)| f(a, b)))
+    }
+}
+
+impl<A: 'static> Monad for StreamK<A> {
+    fn bind<B: 'static>(self, f: impl FnMut(A) -> StreamK<B> + 'static) -> StreamK<B> {
+        StreamK::new(self.0.flat_map(f))
+    }
+}
+
+impl<A: 'static> Alternative for StreamK<A> {
+    fn empty<X: 'static>() -> StreamK<X> {
+        StreamK::new(stream::empty())
+    }
+
+    fn or(self, other: StreamK<A>) -> StreamK<A> {
+        StreamK::new(self.0.chain(other.0))
+    }
+}
+
+impl<A: 'static> MonadPlus for StreamK<A> {}
+
+// The source of a `partition_map`, shared by its two halves. An item pulled by one half that
+// belongs to the other waits in the other's queue.
+struct Split<A, B, C> {
+    source: Fuse<LocalBoxStream<'static, A>>,
+    f: Box<dyn FnMut(A) -> Either<B, C>>,
+    lefts: Half<B>,
+    rights: Half<C>,
+}
+
+struct Half<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+}
+
+impl<T> Half<T> {
+    fn new() -> Self {
+        Half {
+            queue: VecDeque::new(),
+            waker: None,
+        }
+    }
+}
+
+// The source only remembers the half that polled it last, so whenever it gives up an item, the
+// other half is woken to poll it again.
+fn poll_half<A, T, U>(
+    cx: &mut Context<'_>,
+    source: &mut Fuse<LocalBoxStream<'static, A>>,
+    own: &mut Half<T>,
+    other: &mut Half<U>,
+    mut route: impl FnMut(A) -> Either<T, U>,
+) -> Poll<Option<T>> {
+    loop {
+        if let Some(t) = own.queue.pop_front() {
+            return Poll::Ready(Some(t));
+        }
+        let next = match source.poll_next_unpin(cx) {
+            Poll::Ready(next) => next,
+            Poll::Pending => {
+                own.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+        };
+        if let Some(waker) = other.waker.take() {
+            waker.wake();
+        }
+        match next.map(&mut route) {
+            None => return Poll::Ready(None),
+            Some(Either::Left(t)) => return Poll::Ready(Some(t)),
+            Some(Either::Right(u)) => other.queue.push_back(u),
+        }
+    }
+}
+
+/// `partition_map` splits one stream in two that share it. Each pulls items from it as it's
+/// polled, and keeps the ones meant for the other until the other is polled, so polling only one
+/// half buffers everything the other would have got.
+impl<A: 'static> Filterable for StreamK<A> {
+    fn filter_map<B: 'static>(self, mut f: impl FnMut(A) -> Option<B> + 'static) -> StreamK<B> {
+        StreamK::new(self.0.filter_map(move |a| future::ready(f(a))))
+    }
+
+    fn partition_map<B: 'static, C: 'static>(
+        self,
+        f: impl FnMut(A) -> Either<B, C> + 'static,
+    ) -> (StreamK<B>, StreamK<C>) {
+        let split = Rc::new(RefCell::new(Split {
+            source: self.0.fuse(),
+            f: Box::new(f),
+            lefts: Half::new(),
+            rights: Half::new(),
+        }));
+        let shared = split.clone();
+        let lefts = stream::poll_fn(move |cx| {
+            let Split {
+                source,
+                f,
+                lefts,
+                rights,
+            } = &mut *split.borrow_mut();
+            poll_half(cx, source, lefts, rights, f)
+        });
+        let rights = stream::poll_fn(move |cx| {
+            let Split {
+                source,
+                f,
+                lefts,
+                rights,
+            } = &mut *shared.borrow_mut();
+            poll_half(cx, source, rights, lefts, |a| match f(a) {
+                Either::Left(b) => Either::Right(b),
+                Either::Right(c) => Either::Left(c),
+            })
+        });
+        (StreamK::new(lefts), StreamK::new(rights))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::util::block_on;
+
+    fn collect<A: 'static>(stream: StreamK<A>) -> Vec<A> {
+        block_on(stream.collect())
+    }
+
+    // Each item is pending once before it's ready, like one arriving over a channel.
+    fn slowly(items: Vec<u32>) -> StreamK<u32> {
+        let mut items = items.into_iter();
+        let mut waited = false;
+        StreamK::new(stream::poll_fn(move |cx| {
+            if waited {
+                waited = false;
+                Poll::Ready(items.next())
+            } else {
+                waited = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }))
+    }
+
+    #[test]
+    fn maps_and_flat_maps() {
+        let pairs = slowly(vec![1, 2]).bind(|n| StreamK::iter([n, n * 10]).fmap(move |m| (n, m)));
+        assert_eq!(collect(pairs), [(1, 1), (1, 10), (2, 2), (2, 20)]);
+        let zipped = slowly(vec![1, 2, 3]).zip_with(StreamK::iter(["a", "b"]), |n, s| (n, s));
+        assert_eq!(collect(zipped), [(1, "a"), (2, "b")]);
+    }
+
+    #[test]
+    fn filters_and_chains() {
+        let odd = slowly(vec![1, 2, 3, 4, 5]).mfilter(|n| n % 2 == 1);
+        assert_eq!(collect(odd), [1, 3, 5]);
+        let halves =
+            Filterable::filter_map(slowly(vec![1, 2, 4]), |n| (n % 2 == 0).then_some(n / 2));
+        let both = halves
+            .or(StreamK::<u32>::pure(9))
+            .or(StreamK::<u32>::empty());
+        assert_eq!(collect(both), [1, 2, 9]);
+    }
+
+    #[test]
+    fn partitions_into_two_streams() {
+        let (evens, odds) = slowly(vec![1, 2, 3, 4, 5]).partition_map(|n| {
+            if n % 2 == 0 {
+                Either::Left(n / 2)
+            } else {
+                Either::Right(n)
+            }
+        });
+        let (halves, odds) = block_on(future::join(
+            evens.collect::<Vec<_>>(),
+            odds.collect::<Vec<_>>(),
+        ));
+        assert_eq!(halves, [1, 2]);
+        assert_eq!(odds, [1, 3, 5]);
+
+        let (small, big) = slowly(vec![1, 5, 2, 6]).partition_map(|n| {
+            if n < 4 {
+                Either::Left(n)
+            } else {
+                Either::Right(n)
+            }
+        });
+        assert_eq!(collect(big), [5, 6]);
+        assert_eq!(collect(small), [1, 2]);
+    }
+}
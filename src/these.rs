@@ -0,0 +1,140 @@
+//! A sum type that can also hold both sides at once.
+
+use crate::bifunctor::Bifunctor;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::{K1, K2};
+use crate::try_functor::TryFunctor;
+
+/// An `A`, a `B`, or both.
+///
+/// This is what lining up two structures of different shapes produces: positions where only
+/// one side has an element and positions where both do. See
+/// [`Semialign`](crate::align::Semialign). Like [`Either`](crate::either::Either), as a [`K1`]
+/// it maps the right side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum These<A, B> {
+    This(A),
+    That(B),
+    Both(A, B),
+}
+
+impl<A, B> These<A, B> {
+    /// Combines two options, or returns `None` if both are empty.
+    pub fn from_options(a: Option<A>, b: Option<B>) -> Option<Self> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(These::Both(a, b)),
+            (Some(a), None) => Some(These::This(a)),
+            (None, Some(b)) => Some(These::That(b)),
+            (None, None) => None,
+        }
+    }
+
+    pub fn into_options(self) -> (Option<A>, Option<B>) {
+        match self {
+            These::This(a) => (Some(a), None),
+            These::That(b) => (None, Some(b)),
+            These::Both(a, b) => (Some(a), Some(b)),
+        }
+    }
+
+    /// Fills in whichever side is missing with a default.
+    pub fn unwrap_or(self, a: A, b: B) -> (A, B) {
+        let (x, y) = self.into_options();
+        (x.unwrap_or(a), y.unwrap_or(b))
+    }
+
+    /// Reduces to a single value, handling each case with its own function.
+    pub fn these<C>(
+        self,
+        this: impl FnOnce(A) -> C,
+        that: impl FnOnce(B) -> C,
+        both: impl FnOnce(A, B) -> C,
+    ) -> C {
+        match self {
+            These::This(a) => this(a),
+            These::That(b) => that(b),
+            These::Both(a, b) => both(a, b),
+        }
+    }
+}
+
+impl<A, B> K1 for These<A, B> {
+    type Inner = B;
+
+    type With<I> = These<A, I>;
+}
+
+impl<A, B> Functor for These<A, B> {
+    fn fmap<C: 'static>(self, mut f: impl FnMut(B) -> C + 'static) -> These<A, C> {
+        match self {
+            These::This(a) => These::This(a),
+            These::That(b) => These::That(f(b)),
+            These::Both(a, b) => These::Both(a, f(b)),
+        }
+    }
+}
+
+impl<A, B> FunctorWith for These<A, B> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: These<A, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> These<A, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A, B> K2 for These<A, B> {
+    type Inner1 = A;
+    type Inner2 = B;
+
+    type With<C, D> = These<C, D>;
+}
+
+impl<A, B> Bifunctor for These<A, B> {
+    fn bimap<C, D>(self, mut f: impl FnMut(A) -> C, mut g: impl FnMut(B) -> D) -> These<C, D> {
+        match self {
+            These::This(a) => These::This(f(a)),
+            These::That(b) => These::That(g(b)),
+            These::Both(a, b) => These::Both(f(a), g(b)),
+        }
+    }
+}
+
+impl<A, B> TryFunctor for These<A, B> {
+    fn try_fmap<C: 'static, E>(
+        self,
+        mut f: impl FnMut(B) -> Result<C, E>,
+    ) -> Result<These<A, C>, E> {
+        Ok(match self {
+            These::This(a) => These::This(a),
+            These::That(b) => These::That(f(b)?),
+            These::Both(a, b) => These::Both(a, f(b)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_options() {
+        for (a, b) in [(Some(1), Some("x")), (Some(1), None), (None, Some("x"))] {
+            assert_eq!(These::from_options(a, b).unwrap().into_options(), (a, b));
+        }
+        assert_eq!(These::<i32, &str>::from_options(None, None), None);
+        assert_eq!(These::<i32, &str>::This(1).unwrap_or(0, "y"), (1, "y"));
+    }
+
+    #[test]
+    fn maps_each_side() {
+        let both: These<i32, &str> = These::Both(1, "ab");
+        assert_eq!(both.fmap(str::len), These::Both(1, 2));
+        assert_eq!(both.bimap(|n| n + 1, str::len), These::Both(2, 2));
+        assert_eq!(These::<i32, &str>::This(3).fmap(str::len), These::This(3));
+        assert_eq!(
+            both.these(|n| n, |s| s.len() as i32, |n, s| n + s.len() as i32),
+            3
+        );
+    }
+}
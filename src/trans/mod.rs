@@ -0,0 +1,139 @@
+//! Monad transformers.
+//!
+//! A transformer adds one effect on top of an arbitrary base monad. [`K1`](crate::kind::K1) can't
+//! express "the base constructor applied to something else" from just the constructor, so each
+//! transformer is parameterised by the base monad already applied to whatever it wraps. For
+//! example, `OptionT<M>` wraps an `M` whose `Inner` is `Option<A>`, and maps to an
+//! `OptionT<M::With<Option<B>>>`.
+
+use crate::monad::Monad;
+
+mod option;
+#[cfg(feature = "alloc")]
+mod reader;
+mod result;
+#[cfg(feature = "alloc")]
+mod state;
+mod writer;
+
+pub use option::OptionT;
+#[cfg(feature = "alloc")]
+pub use reader::ReaderT;
+pub use result::ResultT;
+#[cfg(feature = "alloc")]
+pub use state::StateT;
+pub use writer::WriterT;
+
+/// A monad transformer, which can run any computation of its base monad.
+///
+/// `Base` is the base monad applied to `Self::Inner`. For example, the base of
+/// `OptionT<Vec<Option<A>>>` is `Vec<A>`.
+///
+/// Instances should satisfy:
+/// - `Self::lift(Base::pure(a)) == Self::pure(a)`
+/// - `Self::lift(m.bind(f)) == Self::lift(m).bind(|a| Self::lift(f(a)))`
+pub trait MonadTrans: Monad {
+    type Base: Monad<Inner = Self::Inner>;
+
+    fn lift(base: Self::Base) -> Self;
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::functor::Functor;
+    use crate::kind::K1;
+    use crate::monad_error::MonadError;
+    use crate::monad_reader::MonadReader;
+    use crate::monad_state::MonadState;
+    use crate::monad_writer::MonadWriter;
+    use crate::reader::Reader;
+    use crate::result::ResultOk;
+    use crate::state::State;
+    use crate::writer::Writer;
+
+    // Runs `base` twice without knowing anything about `T` beyond the transformer interface.
+    fn twice<T>(base: impl Fn() -> T::Base) -> T
+    where
+        T: MonadTrans + K1<With<<T as K1>::Inner> = T>,
+        T::Inner: 'static,
+    {
+        T::lift(base()).zip_with::<T::Inner, T::Inner>(T::lift(base()), |_, b| b)
+    }
+
+    fn tick() -> State<u32, u32> {
+        State::get().bind(|n| State::put(n + 1).fmap(move |()| n))
+    }
+
+    #[test]
+    fn lifts_generically() {
+        let prog: OptionT<State<u32, Option<u32>>> = twice(tick);
+        assert_eq!(prog.run().run_state(0), (Some(1), 2));
+
+        let prog: ReaderT<(), State<u32, u32>> = twice(tick);
+        assert_eq!(prog.run_reader_t(()).run_state(5), (6, 7));
+    }
+
+    // Takes `amount` out of the balance in the state, failing if there isn't enough.
+    fn withdraw<M>(amount: u32) -> M
+    where
+        M: MonadState<u32> + MonadError<String> + K1<Inner = u32, With<u32> = M>,
+    {
+        M::gets(|balance| *balance).bind::<u32>(move |balance| {
+            if balance < amount {
+                M::throw_error::<u32>(format!("{balance} is less than {amount}"))
+            } else {
+                M::state::<u32>(move |b| (b - amount, b - amount))
+            }
+        })
+    }
+
+    #[test]
+    fn capabilities_run_on_any_stack() {
+        // A `StateT` over a `Result` loses the state when it fails...
+        type Strict = StateT<u32, ResultOk<(u32, u32), String>>;
+        let run = |m: Strict| m.run_state_t(10).into_result();
+        assert_eq!(run(withdraw(3)), Ok((7, 7)));
+        assert_eq!(run(withdraw(30)), Err("10 is less than 30".to_string()));
+        let retried = withdraw::<Strict>(30).catch_error(|_| withdraw(4));
+        assert_eq!(run(retried), Ok((6, 6)));
+
+        // ...while a `ResultT` over a `State` keeps it.
+        type Kept = ResultT<State<u32, Result<u32, String>>>;
+        let prog = withdraw::<Kept>(4).bind(|_| withdraw(30));
+        assert_eq!(
+            prog.run().run_state(10),
+            (Err("6 is less than 30".to_string()), 6)
+        );
+    }
+
+    // Logs a greeting for the name in the environment.
+    fn greet<M>() -> M::With<()>
+    where
+        M: MonadReader<String> + MonadWriter<Vec<String>> + K1<Inner = String, With<String> = M>,
+    {
+        M::ask().bind::<()>(|name| M::tell(vec![format!("hello {name}")]))
+    }
+
+    #[test]
+    fn readers_and_writers_pass_through() {
+        type Outer = ReaderT<String, Writer<Vec<String>, String>>;
+        let (_, log) = greet::<Outer>()
+            .local(|name| name.to_uppercase())
+            .run_reader_t("ada".to_string())
+            .run_writer();
+        assert_eq!(log, ["hello ADA"]);
+
+        type Inner = WriterT<Reader<String, (String, Vec<String>)>>;
+        let listened = greet::<Inner>()
+            .listen()
+            .censor(|log| [log.clone(), log].concat());
+        let (((), heard), log) = listened.run_writer_t().run_reader("alan".to_string());
+        assert_eq!(heard, ["hello alan"]);
+        assert_eq!(log, ["hello alan", "hello alan"]);
+
+        type Counter = OptionT<State<u32, Option<u32>>>;
+        let counted = Counter::gets(|n| *n).bind(|n| Counter::put(n + 1).fmap(move |()| n));
+        assert_eq!(counted.run().run_state(1), (Some(1), 2));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::monad_reader::MonadReader;
+use crate::monad_state::MonadState;
+use crate::monad_writer::MonadWriter;
+use crate::nat::NatTrans;
+use crate::trans::MonadTrans;
+
+/// Adds short-circuiting on `None` to the monad `M`, which must be applied to an `Option<A>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionT<M>(pub M);
+
+impl<M: K1<Inner = Option<A>>, A> OptionT<M> {
+    /// Lifts a computation in the base monad into one that always succeeds.
+    pub fn lift<N>(base: N) -> Self
+    where
+        N: Functor<Inner = A, With<Option<A>> = M>,
+        A: 'static,
+    {
+        OptionT(base.fmap(Some))
+    }
+
+    pub fn run(self) -> M {
+        self.0
+    }
+
+    /// Swaps the base monad for another with the natural transformation `nt`.
+    pub fn hoist<F, G, N>(self, mut nt: N) -> OptionT<G::With<Option<A>>>
+    where
+        F: K1<With<Option<A>> = M>,
+        G: K1,
+        N: NatTrans<F, G>,
+        A: 'static,
+    {
+        OptionT(nt.apply::<Option<A>>(self.0))
+    }
+}
+
+impl<M: K1<Inner = Option<A>>, A> K1 for OptionT<M> {
+    type Inner = A;
+
+    type With<I> = OptionT<M::With<Option<I>>>;
+}
+
+impl<M: Functor<Inner = Option<A>>, A> Functor for OptionT<M> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> OptionT<M::With<Option<B>>> {
+        OptionT(self.0.fmap(move |a: Option<A>| a.map(&mut f)))
+    }
+}
+
+/// `zip_with` runs the effects of both sides even if the first produces `None`, since only
+/// `M: Applicative` is required. [`Monad::bind`] skips the rest of the computation instead.
+impl<M: Applicative<Inner = Option<A>>, A> Applicative for OptionT<M> {
+    fn pure<X: 'static>(val: X) -> OptionT<M::With<Option<X>>> {
+        OptionT(M::pure(Some(val)))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: OptionT<M::With<Option<B>>>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> OptionT<M::With<Option<C>>> {
+        OptionT(self.0.zip_with(b.0, move |a: Option<A>, b| Some(f(a?, b?))))
+    }
+}
+
+impl<M: Monad<Inner = Option<A>>, A> Monad for OptionT<M> {
+    fn bind<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> OptionT<M::With<Option<B>>> + 'static,
+    ) -> OptionT<M::With<Option<B>>> {
+        OptionT(self.0.bind(move |a: Option<A>| match a {
+            Some(a) => f(a).0,
+            None => M::pure(None),
+        }))
+    }
+}
+
+impl<M, A: 'static> MonadTrans for OptionT<M>
+where
+    M: Monad<Inner = Option<A>>,
+    M::With<A>: Monad,
+{
+    type Base = M::With<A>;
+
+    fn lift(base: M::With<A>) -> Self {
+        OptionT::lift(base)
+    }
+}
+
+impl<M, A, R> MonadReader<R> for OptionT<M>
+where
+    M: Monad<Inner = Option<A>> + MonadReader<R>,
+{
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> OptionT<M::With<Option<X>>> {
+        OptionT(M::reader(move |r| Some(f(r))))
+    }
+
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        OptionT(self.0.local(f))
+    }
+}
+
+impl<M, A, S> MonadState<S> for OptionT<M>
+where
+    M: Monad<Inner = Option<A>> + MonadState<S>,
+{
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> OptionT<M::With<Option<X>>> {
+        OptionT(M::state(move |s| {
+            let (x, s) = f(s);
+            (Some(x), s)
+        }))
+    }
+}
+
+impl<M, A, W: 'static> MonadWriter<W> for OptionT<M>
+where
+    M: Monad<Inner = Option<A>> + FunctorWith + MonadWriter<W>,
+    A: 'static,
+{
+    fn writer<X: 'static>(val: X, output: W) -> OptionT<M::With<Option<X>>> {
+        OptionT(M::writer(Some(val), output))
+    }
+
+    fn listen(self) -> OptionT<M::With<Option<(A, W)>>>
+    where
+        W: Clone,
+    {
+        OptionT(M::fmap_with(self.0.listen(), |(o, w): (Option<A>, W)| {
+            o.map(|a| (a, w))
+        }))
+    }
+
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self {
+        OptionT(self.0.censor(f))
+    }
+}
+
+impl<M, A, E> MonadError<E> for OptionT<M>
+where
+    M: Monad<Inner = Option<A>> + MonadError<E>,
+{
+    fn throw_error<X: 'static>(err: E) -> OptionT<M::With<Option<X>>> {
+        OptionT(M::throw_error::<Option<X>>(err))
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(E) -> Self + 'static) -> Self {
+        OptionT(self.0.catch_error(move |e| handler(e).0))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::state::State;
+
+    type Counter<A> = OptionT<State<u32, Option<A>>>;
+
+    fn tick() -> Counter<u32> {
+        OptionT::lift(State::get().bind(|n| State::put(n + 1).fmap(move |()| n)))
+    }
+
+    fn bail<A: 'static>() -> Counter<A> {
+        OptionT(State::<u32, ()>::pure(None))
+    }
+
+    #[test]
+    fn bind_short_circuits() {
+        let prog = tick().bind(|_| bail::<u32>()).bind(|_| tick());
+        assert_eq!(prog.run().run_state(0), (None, 1));
+
+        let prog = tick().bind(|a| tick().fmap(move |b| a + b));
+        assert_eq!(prog.run().run_state(1), (Some(3), 3));
+    }
+
+    #[test]
+    fn zip_runs_both_effects() {
+        let prog = bail::<u32>().zip_with(tick(), |a, b| a + b);
+        assert_eq!(prog.run().run_state(0), (None, 1));
+    }
+
+    #[test]
+    fn over_vec() {
+        let prog = OptionT(vec![Some(1), None, Some(3)]).bind(|n| OptionT(vec![Some(n), Some(-n)]));
+        assert_eq!(prog.run(), [Some(1), Some(-1), None, Some(3), Some(-3)]);
+    }
+
+    struct Last;
+
+    impl NatTrans<Vec<()>, Option<()>> for Last {
+        fn apply<A: 'static>(&mut self, fa: Vec<A>) -> Option<A> {
+            fa.into_iter().last()
+        }
+    }
+
+    #[test]
+    fn hoists_into_another_base() {
+        let prog = OptionT(vec![Some(1), None]).hoist(Last);
+        assert_eq!(prog.run(), Some(None));
+        let prog = OptionT(Vec::<Option<i32>>::new()).hoist(Last);
+        assert_eq!(prog.bind(|n| OptionT(Some(Some(n + 1)))).run(), None);
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::boxed::Box;
+
+use crate::applicative::Applicative;
+use crate::functor::Functor;
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::monad_reader::MonadReader;
+use crate::monad_state::MonadState;
+use crate::monad_writer::MonadWriter;
+use crate::nat::NatTrans;
+use crate::trans::MonadTrans;
+
+/// Reads from a shared environment of type `R` before running in the monad `M`.
+///
+/// Like [`Reader`](crate::reader::Reader), combining computations hands each of them its own copy
+/// of the environment, so the instances need `R: Clone`.
+pub struct ReaderT<R, M>(Box<dyn FnOnce(R) -> M>);
+
+impl<R, M> ReaderT<R, M> {
+    pub fn new(f: impl FnOnce(R) -> M + 'static) -> Self {
+        ReaderT(Box::new(f))
+    }
+
+    /// Runs the computation against `env`, leaving the base monad's effects.
+    pub fn run_reader_t(self, env: R) -> M {
+        (self.0)(env)
+    }
+
+    /// Lifts a computation in the base monad into one that ignores the environment.
+    pub fn lift(base: M) -> Self
+    where
+        M: 'static,
+    {
+        ReaderT::new(move |_| base)
+    }
+}
+
+impl<R: 'static, M: 'static> ReaderT<R, M> {
+    /// Runs `self` in an environment modified by `f`.
+    pub fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        ReaderT::new(move |r| self.run_reader_t(f(r)))
+    }
+
+    /// Swaps the base monad for another with the natural transformation `nt`.
+    pub fn hoist<F, G, N>(self, mut nt: N) -> ReaderT<R, G::With<M::Inner>>
+    where
+        M: K1,
+        M::Inner: 'static,
+        F: K1<With<M::Inner> = M>,
+        G: K1,
+        N: NatTrans<F, G> + 'static,
+    {
+        ReaderT::new(move |r| nt.apply::<M::Inner>(self.run_reader_t(r)))
+    }
+}
+
+// `M::pure` only gives back an `M` if `M` is its own `With` at `M::Inner`, which holds for every
+// concrete monad.
+impl<R: 'static, M: Applicative<Inner = R> + K1<With<R> = M>> ReaderT<R, M> {
+    /// Returns the whole environment.
+    pub fn ask() -> Self {
+        ReaderT::new(|r| M::pure::<R>(r))
+    }
+}
+
+impl<R: 'static, M: Applicative<Inner = A> + K1<With<A> = M>, A: 'static> ReaderT<R, M> {
+    /// Returns a projection of the environment.
+    pub fn asks(f: impl FnOnce(&R) -> A + 'static) -> Self {
+        ReaderT::new(move |r| M::pure::<A>(f(&r)))
+    }
+}
+
+impl<R, M: K1> K1 for ReaderT<R, M> {
+    type Inner = M::Inner;
+
+    type With<I> = ReaderT<R, M::With<I>>;
+}
+
+impl<R: 'static, M: Functor + 'static> Functor for ReaderT<R, M> {
+    fn fmap<B: 'static>(self, f: impl FnMut(M::Inner) -> B + 'static) -> ReaderT<R, M::With<B>> {
+        ReaderT::new(move |r| self.run_reader_t(r).fmap(f))
+    }
+}
+
+impl<R: Clone + 'static, M: Applicative + 'static> Applicative for ReaderT<R, M> {
+    fn pure<X: 'static>(val: X) -> ReaderT<R, M::With<X>> {
+        ReaderT::new(move |_| M::pure(val))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: ReaderT<R, M::With<B>>,
+        f: impl FnMut(M::Inner, B) -> C + 'static,
+    ) -> ReaderT<R, M::With<C>> {
+        ReaderT::new(move |r: R| self.run_reader_t(r.clone()).zip_with(b.run_reader_t(r), f))
+    }
+}
+
+impl<R: Clone + 'static, M: Monad + 'static> Monad for ReaderT<R, M> {
+    fn bind<B: 'static>(
+        self,
+        mut f: impl FnMut(M::Inner) -> ReaderT<R, M::With<B>> + 'static,
+    ) -> ReaderT<R, M::With<B>> {
+        ReaderT::new(move |r: R| {
+            self.run_reader_t(r.clone())
+                .bind(move |a| f(a).run_reader_t(r.clone()))
+        })
+    }
+}
+
+impl<R: Clone + 'static, M: Monad + 'static> MonadTrans for ReaderT<R, M> {
+    type Base = M;
+
+    fn lift(base: M) -> Self {
+        ReaderT::lift(base)
+    }
+}
+
+impl<R: Clone + 'static, M: Monad + 'static> MonadReader<R> for ReaderT<R, M> {
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> ReaderT<R, M::With<X>> {
+        ReaderT::new(move |r| M::pure(f(r)))
+    }
+
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        ReaderT::local(self, f)
+    }
+}
+
+impl<R: Clone + 'static, M: MonadState<S> + 'static, S> MonadState<S> for ReaderT<R, M> {
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> ReaderT<R, M::With<X>> {
+        ReaderT::new(move |_| M::state(f))
+    }
+}
+
+impl<R: Clone + 'static, M: MonadWriter<W> + 'static, W: 'static> MonadWriter<W> for ReaderT<R, M> {
+    fn writer<X: 'static>(val: X, output: W) -> ReaderT<R, M::With<X>> {
+        ReaderT::new(move |_| M::writer(val, output))
+    }
+
+    fn listen(self) -> ReaderT<R, M::With<(M::Inner, W)>>
+    where
+        W: Clone,
+    {
+        ReaderT::new(move |r| self.run_reader_t(r).listen())
+    }
+
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self {
+        ReaderT::new(move |r| self.run_reader_t(r).censor(f))
+    }
+}
+
+impl<R: Clone + 'static, M: MonadError<E> + 'static, E: 'static> MonadError<E> for ReaderT<R, M> {
+    fn throw_error<X: 'static>(err: E) -> ReaderT<R, M::With<X>> {
+        ReaderT::new(move |_| M::throw_error(err))
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(E) -> Self + 'static) -> Self {
+        ReaderT::new(move |r: R| {
+            self.run_reader_t(r.clone())
+                .catch_error(move |e| handler(e).run_reader_t(r.clone()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trans::StateT;
+
+    #[test]
+    fn reads_through_option() {
+        let half = |n: i32| ReaderT::new(move |d: i32| (d != 0).then(|| n / d));
+        let prog = ReaderT::<i32, Option<i32>>::ask().bind(move |d| half(d * 10));
+        assert_eq!(prog.run_reader_t(2), Some(10));
+
+        let prog = ReaderT::<i32, Option<i32>>::lift(Some(4)).bind(half);
+        assert_eq!(prog.local(|d| d - 2).run_reader_t(2), None);
+    }
+
+    #[test]
+    fn stacks_with_state() {
+        type Counter<A> = StateT<u32, Option<(A, u32)>>;
+        let step = || {
+            ReaderT::new(|by: u32| {
+                Counter::<()>::modify(move |n| n + by).bind(|()| Counter::<u32>::get())
+            })
+        };
+        let prog = step().zip_with(step(), |a, b| (a, b));
+        let state = prog.run_reader_t(3);
+        assert_eq!(state.run_state_t(1), Some(((4, 7), 7)));
+    }
+}
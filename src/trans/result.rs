@@ -0,0 +1,313 @@
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use core::cell::Cell;
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+#[cfg(feature = "alloc")]
+use crate::monad_bracket::MonadBracket;
+use crate::monad_error::MonadError;
+use crate::monad_reader::MonadReader;
+use crate::monad_state::MonadState;
+use crate::monad_writer::MonadWriter;
+use crate::nat::NatTrans;
+use crate::trans::MonadTrans;
+
+/// Adds failure with an `E` to the monad `M`, which must be applied to a `Result<A, E>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultT<M>(pub M);
+
+impl<M: K1<Inner = Result<A, E>>, A, E> ResultT<M> {
+    /// Lifts a computation in the base monad into one that always succeeds.
+    pub fn lift<N>(base: N) -> Self
+    where
+        N: Functor<Inner = A, With<Result<A, E>> = M>,
+        A: 'static,
+        E: 'static,
+    {
+        ResultT(base.fmap(Ok))
+    }
+
+    pub fn run(self) -> M {
+        self.0
+    }
+
+    /// Swaps the base monad for another with the natural transformation `nt`.
+    pub fn hoist<F, G, N>(self, mut nt: N) -> ResultT<G::With<Result<A, E>>>
+    where
+        F: K1<With<Result<A, E>> = M>,
+        G: K1,
+        N: NatTrans<F, G>,
+        A: 'static,
+        E: 'static,
+    {
+        ResultT(nt.apply::<Result<A, E>>(self.0))
+    }
+
+    /// Transforms the error, if there is one.
+    pub fn map_err<E2: 'static>(
+        self,
+        mut f: impl FnMut(E) -> E2 + 'static,
+    ) -> ResultT<M::With<Result<A, E2>>>
+    where
+        M: Functor,
+        A: 'static,
+    {
+        ResultT(self.0.fmap(move |r: Result<A, E>| r.map_err(&mut f)))
+    }
+}
+
+impl<M: K1<Inner = Result<A, E>>, A, E> K1 for ResultT<M> {
+    type Inner = A;
+
+    type With<I> = ResultT<M::With<Result<I, E>>>;
+}
+
+impl<M: Functor<Inner = Result<A, E>>, A, E: 'static> Functor for ResultT<M> {
+    fn fmap<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> B + 'static,
+    ) -> ResultT<M::With<Result<B, E>>> {
+        ResultT(self.0.fmap(move |a: Result<A, E>| a.map(&mut f)))
+    }
+}
+
+/// `zip_with` runs the effects of both sides even if the first fails, since only
+/// `M: Applicative` is required. The first error still wins. [`Monad::bind`] skips the rest of
+/// the computation instead.
+impl<M: Applicative<Inner = Result<A, E>>, A, E: 'static> Applicative for ResultT<M> {
+    fn pure<X: 'static>(val: X) -> ResultT<M::With<Result<X, E>>> {
+        ResultT(M::pure(Ok(val)))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: ResultT<M::With<Result<B, E>>>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> ResultT<M::With<Result<C, E>>> {
+        ResultT(
+            self.0
+                .zip_with(b.0, move |a: Result<A, E>, b| Ok(f(a?, b?))),
+        )
+    }
+}
+
+impl<M: Monad<Inner = Result<A, E>>, A, E: 'static> Monad for ResultT<M> {
+    fn bind<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> ResultT<M::With<Result<B, E>>> + 'static,
+    ) -> ResultT<M::With<Result<B, E>>> {
+        ResultT(self.0.bind(move |a: Result<A, E>| match a {
+            Ok(a) => f(a).0,
+            Err(e) => M::pure(Err(e)),
+        }))
+    }
+}
+
+/// `catch_error` has to hand back a `ResultT<M>`, so `M` must also be its own `With` at
+/// `Result<A, E>`, which holds for every concrete monad.
+impl<M, A: 'static, E: 'static> MonadError<E> for ResultT<M>
+where
+    M: Monad<Inner = Result<A, E>> + K1<With<Result<A, E>> = M>,
+{
+    fn throw_error<X: 'static>(err: E) -> ResultT<M::With<Result<X, E>>> {
+        ResultT(M::pure::<Result<X, E>>(Err(err)))
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(E) -> Self + 'static) -> Self {
+        ResultT(self.0.bind::<Result<A, E>>(move |a| match a {
+            Ok(a) => M::pure(Ok(a)),
+            Err(e) => handler(e).0,
+        }))
+    }
+}
+
+/// An `Err` from `use_` still releases the resource. If `use_` succeeded but `release` fails, the
+/// bracket fails with the release's error.
+#[cfg(feature = "alloc")]
+impl<M, A: 'static, E: 'static> MonadBracket for ResultT<M>
+where
+    M: MonadBracket<Inner = Result<A, E>> + FunctorWith + K1<With<Result<A, E>> = M>,
+{
+    fn bracket<R: Clone + 'static>(
+        acquire: ResultT<M::With<Result<R, E>>>,
+        use_: impl FnOnce(R) -> Self + 'static,
+        release: impl FnOnce(R) -> ResultT<M::With<Result<(), E>>> + 'static,
+    ) -> Self {
+        // The base bracket only returns what `use_` produced, and an error in the resource
+        // would need `E: Clone`, so errors from acquiring and releasing are set aside here and
+        // put back afterwards.
+        let failed = Rc::new(Cell::new(None));
+        let (on_acquire, on_use, on_release) = (failed.clone(), failed.clone(), failed.clone());
+        let acquired = M::fmap_with(acquire.0, move |res: Result<R, E>| match res {
+            Ok(r) => Some(r),
+            Err(e) => {
+                on_acquire.set(Some(e));
+                None
+            }
+        });
+        let used = M::bracket(
+            acquired,
+            move |r| match r {
+                Some(r) => use_(r).0,
+                None => M::pure(Err(on_use.take().expect("acquiring failed"))),
+            },
+            move |r| match r {
+                Some(r) => M::fmap_with(release(r).0, move |res: Result<(), E>| {
+                    if let Err(e) = res {
+                        on_release.set(Some(e));
+                    }
+                }),
+                None => M::pure(()),
+            },
+        );
+        ResultT(M::fmap_with(used, move |res| match (res, failed.take()) {
+            (Ok(_), Some(e)) => Err(e),
+            (res, _) => res,
+        }))
+    }
+}
+
+impl<M, A: 'static, E: 'static> MonadTrans for ResultT<M>
+where
+    M: Monad<Inner = Result<A, E>>,
+    M::With<A>: Monad,
+{
+    type Base = M::With<A>;
+
+    fn lift(base: M::With<A>) -> Self {
+        ResultT::lift(base)
+    }
+}
+
+impl<M, A, E: 'static, R> MonadReader<R> for ResultT<M>
+where
+    M: Monad<Inner = Result<A, E>> + MonadReader<R>,
+{
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> ResultT<M::With<Result<X, E>>> {
+        ResultT(M::reader(move |r| Ok(f(r))))
+    }
+
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        ResultT(self.0.local(f))
+    }
+}
+
+impl<M, A, E: 'static, S> MonadState<S> for ResultT<M>
+where
+    M: Monad<Inner = Result<A, E>> + MonadState<S>,
+{
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> ResultT<M::With<Result<X, E>>> {
+        ResultT(M::state(move |s| {
+            let (x, s) = f(s);
+            (Ok(x), s)
+        }))
+    }
+}
+
+impl<M, A, E: 'static, W: 'static> MonadWriter<W> for ResultT<M>
+where
+    M: Monad<Inner = Result<A, E>> + FunctorWith + MonadWriter<W>,
+    A: 'static,
+{
+    fn writer<X: 'static>(val: X, output: W) -> ResultT<M::With<Result<X, E>>> {
+        ResultT(M::writer(Ok(val), output))
+    }
+
+    fn listen(self) -> ResultT<M::With<Result<(A, W), E>>>
+    where
+        W: Clone,
+    {
+        ResultT(M::fmap_with(
+            self.0.listen(),
+            |(r, w): (Result<A, E>, W)| r.map(|a| (a, w)),
+        ))
+    }
+
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self {
+        ResultT(self.0.censor(f))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::state::State;
+
+    type Counter<A> = ResultT<State<u32, Result<A, String>>>;
+
+    fn tick() -> Counter<u32> {
+        ResultT::lift(State::get().bind(|n| State::put(n + 1).fmap(move |()| n)))
+    }
+
+    fn fail<A: 'static>(msg: &str) -> Counter<A> {
+        Counter::<u32>::throw_error(msg.to_owned())
+    }
+
+    #[test]
+    fn bind_short_circuits() {
+        let prog = tick().bind(|_| fail::<u32>("boom")).bind(|_| tick());
+        assert_eq!(prog.run().run_state(0), (Err("boom".to_owned()), 1));
+    }
+
+    #[test]
+    fn catch_and_map_err() {
+        let prog = fail::<u32>("boom").catch_error(|e| {
+            assert_eq!(e, "boom");
+            tick()
+        });
+        assert_eq!(prog.run().run_state(5), (Ok(5), 6));
+
+        let prog = fail::<u32>("boom").map_err(|e| e.len());
+        assert_eq!(prog.run().run_state(0), (Err(4), 0));
+    }
+
+    #[test]
+    fn first_error_wins_in_zip() {
+        let prog = fail::<u32>("a").zip_with(fail::<u32>("b"), |a, b| a + b);
+        assert_eq!(prog.run().eval_state(0), Err("a".to_owned()));
+    }
+
+    #[test]
+    fn bracket_releases_on_errors() {
+        use std::cell::RefCell;
+
+        use crate::io::Io;
+
+        type Task<A> = ResultT<Io<Result<A, String>>>;
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let step = |line: &'static str, res: Result<u32, String>| {
+            let log = Rc::clone(&log);
+            ResultT(Io::new(move || {
+                log.borrow_mut().push(line);
+                res
+            }))
+        };
+
+        let close = step("close", Ok(2)).fmap(|_| ());
+        let prog = Task::bracket(
+            step("open", Ok(1)),
+            |_| Task::<u32>::throw_error::<u32>("read failed".to_owned()),
+            move |_| close,
+        );
+        assert_eq!(prog.run().unsafe_run(), Err("read failed".to_owned()));
+        assert_eq!(*log.borrow(), ["open", "close"]);
+
+        let close = step("close", Ok(2)).fmap(|_| ());
+        let prog = Task::bracket(
+            step("open", Err("no such file".to_owned())),
+            Task::<u32>::pure,
+            move |_| close,
+        );
+        assert_eq!(prog.run().unsafe_run(), Err("no such file".to_owned()));
+        assert_eq!(log.borrow().len(), 3);
+
+        let prog =
+            step("work", Ok(3)).guarantee(step("close", Err("busy".to_owned())).fmap(|_| ()));
+        assert_eq!(prog.run().unsafe_run(), Err("busy".to_owned()));
+        assert_eq!(log.borrow()[3..], ["work", "close"]);
+    }
+}
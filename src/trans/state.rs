@@ -0,0 +1,278 @@
+use alloc::boxed::Box;
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorOnce, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::monad_reader::MonadReader;
+use crate::monad_state::MonadState;
+use crate::monad_writer::MonadWriter;
+use crate::monoid::Monoid;
+use crate::nat::NatTrans;
+use crate::trans::MonadTrans;
+use crate::util::once;
+
+/// Threads a state of type `S` through the monad `M`, which must be applied to an `(A, S)`.
+///
+/// Like [`State`](crate::state::State), this is a boxed `FnOnce(S) -> M` that can only be run
+/// once.
+pub struct StateT<S, M>(Box<dyn FnOnce(S) -> M>);
+
+impl<S, M: K1<Inner = (A, S)>, A> StateT<S, M> {
+    pub fn new(f: impl FnOnce(S) -> M + 'static) -> Self {
+        StateT(Box::new(f))
+    }
+
+    /// Runs the computation, returning the base monad's results paired with their final states.
+    pub fn run_state_t(self, init: S) -> M {
+        (self.0)(init)
+    }
+
+    /// Lifts a computation in the base monad into one that leaves the state alone.
+    ///
+    /// The state is cloned once for every result the base monad produces.
+    pub fn lift<N>(base: N) -> Self
+    where
+        N: Functor<Inner = A, With<(A, S)> = M> + 'static,
+        S: Clone + 'static,
+        A: 'static,
+    {
+        StateT::new(move |s: S| base.fmap(move |a| (a, s.clone())))
+    }
+
+    /// Swaps the base monad for another with the natural transformation `nt`.
+    pub fn hoist<F, G, N>(self, mut nt: N) -> StateT<S, G::With<(A, S)>>
+    where
+        F: K1<With<(A, S)> = M>,
+        G: K1,
+        N: NatTrans<F, G> + 'static,
+        S: 'static,
+        A: 'static,
+        M: 'static,
+    {
+        StateT::new(move |s: S| nt.apply::<(A, S)>(self.run_state_t(s)))
+    }
+}
+
+// The constructors below return a `StateT<S, M>` built with `M::pure`, which only gives back an
+// `M` if `M` is its own `With` at `M::Inner`. That holds for every concrete monad.
+impl<S: Clone + 'static, M> StateT<S, M>
+where
+    M: Applicative<Inner = (S, S)> + K1<With<(S, S)> = M>,
+{
+    /// Returns (a copy of) the current state.
+    pub fn get() -> Self {
+        StateT::new(|s: S| M::pure::<(S, S)>((s.clone(), s)))
+    }
+}
+
+impl<S: 'static, M> StateT<S, M>
+where
+    M: Applicative<Inner = ((), S)> + K1<With<((), S)> = M>,
+{
+    /// Replaces the state.
+    pub fn put(new: S) -> Self {
+        StateT::new(move |_| M::pure::<((), S)>(((), new)))
+    }
+
+    /// Updates the state with `f`.
+    pub fn modify(f: impl FnOnce(S) -> S + 'static) -> Self {
+        StateT::new(move |s| M::pure::<((), S)>(((), f(s))))
+    }
+}
+
+impl<S, M: K1<Inner = (A, S)>, A> K1 for StateT<S, M> {
+    type Inner = A;
+
+    type With<I> = StateT<S, M::With<(I, S)>>;
+}
+
+impl<S: 'static, M: Functor<Inner = (A, S)> + 'static, A: 'static> Functor for StateT<S, M> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> StateT<S, M::With<(B, S)>> {
+        StateT::new(move |s| self.run_state_t(s).fmap(move |(a, s)| (f(a), s)))
+    }
+}
+
+/// `zip_with` runs `b` with the state `self` finished in, so it needs `M: Monad`, plus
+/// [`FunctorWith`] to map `b`'s results. `b` can't be cloned, so the base has to be a
+/// [`FunctorOnce`] that gives it at most one state to run with, and the instances that build on
+/// these ask for the same.
+impl<S: 'static, M: Monad<Inner = (A, S)> + FunctorOnce + FunctorWith + 'static, A: 'static>
+    Applicative for StateT<S, M>
+{
+    fn pure<X: 'static>(val: X) -> StateT<S, M::With<(X, S)>> {
+        StateT::new(move |s| M::pure((val, s)))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: StateT<S, M::With<(B, S)>>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> StateT<S, M::With<(C, S)>> {
+        StateT::new(move |s| {
+            self.run_state_t(s).bind(once(move |(a, s)| {
+                M::fmap_with(b.run_state_t(s), once(move |(b, s)| (f(a, b), s)))
+            }))
+        })
+    }
+}
+
+impl<S: 'static, M: Monad<Inner = (A, S)> + FunctorOnce + FunctorWith + 'static, A: 'static> Monad
+    for StateT<S, M>
+{
+    fn bind<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> StateT<S, M::With<(B, S)>> + 'static,
+    ) -> StateT<S, M::With<(B, S)>> {
+        StateT::new(move |s| self.run_state_t(s).bind(move |(a, s)| f(a).run_state_t(s)))
+    }
+}
+
+impl<S: Clone + 'static, M, A: 'static> MonadTrans for StateT<S, M>
+where
+    M: Monad<Inner = (A, S)> + FunctorOnce + FunctorWith + 'static,
+    M::With<A>: Monad + 'static,
+{
+    type Base = M::With<A>;
+
+    fn lift(base: M::With<A>) -> Self {
+        StateT::lift(base)
+    }
+}
+
+impl<S: 'static, M, A: 'static> MonadState<S> for StateT<S, M>
+where
+    M: Monad<Inner = (A, S)> + FunctorOnce + FunctorWith + 'static,
+{
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> StateT<S, M::With<(X, S)>> {
+        StateT::new(move |s| M::pure(f(s)))
+    }
+}
+
+impl<S: 'static, M, A: 'static, R> MonadReader<R> for StateT<S, M>
+where
+    M: Monad<Inner = (A, S)> + FunctorOnce + FunctorWith + MonadReader<R> + 'static,
+{
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> StateT<S, M::With<(X, S)>> {
+        StateT::new(move |s| M::reader(move |r| (f(r), s)))
+    }
+
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        StateT::new(move |s| self.run_state_t(s).local(f))
+    }
+}
+
+impl<S: 'static, M, A: 'static, W: Monoid + 'static> MonadWriter<W> for StateT<S, M>
+where
+    M: Monad<Inner = (A, S)> + FunctorOnce + FunctorWith + MonadWriter<W> + 'static,
+{
+    fn writer<X: 'static>(val: X, output: W) -> StateT<S, M::With<(X, S)>> {
+        StateT::new(move |s| M::writer((val, s), output))
+    }
+
+    fn listen(self) -> StateT<S, M::With<((A, W), S)>>
+    where
+        W: Clone,
+    {
+        StateT::new(move |s| M::fmap_with(self.run_state_t(s).listen(), |((a, s), w)| ((a, w), s)))
+    }
+
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self {
+        StateT::new(move |s| self.run_state_t(s).censor(f))
+    }
+}
+
+/// A handler restarts from the state the failed computation was given, as any changes it made
+/// were lost with its result.
+impl<S: Clone + 'static, M, A: 'static, E: 'static> MonadError<E> for StateT<S, M>
+where
+    M: Monad<Inner = (A, S)> + FunctorOnce + FunctorWith + MonadError<E> + 'static,
+{
+    fn throw_error<X: 'static>(err: E) -> StateT<S, M::With<(X, S)>> {
+        StateT::new(move |_| M::throw_error::<(X, S)>(err))
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(E) -> Self + 'static) -> Self {
+        StateT::new(move |s: S| {
+            let init = s.clone();
+            self.run_state_t(s)
+                .catch_error(move |e| handler(e).run_state_t(init.clone()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::ResultOk;
+
+    type Stack<A> = StateT<Vec<i32>, Option<(A, Vec<i32>)>>;
+
+    fn push(x: i32) -> Stack<()> {
+        StateT::modify(move |mut v: Vec<i32>| {
+            v.push(x);
+            v
+        })
+    }
+
+    fn pop() -> Stack<i32> {
+        StateT::new(|mut v: Vec<i32>| v.pop().map(|x| (x, v)))
+    }
+
+    #[test]
+    fn fails_through_the_base() {
+        let prog = push(1)
+            .bind(|()| pop())
+            .bind(|a| pop().fmap(move |b| a + b));
+        assert_eq!(prog.run_state_t(vec![2]), Some((3, vec![])));
+
+        let prog = push(1)
+            .bind(|()| pop())
+            .bind(|a| pop().fmap(move |b| a + b));
+        assert_eq!(prog.run_state_t(vec![]), None);
+    }
+
+    #[test]
+    fn get_put_and_zip() {
+        let prog = Stack::<Vec<i32>>::get()
+            .bind(|v: Vec<i32>| StateT::put(vec![v.len() as i32]))
+            .zip_with(pop(), |(), x| x);
+        assert_eq!(prog.run_state_t(vec![7, 8]), Some((2, vec![])));
+    }
+
+    #[test]
+    fn lift_leaves_the_state_alone() {
+        let prog: StateT<u32, Option<(i32, u32)>> =
+            StateT::lift(Some(1)).bind(|x| StateT::new(move |s: u32| Some((x * 10, s + 1))));
+        assert_eq!(prog.run_state_t(0), Some((10, 1)));
+        let prog: StateT<u32, Option<(i32, u32)>> = StateT::lift(None);
+        assert_eq!(prog.run_state_t(0), None);
+    }
+
+    // Lifting into a base with more than one result still works, even though that base can't
+    // make `StateT` a monad.
+    #[test]
+    fn lift_over_vec() {
+        let prog: StateT<u32, Vec<(i32, u32)>> = StateT::lift(vec![1, 2]);
+        assert_eq!(prog.run_state_t(0), [(1, 0), (2, 0)]);
+    }
+
+    struct OrEmpty;
+
+    impl NatTrans<Option<()>, ResultOk<(), &'static str>> for OrEmpty {
+        fn apply<A: 'static>(&mut self, fa: Option<A>) -> ResultOk<A, &'static str> {
+            ResultOk(fa.ok_or("empty"))
+        }
+    }
+
+    #[test]
+    fn hoist_runs_the_transformation_after_each_run() {
+        let prog = pop()
+            .hoist(OrEmpty)
+            .bind(|x| StateT::new(move |s| ResultOk(Ok((x, s)))));
+        assert_eq!(prog.run_state_t(vec![5]), ResultOk(Ok((5, vec![]))));
+        let prog = pop().hoist(OrEmpty);
+        assert_eq!(prog.run_state_t(vec![]), ResultOk(Err("empty")));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_error::MonadError;
+use crate::monad_reader::MonadReader;
+use crate::monad_state::MonadState;
+use crate::monad_writer::MonadWriter;
+use crate::monoid::Monoid;
+use crate::nat::NatTrans;
+use crate::trans::MonadTrans;
+
+/// Accumulates an output alongside the monad `M`, which must be applied to an `(A, W)` of a value
+/// and what was written while producing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriterT<M>(pub M);
+
+impl<W, M: K1<Inner = (A, W)>, A> WriterT<M> {
+    /// Returns the base monad's values paired with everything written.
+    pub fn run_writer_t(self) -> M {
+        self.0
+    }
+
+    /// Lifts a computation in the base monad into one that writes nothing.
+    pub fn lift<N>(base: N) -> Self
+    where
+        N: Functor<Inner = A, With<(A, W)> = M>,
+        W: Monoid + 'static,
+        A: 'static,
+    {
+        WriterT(base.fmap(|a| (a, W::empty())))
+    }
+
+    /// Returns the value alongside a copy of what has been written so far.
+    pub fn listen(self) -> <Self as K1>::With<(A, W)>
+    where
+        M: Functor,
+        W: Clone + 'static,
+        A: 'static,
+    {
+        WriterT(self.0.fmap(|(a, w): (A, W)| ((a, w.clone()), w)))
+    }
+
+    /// Rewrites the output with `f`.
+    pub fn censor(self, mut f: impl FnMut(W) -> W + 'static) -> WriterT<M::With<(A, W)>>
+    where
+        M: Functor,
+        W: 'static,
+        A: 'static,
+    {
+        WriterT(self.0.fmap(move |(a, w): (A, W)| (a, f(w))))
+    }
+
+    /// Swaps the base monad for another with the natural transformation `nt`.
+    pub fn hoist<F, G, N>(self, mut nt: N) -> WriterT<G::With<(A, W)>>
+    where
+        F: K1<With<(A, W)> = M>,
+        G: K1,
+        N: NatTrans<F, G>,
+        A: 'static,
+        W: 'static,
+    {
+        WriterT(nt.apply::<(A, W)>(self.0))
+    }
+}
+
+impl<W: 'static, M, A: 'static, F> WriterT<M>
+where
+    M: Functor<Inner = ((A, F), W)>,
+    F: FnOnce(W) -> W,
+{
+    /// Rewrites the output with the function the computation returned.
+    pub fn pass(self) -> WriterT<M::With<(A, W)>> {
+        WriterT(self.0.fmap(|((a, f), w): ((A, F), W)| (a, f(w))))
+    }
+}
+
+// `M::pure` only gives back an `M` if `M` is its own `With` at `M::Inner`, which holds for every
+// concrete monad.
+impl<W: 'static, M: Applicative<Inner = ((), W)> + K1<With<((), W)> = M>> WriterT<M> {
+    /// Writes `output` without producing a value.
+    pub fn tell(output: W) -> Self {
+        WriterT(M::pure::<((), W)>(((), output)))
+    }
+}
+
+impl<W, M: K1<Inner = (A, W)>, A> K1 for WriterT<M> {
+    type Inner = A;
+
+    type With<I> = WriterT<M::With<(I, W)>>;
+}
+
+impl<W: 'static, M: Functor<Inner = (A, W)>, A> Functor for WriterT<M> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> WriterT<M::With<(B, W)>> {
+        WriterT(self.0.fmap(move |(a, w): (A, W)| (f(a), w)))
+    }
+}
+
+impl<W: Monoid + 'static, M: Applicative<Inner = (A, W)>, A> Applicative for WriterT<M> {
+    fn pure<X: 'static>(val: X) -> WriterT<M::With<(X, W)>> {
+        WriterT(M::pure((val, W::empty())))
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: WriterT<M::With<(B, W)>>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> WriterT<M::With<(C, W)>> {
+        WriterT(self.0.zip_with(b.0, move |(a, w1): (A, W), (b, w2)| {
+            (f(a, b), w1.combine(w2))
+        }))
+    }
+}
+
+/// The output written before each result is cloned once per result `f` produces from it, and `M`
+/// needs [`FunctorWith`] to prepend it.
+impl<W, M, A> Monad for WriterT<M>
+where
+    W: Monoid + Clone + 'static,
+    M: Monad<Inner = (A, W)> + FunctorWith,
+{
+    fn bind<B: 'static>(
+        self,
+        mut f: impl FnMut(A) -> WriterT<M::With<(B, W)>> + 'static,
+    ) -> WriterT<M::With<(B, W)>> {
+        WriterT(self.0.bind(move |(a, w1): (A, W)| {
+            M::fmap_with(f(a).0, move |(b, w2)| (b, w1.clone().combine(w2)))
+        }))
+    }
+}
+
+impl<W, M, A: 'static> MonadTrans for WriterT<M>
+where
+    W: Monoid + Clone + 'static,
+    M: Monad<Inner = (A, W)> + FunctorWith,
+    M::With<A>: Monad,
+{
+    type Base = M::With<A>;
+
+    fn lift(base: M::With<A>) -> Self {
+        WriterT::lift(base)
+    }
+}
+
+/// `censor` has to hand back a `WriterT<M>`, so `M` must also be its own `With` at `(A, W)`,
+/// which holds for every concrete monad.
+impl<W, M, A> MonadWriter<W> for WriterT<M>
+where
+    W: Monoid + Clone + 'static,
+    M: Monad<Inner = (A, W)> + FunctorWith + K1<With<(A, W)> = M>,
+    A: 'static,
+{
+    fn writer<X: 'static>(val: X, output: W) -> WriterT<M::With<(X, W)>> {
+        WriterT(M::pure((val, output)))
+    }
+
+    fn listen(self) -> WriterT<M::With<((A, W), W)>> {
+        WriterT::listen(self)
+    }
+
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self {
+        WriterT::censor(self, f)
+    }
+}
+
+impl<W, M, A, R> MonadReader<R> for WriterT<M>
+where
+    W: Monoid + Clone + 'static,
+    M: Monad<Inner = (A, W)> + FunctorWith + MonadReader<R>,
+{
+    fn reader<X: 'static>(f: impl FnOnce(R) -> X + 'static) -> WriterT<M::With<(X, W)>> {
+        WriterT(M::reader(move |r| (f(r), W::empty())))
+    }
+
+    fn local(self, f: impl FnOnce(R) -> R + 'static) -> Self {
+        WriterT(self.0.local(f))
+    }
+}
+
+impl<W, M, A, S> MonadState<S> for WriterT<M>
+where
+    W: Monoid + Clone + 'static,
+    M: Monad<Inner = (A, W)> + FunctorWith + MonadState<S>,
+{
+    fn state<X: 'static>(f: impl FnOnce(S) -> (X, S) + 'static) -> WriterT<M::With<(X, W)>> {
+        WriterT(M::state(move |s| {
+            let (x, s) = f(s);
+            ((x, W::empty()), s)
+        }))
+    }
+}
+
+/// What the failed computation wrote is dropped along with its result.
+impl<W, M, A, E> MonadError<E> for WriterT<M>
+where
+    W: Monoid + Clone + 'static,
+    M: Monad<Inner = (A, W)> + FunctorWith + MonadError<E>,
+{
+    fn throw_error<X: 'static>(err: E) -> WriterT<M::With<(X, W)>> {
+        WriterT(M::throw_error::<(X, W)>(err))
+    }
+
+    fn catch_error(self, mut handler: impl FnMut(E) -> Self + 'static) -> Self {
+        WriterT(self.0.catch_error(move |e| handler(e).0))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::identity::Identity;
+
+    type Logged<A> = WriterT<Option<(A, Vec<String>)>>;
+
+    fn log(msg: &str) -> Logged<()> {
+        WriterT::tell(vec![msg.to_owned()])
+    }
+
+    fn checked_div(a: i32, b: i32) -> Logged<i32> {
+        log(&format!("{a} / {b}")).bind(move |()| WriterT((b != 0).then(|| (a / b, vec![]))))
+    }
+
+    #[test]
+    fn accumulates_and_fails() {
+        let prog = checked_div(100, 5).bind(|n| checked_div(n, 2));
+        let (n, out) = prog.run_writer_t().unwrap();
+        assert_eq!(n, 10);
+        assert_eq!(out, ["100 / 5", "20 / 2"]);
+
+        assert!(checked_div(1, 0)
+            .bind(|n| checked_div(n, 2))
+            .run_writer_t()
+            .is_none());
+    }
+
+    #[test]
+    fn zip_combines_in_order() {
+        let prog = log("a").zip_with(log("b"), |(), ()| 1);
+        assert_eq!(prog.run_writer_t(), Some((1, vec!["a".into(), "b".into()])));
+    }
+
+    #[test]
+    fn listen_censor_and_pass() {
+        let prog = WriterT::<Identity<((), String)>>::tell("hi".to_owned())
+            .listen()
+            .fmap(|((), w)| (w.len(), |w: String| w.to_uppercase()))
+            .pass()
+            .censor(|w| w + "!");
+        assert_eq!(prog.run_writer_t(), Identity((2, "HI!".to_owned())));
+
+        let lifted: WriterT<Vec<(i32, String)>> = WriterT::lift(vec![1, 2]);
+        assert_eq!(
+            lifted.run_writer_t(),
+            [(1, String::new()), (2, String::new())]
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Structures that can be walked while running an effect for each element.
+
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::Functor;
+use crate::kind::K1;
+
+/// A [`Functor`] whose elements can be mapped into some [`Applicative`] `F`, with the effects
+/// combined in element order and the structure rebuilt inside `F`.
+///
+/// The result type `F::With<Self::With<B>>` reads as "an `F` of my shape holding `B`s": for
+/// example traversing a `Vec<A>` with `A -> Option<B>` gives an `Option<Vec<B>>`.
+pub trait Traversable: Functor + Foldable {
+    fn traverse<F, B: 'static>(self, f: impl FnMut(Self::Inner) -> F) -> F::With<Self::With<B>>
+    where
+        F: Applicative<Inner = B>;
+
+    /// Turns a structure of effects inside out, e.g. `Vec<Option<A>>` into `Option<Vec<A>>`.
+    fn sequence<B: 'static>(self) -> <Self::Inner as K1>::With<Self::With<B>>
+    where
+        Self: Sized,
+        Self::Inner: Applicative<Inner = B>,
+    {
+        self.traverse(|fa| fa)
+    }
+}
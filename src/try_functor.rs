@@ -0,0 +1,106 @@
+//! Mapping with a function that can fail.
+
+use crate::applicative::Applicative;
+use crate::functor::Functor;
+use crate::result::ResultOk;
+use crate::traversable::Traversable;
+
+/// A [`Functor`] that can be mapped with a fallible function, stopping at the first error.
+///
+/// This is what a `?` inside an [`fmap`](Functor::fmap) would mean. Every [`Traversable`] gets
+/// it by traversing with [`ResultOk`], and the eager functors that can't traverse, like
+/// [`Either`](crate::either::Either) or `Poll`, implement it directly. The lazy ones, like
+/// [`State`](crate::state::State), can't: whether the map fails isn't known until they run.
+///
+/// `x.try_fmap(|a| Ok(f(a)))` should equal `Ok(x.fmap(f))`.
+pub trait TryFunctor: Functor {
+    fn try_fmap<B: 'static, E>(
+        self,
+        f: impl FnMut(Self::Inner) -> Result<B, E>,
+    ) -> Result<Self::With<B>, E>;
+
+    /// Combines `self` and `b` with a fallible `f`, like [`zip_with`](Applicative::zip_with)
+    /// followed by [`try_fmap`](TryFunctor::try_fmap).
+    fn try_zip_with<B, C, E>(
+        self,
+        b: Self::With<B>,
+        f: impl FnMut(Self::Inner, B) -> Result<C, E> + 'static,
+    ) -> Result<Self::With<C>, E>
+    where
+        Self: Applicative + Sized,
+        Self::With<Result<C, E>>: TryFunctor<Inner = Result<C, E>, With<C> = Self::With<C>>,
+        B: 'static,
+        C: 'static,
+        E: 'static,
+    {
+        self.zip_with(b, f).try_fmap(|res| res)
+    }
+}
+
+impl<T: Traversable> TryFunctor for T {
+    fn try_fmap<B: 'static, E>(
+        self,
+        mut f: impl FnMut(T::Inner) -> Result<B, E>,
+    ) -> Result<T::With<B>, E> {
+        self.traverse(|a| ResultOk(f(a))).0
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::ops::ControlFlow;
+    use std::task::Poll;
+
+    use super::*;
+    use crate::either::Either;
+    use crate::writer::Writer;
+
+    fn parse(s: &str) -> Result<i32, String> {
+        s.parse().map_err(|_| format!("bad number: {s}"))
+    }
+
+    #[test]
+    fn traversables_stop_at_the_first_error() {
+        assert_eq!(vec!["1", "2"].try_fmap(parse), Ok(vec![1, 2]));
+        assert_eq!(
+            vec!["1", "x", "y"].try_fmap(parse),
+            Err("bad number: x".to_owned())
+        );
+        assert_eq!(None.try_fmap(parse), Ok(None));
+        let config = BTreeMap::from([("width", "3"), ("height", "4")]);
+        assert_eq!(config.try_fmap(parse).unwrap()["height"], 4);
+    }
+
+    #[test]
+    fn try_zip_with_fails_on_any_pair() {
+        let checked_div = |a: i32, b: i32| a.checked_div(b).ok_or("division by zero");
+        assert_eq!(
+            vec![6, 8].try_zip_with(vec![3, 2], checked_div),
+            Ok(vec![2, 4])
+        );
+        assert_eq!(
+            vec![6, 8].try_zip_with(vec![3, 0], checked_div),
+            Err("division by zero")
+        );
+        assert_eq!(Some(1).try_zip_with(None, checked_div), Ok(None));
+    }
+
+    #[test]
+    fn direct_instances() {
+        let right: Either<(), &str> = Either::Right("5");
+        assert_eq!(right.try_fmap(parse), Ok(Either::Right(5)));
+        assert_eq!(
+            Either::<(), &str>::Left(()).try_fmap(parse),
+            Ok(Either::Left(()))
+        );
+        assert_eq!(
+            Poll::Ready("x").try_fmap(parse),
+            Err("bad number: x".to_owned())
+        );
+        let logged = Writer::new("7", vec!["read"]).try_fmap(parse).unwrap();
+        assert_eq!(logged.run_writer(), (7, vec!["read"]));
+        let flow: ControlFlow<(), &str> = ControlFlow::Continue("8");
+        assert_eq!(flow.try_fmap(parse), Ok(ControlFlow::Continue(8)));
+    }
+}
@@ -0,0 +1,24 @@
+//! Crate-internal helpers.
+
+/// Adapts a `FnOnce` for the `FnMut` the hierarchy asks for, panicking if it's called twice.
+///
+/// This is for single-shot pieces of lazy instances, like the second argument of `zip_with`,
+/// that can't be cloned. It's only safe when the base functor calls its closure at most once,
+/// so instances that use it over another functor should ask for a
+/// [`FunctorOnce`](crate::functor::FunctorOnce).
+pub(crate) fn once<A, B>(f: impl FnOnce(A) -> B) -> impl FnMut(A) -> B {
+    let mut f = Some(f);
+    move |a| (f.take().expect("single-shot closure called more than once"))(a)
+}
+
+/// Polls `fut` to completion on the current thread, for tests.
+#[cfg(all(test, feature = "futures"))]
+pub(crate) fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+    let mut fut = core::pin::pin!(fut);
+    let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    loop {
+        if let core::task::Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
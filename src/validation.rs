@@ -0,0 +1,190 @@
+//! Validation, which accumulates every error instead of stopping at the first.
+
+use crate::applicative::Applicative;
+use crate::either::Either;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monoid::Semigroup;
+#[cfg(feature = "alloc")]
+use crate::non_empty::NonEmptyVec;
+use crate::selective::Selective;
+use crate::try_functor::TryFunctor;
+
+/// Either a success, or every failure encountered so far.
+///
+/// This is shaped like `Result`, but its [`Applicative`] instance runs both sides of a
+/// `zip_with` and [combines](Semigroup::combine) their errors if both fail. That is exactly
+/// what rules out a lawful `Monad` instance: `bind` can't run the rest of the computation
+/// without the value the first part failed to produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Validation<E, A> {
+    Failure(E),
+    Success(A),
+}
+
+/// A [`Validation`] that collects individual errors into a [`NonEmptyVec`].
+#[cfg(feature = "alloc")]
+pub type Validated<E, A> = Validation<NonEmptyVec<E>, A>;
+
+impl<E, A> Validation<E, A> {
+    pub fn into_result(self) -> Result<A, E> {
+        match self {
+            Validation::Failure(e) => Err(e),
+            Validation::Success(a) => Ok(a),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E, A> Validated<E, A> {
+    /// Fails with a single error.
+    pub fn fail(err: E) -> Self {
+        Validation::Failure(NonEmptyVec::singleton(err))
+    }
+}
+
+impl<E, A> From<Result<A, E>> for Validation<E, A> {
+    fn from(res: Result<A, E>) -> Self {
+        match res {
+            Ok(a) => Validation::Success(a),
+            Err(e) => Validation::Failure(e),
+        }
+    }
+}
+
+impl<E, A> From<Validation<E, A>> for Result<A, E> {
+    fn from(val: Validation<E, A>) -> Self {
+        val.into_result()
+    }
+}
+
+impl<E, A> K1 for Validation<E, A> {
+    type Inner = A;
+
+    type With<I> = Validation<E, I>;
+}
+
+impl<E, A> Functor for Validation<E, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Validation<E, B> {
+        match self {
+            Validation::Failure(e) => Validation::Failure(e),
+            Validation::Success(a) => Validation::Success(f(a)),
+        }
+    }
+}
+
+impl<E, A> FunctorWith for Validation<E, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Validation<E, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Validation<E, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<E: Clone, A> FunctorRef for Validation<E, A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Validation<E, B> {
+        match self {
+            Validation::Failure(e) => Validation::Failure(e.clone()),
+            Validation::Success(a) => Validation::Success(f(a)),
+        }
+    }
+}
+
+impl<E: Semigroup, A> Applicative for Validation<E, A> {
+    fn pure<X: 'static>(val: X) -> Validation<E, X> {
+        Validation::Success(val)
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Validation<E, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Validation<E, C> {
+        match (self, b) {
+            (Validation::Success(a), Validation::Success(b)) => Validation::Success(f(a, b)),
+            (Validation::Failure(e1), Validation::Failure(e2)) => {
+                Validation::Failure(e1.combine(e2))
+            }
+            (Validation::Failure(e), _) | (_, Validation::Failure(e)) => Validation::Failure(e),
+        }
+    }
+}
+
+/// `select` can't combine errors the way `zip_with` does: when `fxy` fails there's no value to
+/// decide whether `f` would have been needed, so its errors are dropped.
+impl<E: Semigroup, A> Selective for Validation<E, A> {
+    fn select<X: 'static, Y: 'static, F: FnOnce(X) -> Y + 'static>(
+        fxy: Validation<E, Either<X, Y>>,
+        f: Validation<E, F>,
+    ) -> Validation<E, Y> {
+        match fxy {
+            Validation::Success(Either::Left(x)) => match f {
+                Validation::Success(f) => Validation::Success(f(x)),
+                Validation::Failure(e) => Validation::Failure(e),
+            },
+            Validation::Success(Either::Right(y)) => Validation::Success(y),
+            Validation::Failure(e) => Validation::Failure(e),
+        }
+    }
+}
+
+impl<E, A> TryFunctor for Validation<E, A> {
+    fn try_fmap<B: 'static, X>(
+        self,
+        mut f: impl FnMut(A) -> Result<B, X>,
+    ) -> Result<Validation<E, B>, X> {
+        Ok(match self {
+            Validation::Failure(e) => Validation::Failure(e),
+            Validation::Success(a) => Validation::Success(f(a)?),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::traversable::Traversable;
+
+    #[derive(Debug, PartialEq)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    fn name(raw: &str) -> Validated<&'static str, String> {
+        if raw.is_empty() {
+            Validation::fail("empty name")
+        } else {
+            Validation::Success(raw.to_owned())
+        }
+    }
+
+    fn age(raw: u32) -> Validated<&'static str, u32> {
+        if raw > 150 {
+            Validation::fail("implausible age")
+        } else {
+            Validation::Success(raw)
+        }
+    }
+
+    #[test]
+    fn accumulates_every_error() {
+        let user = name("ada").zip_with(age(36), |name, age| User { name, age });
+        assert_eq!(user.into_result().unwrap().age, 36);
+
+        let user = name("").zip_with(age(200), |name, age| User { name, age });
+        let errors = user.into_result().unwrap_err().into_vec();
+        assert_eq!(errors, ["empty name", "implausible age"]);
+    }
+
+    #[test]
+    fn traverse_collects_all_failures() {
+        let ages = vec![1, 151, 20, 999].traverse(age);
+        assert_eq!(
+            Result::from(ages).unwrap_err().into_vec(),
+            ["implausible age", "implausible age"]
+        );
+        assert_eq!(Validation::from(Ok::<_, ()>(1)), Validation::Success(1));
+    }
+}
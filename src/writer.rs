@@ -0,0 +1,169 @@
+//! The writer monad.
+
+use crate::applicative::Applicative;
+use crate::functor::{Functor, FunctorOnce, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::monad::Monad;
+use crate::monad_writer::MonadWriter;
+use crate::monoid::Monoid;
+use crate::try_functor::TryFunctor;
+
+/// A value of type `A` paired with an accumulated output of type `W`.
+///
+/// Sequencing writers combines their outputs with `W`'s [`Monoid`] instance, left to right.
+/// Unlike [`State`](crate::state::State) and [`Reader`](crate::reader::Reader) there is nothing
+/// to defer, so a `Writer` is just the pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Writer<W, A> {
+    value: A,
+    output: W,
+}
+
+impl<W, A> Writer<W, A> {
+    pub fn new(value: A, output: W) -> Self {
+        Writer { value, output }
+    }
+
+    /// Returns the value and everything written.
+    pub fn run_writer(self) -> (A, W) {
+        (self.value, self.output)
+    }
+
+    /// Returns the value alongside a copy of what has been written so far.
+    pub fn listen(self) -> Writer<W, (A, W)>
+    where
+        W: Clone,
+    {
+        Writer::new((self.value, self.output.clone()), self.output)
+    }
+
+    /// Rewrites the output with `f`.
+    pub fn censor(self, f: impl FnOnce(W) -> W) -> Self {
+        Writer::new(self.value, f(self.output))
+    }
+}
+
+impl<W, A, F: FnOnce(W) -> W> Writer<W, (A, F)> {
+    /// Rewrites the output with the function the computation returned.
+    pub fn pass(self) -> Writer<W, A> {
+        let (value, f) = self.value;
+        Writer::new(value, f(self.output))
+    }
+}
+
+impl<W> Writer<W, ()> {
+    /// Writes `output` without producing a value.
+    pub fn tell(output: W) -> Self {
+        Writer::new((), output)
+    }
+}
+
+impl<W, A> K1 for Writer<W, A> {
+    type Inner = A;
+
+    type With<I> = Writer<W, I>;
+}
+
+impl<W, A> Functor for Writer<W, A> {
+    fn fmap<B: 'static>(self, mut f: impl FnMut(A) -> B + 'static) -> Writer<W, B> {
+        Writer::new(f(self.value), self.output)
+    }
+}
+
+impl<W, A> FunctorWith for Writer<W, A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: Writer<W, X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> Writer<W, Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<W, A> FunctorOnce for Writer<W, A> {}
+
+impl<W: Clone, A> FunctorRef for Writer<W, A> {
+    fn fmap_ref<B>(&self, mut f: impl FnMut(&A) -> B) -> Writer<W, B> {
+        Writer::new(f(&self.value), self.output.clone())
+    }
+}
+
+impl<W: Monoid, A> Applicative for Writer<W, A> {
+    fn pure<X: 'static>(val: X) -> Writer<W, X> {
+        Writer::new(val, W::empty())
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: Writer<W, B>,
+        mut f: impl FnMut(A, B) -> C + 'static,
+    ) -> Writer<W, C> {
+        Writer::new(f(self.value, b.value), self.output.combine(b.output))
+    }
+}
+
+impl<W: Monoid, A> Monad for Writer<W, A> {
+    fn bind<B: 'static>(self, mut f: impl FnMut(A) -> Writer<W, B> + 'static) -> Writer<W, B> {
+        let next = f(self.value);
+        Writer::new(next.value, self.output.combine(next.output))
+    }
+}
+
+impl<W: Monoid, A> MonadWriter<W> for Writer<W, A> {
+    fn writer<X: 'static>(val: X, output: W) -> Writer<W, X> {
+        Writer::new(val, output)
+    }
+
+    fn listen(self) -> Writer<W, (A, W)>
+    where
+        W: Clone,
+    {
+        Writer::listen(self)
+    }
+
+    fn censor(self, f: impl FnMut(W) -> W + 'static) -> Self {
+        Writer::censor(self, f)
+    }
+}
+
+impl<W, A> TryFunctor for Writer<W, A> {
+    fn try_fmap<B: 'static, E>(
+        self,
+        mut f: impl FnMut(A) -> Result<B, E>,
+    ) -> Result<Writer<W, B>, E> {
+        Ok(Writer::new(f(self.value)?, self.output))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    fn logged(x: i32) -> Writer<Vec<String>, i32> {
+        Writer::tell(vec![format!("got {x}")]).fmap(move |()| x)
+    }
+
+    #[test]
+    fn outputs_accumulate_in_order() {
+        let sum = logged(1).bind(|a| logged(2).fmap(move |b| a + b));
+        assert_eq!(sum.run_writer(), (3, vec!["got 1".into(), "got 2".into()]));
+    }
+
+    #[test]
+    fn listen_pass_censor() {
+        let (value, out) = logged(1).listen().run_writer();
+        assert_eq!(value, (1, vec!["got 1".to_owned()]));
+        assert_eq!(out, ["got 1"]);
+
+        let reversed = logged(1)
+            .zip_with(logged(2), |a, b| a + b)
+            .fmap(|x| (x, |w: Vec<String>| w.into_iter().rev().collect()))
+            .pass();
+        assert_eq!(
+            reversed.run_writer(),
+            (3, vec!["got 2".into(), "got 1".into()])
+        );
+
+        let quiet = logged(5).censor(|_| Vec::new());
+        assert_eq!(quiet.run_writer(), (5, vec![]));
+    }
+}
@@ -0,0 +1,213 @@
+//! A `Vec` whose instances all treat it positionally.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::align::{Align, LengthMismatch, Semialign, Zip};
+use crate::alternative::Alternative;
+use crate::applicative::Applicative;
+use crate::foldable::Foldable;
+use crate::functor::{Functor, FunctorRef, FunctorWith};
+use crate::kind::K1;
+use crate::these::These;
+use crate::traversable::Traversable;
+
+/// A `Vec` that combines element by element, like Haskell's `ZipList`.
+///
+/// `Vec` has two applicatives: the cartesian product, which agrees with its `flat_map`
+/// [`Monad`](crate::monad::Monad), and zipping. The product hands each element of both sides to
+/// `f` once per element of the other, so even `Vec::<()>::pure(x).zip_with(vec![b, c], f)` has
+/// to pass `x` to `f` twice. That takes `Clone`, and an instance can't ask for it: `pure` and
+/// `zip_with` are generic over their element types, so the bound would have to be on
+/// [`Applicative`] itself, for every instance. So `Vec`'s own instance zips too, and sits next to
+/// a `Monad` it doesn't agree with. So do the instances for the other sequences, like
+/// [`NonEmptyVec`](crate::non_empty::NonEmptyVec), [`Logic`](crate::logic::Logic) and
+/// [`Dist`](crate::dist::Dist).
+///
+/// What `ZipVec` adds is consistency: it has no `Monad`, and its [`Alternative`] is positional
+/// too, so code that relies on zipping can say so in its types.
+///
+/// A lawful zip applicative's `pure` would repeat its value forever, which would take `Clone` as
+/// well. Here and in all of those instances, `pure` gives a single element instead, so the
+/// identity laws only hold for vectors of at most one element.
+///
+/// [`Alternative::or`] takes every element of `self`, then whatever `other` has past the end of
+/// `self`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ZipVec<T>(pub Vec<T>);
+
+impl<T> ZipVec<T> {
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for ZipVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        ZipVec(vec)
+    }
+}
+
+impl<T> From<ZipVec<T>> for Vec<T> {
+    fn from(vec: ZipVec<T>) -> Self {
+        vec.0
+    }
+}
+
+impl<T> FromIterator<T> for ZipVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        ZipVec(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for ZipVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> K1 for ZipVec<T> {
+    type Inner = T;
+
+    type With<I> = ZipVec<I>;
+}
+
+impl<A> Functor for ZipVec<A> {
+    fn fmap<B: 'static>(self, f: impl FnMut(A) -> B + 'static) -> ZipVec<B> {
+        ZipVec(self.0.fmap(f))
+    }
+}
+
+impl<A> FunctorWith for ZipVec<A> {
+    fn fmap_with<X: 'static, Y: 'static>(
+        fx: ZipVec<X>,
+        f: impl FnMut(X) -> Y + 'static,
+    ) -> ZipVec<Y> {
+        fx.fmap(f)
+    }
+}
+
+impl<A> FunctorRef for ZipVec<A> {
+    fn fmap_ref<B>(&self, f: impl FnMut(&A) -> B) -> ZipVec<B> {
+        ZipVec(self.0.fmap_ref(f))
+    }
+}
+
+impl<A> Applicative for ZipVec<A> {
+    fn pure<X: 'static>(val: X) -> ZipVec<X> {
+        ZipVec(vec![val])
+    }
+
+    fn zip_with<B: 'static, C: 'static>(
+        self,
+        b: ZipVec<B>,
+        f: impl FnMut(A, B) -> C + 'static,
+    ) -> ZipVec<C> {
+        ZipVec(self.0.zip_with(b.0, f))
+    }
+}
+
+impl<A> Alternative for ZipVec<A> {
+    fn empty<X: 'static>() -> ZipVec<X> {
+        ZipVec(Vec::new())
+    }
+
+    fn or(mut self, other: ZipVec<A>) -> ZipVec<A> {
+        let len = self.0.len();
+        self.0.extend(other.0.into_iter().skip(len));
+        self
+    }
+}
+
+impl<A> Semialign for ZipVec<A> {
+    fn align_with<B, C>(self, other: ZipVec<B>, f: impl FnMut(These<A, B>) -> C) -> ZipVec<C> {
+        ZipVec(self.0.align_with(other.0, f))
+    }
+}
+
+impl<A> Align for ZipVec<A> {
+    fn nil<X>() -> ZipVec<X> {
+        ZipVec(Vec::new())
+    }
+}
+
+impl<A> Zip for ZipVec<A> {
+    fn zip_with_truncated<B, C>(self, other: ZipVec<B>, f: impl FnMut(A, B) -> C) -> ZipVec<C> {
+        ZipVec(self.0.zip_with_truncated(other.0, f))
+    }
+
+    fn try_zip_exact_with<B, C>(
+        self,
+        other: ZipVec<B>,
+        f: impl FnMut(A, B) -> C,
+    ) -> Result<ZipVec<C>, LengthMismatch> {
+        self.0.try_zip_exact_with(other.0, f).map(ZipVec)
+    }
+}
+
+impl<A> Foldable for ZipVec<A> {
+    fn fold_left<B>(self, init: B, f: impl FnMut(B, A) -> B) -> B {
+        self.0.fold_left(init, f)
+    }
+
+    fn fold_right<B>(self, init: B, f: impl FnMut(A, B) -> B) -> B {
+        self.0.fold_right(init, f)
+    }
+}
+
+impl<A> Traversable for ZipVec<A> {
+    fn traverse<F, B: 'static>(self, f: impl FnMut(A) -> F) -> F::With<ZipVec<B>>
+    where
+        F: Applicative<Inner = B>,
+    {
+        // Nested from the right like `Vec`'s `traverse`, so the elements are pushed back to front.
+        let effects: Vec<F> = self.0.into_iter().map(f).collect();
+        let mut acc = F::pure(ZipVec(Vec::with_capacity(effects.len())));
+        for (i, fb) in effects.into_iter().enumerate().rev() {
+            acc = fb.zip_with(acc, move |b, mut bs: ZipVec<B>| {
+                bs.0.push(b);
+                if i == 0 {
+                    bs.0.reverse();
+                }
+                bs
+            });
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zips_positionally() {
+        let sums = ZipVec(vec![1, 2, 3]).zip_with(ZipVec(vec![10, 20]), |a, b| a + b);
+        assert_eq!(sums, ZipVec(vec![11, 22]));
+        assert_eq!(ZipVec::<()>::pure(1), ZipVec(vec![1]));
+    }
+
+    #[test]
+    fn or_fills_in_past_the_end() {
+        let short = ZipVec(vec![1, 2]);
+        assert_eq!(
+            short.clone().or(ZipVec(vec![9, 9, 3, 4])),
+            ZipVec(vec![1, 2, 3, 4])
+        );
+        assert_eq!(short.clone().or(ZipVec::<()>::empty()), short);
+        assert_eq!(ZipVec::<()>::empty().or(short.clone()), short);
+    }
+
+    #[test]
+    fn converts_and_iterates() {
+        let zv: ZipVec<i32> = (1..=3).collect();
+        assert_eq!(Vec::from(zv.clone().fmap(|n| n * 2)), [2, 4, 6]);
+        assert_eq!(zv.clone().into_iter().sum::<i32>(), 6);
+        let skipped = zv.traverse(|n| (n != 2).then_some(n));
+        assert_eq!(skipped, None);
+        assert_eq!(ZipVec(vec![1, 3]).traverse(Some), Some(ZipVec(vec![1, 3])));
+    }
+}